@@ -0,0 +1,465 @@
+//! Animate values over time with easing curves and springs.
+//!
+//! An [`Animation`] is a pure function of time: it does not need to be ticked.
+//! Instead, you query its [`Animation::value`] at any [`Instant`] and ask it
+//! for a [`RedrawRequest`] while it is in motion, so nothing is redrawn once
+//! it has settled.
+//!
+//! Inside a widget, you can request redraws with the [`Shell`]:
+//!
+//! ```
+//! # use iced_core::animation::Animation;
+//! # use iced_core::time::Instant;
+//! # use iced_core::{Color, Shell};
+//! # let mut messages: Vec<()> = Vec::new();
+//! # let mut shell = Shell::new(&mut messages);
+//! # let now = Instant::now();
+//! let mut background = Animation::new(Color::WHITE);
+//!
+//! background.go(Color::BLACK, now);
+//!
+//! if let Some(redraw_request) = background.redraw_request(now) {
+//!     shell.request_redraw(redraw_request);
+//! }
+//! ```
+//!
+//! In application state, you can subscribe to the frames of a window only
+//! while [`Animation::is_animating`] and store the [`Instant`] of the latest
+//! frame to compute values in your `view`.
+//!
+//! [`Shell`]: crate::Shell
+mod easing;
+mod spring;
+
+pub use easing::Easing;
+pub use spring::Spring;
+
+use crate::time::{Duration, Instant};
+use crate::window::RedrawRequest;
+use crate::{Color, Point, Radians, Rectangle, Size, Vector};
+
+/// The smallest displacement and velocity a [`Spring`] considers at rest.
+const REST_THRESHOLD: f32 = 1e-3;
+
+/// A value that is animated over time.
+///
+/// By default, an [`Animation`] transitions in 200 milliseconds with
+/// [`Easing::EaseInOut`]. Use [`Animation::spring`] to animate it with
+/// physics instead.
+#[derive(Debug, Clone)]
+pub struct Animation<T> {
+    from: T,
+    to: T,
+    motion: Motion,
+    delay: Duration,
+    start: Option<Instant>,
+    velocity: f32,
+}
+
+/// How an [`Animation`] moves towards its target.
+#[derive(Debug, Clone, Copy)]
+enum Motion {
+    Transition { duration: Duration, easing: Easing },
+    Spring(Spring),
+}
+
+impl<T> Animation<T>
+where
+    T: Interpolable,
+{
+    /// Creates a new [`Animation`] resting at the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            from: value.clone(),
+            to: value,
+            motion: Motion::Transition {
+                duration: Duration::from_millis(200),
+                easing: Easing::default(),
+            },
+            delay: Duration::ZERO,
+            start: None,
+            velocity: 0.0,
+        }
+    }
+
+    /// Sets the duration of the transitions of the [`Animation`].
+    ///
+    /// This turns a spring [`Animation`] into an eased one.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.motion = match self.motion {
+            Motion::Transition { easing, .. } => {
+                Motion::Transition { duration, easing }
+            }
+            Motion::Spring(_) => Motion::Transition {
+                duration,
+                easing: Easing::default(),
+            },
+        };
+        self
+    }
+
+    /// Sets the [`Easing`] curve of the transitions of the [`Animation`].
+    ///
+    /// This turns a spring [`Animation`] into an eased one.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.motion = match self.motion {
+            Motion::Transition { duration, .. } => {
+                Motion::Transition { duration, easing }
+            }
+            Motion::Spring(_) => Motion::Transition {
+                duration: Duration::from_millis(200),
+                easing,
+            },
+        };
+        self
+    }
+
+    /// Animates the [`Animation`] with the given [`Spring`].
+    pub fn spring(mut self, spring: Spring) -> Self {
+        self.motion = Motion::Spring(spring);
+        self
+    }
+
+    /// Sets the delay before the [`Animation`] starts moving after
+    /// [`Animation::go`] is called.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Starts moving the [`Animation`] towards the given target at the
+    /// given [`Instant`].
+    ///
+    /// If the [`Animation`] is already moving, it is interrupted and
+    /// retargeted from its current value. Spring animations preserve their
+    /// momentum. Nothing happens if the target does not change.
+    pub fn go(&mut self, target: T, now: Instant) {
+        if self.to == target {
+            return;
+        }
+
+        let (progress, velocity) = self.progress(now);
+        let current = self.from.interpolate(&self.to, progress);
+
+        let distance = current.distance(&target);
+
+        // Project the current velocity onto the direction of the new target
+        let velocity = if velocity != 0.0 && distance > f32::EPSILON {
+            const STEP: f32 = 1e-3;
+
+            let next = self.from.interpolate(&self.to, progress + STEP);
+            let rate = (distance - next.distance(&target)) / STEP;
+
+            velocity * rate / distance
+        } else {
+            0.0
+        };
+
+        self.from = current;
+        self.to = target;
+        self.start = Some(now);
+        self.velocity = velocity;
+    }
+
+    /// Moves the [`Animation`] to the given value right away, without
+    /// animating it.
+    pub fn set(&mut self, value: T) {
+        self.from = value.clone();
+        self.to = value;
+        self.start = None;
+        self.velocity = 0.0;
+    }
+
+    /// Returns the value of the [`Animation`] at the given [`Instant`].
+    pub fn value(&self, now: Instant) -> T {
+        if self.start.is_none() {
+            return self.to.clone();
+        }
+
+        let (progress, _) = self.progress(now);
+
+        if progress == 1.0 {
+            self.to.clone()
+        } else {
+            self.from.interpolate(&self.to, progress)
+        }
+    }
+
+    /// Returns the target of the [`Animation`].
+    pub fn target(&self) -> &T {
+        &self.to
+    }
+
+    /// Returns true if the [`Animation`] has not reached its target at the
+    /// given [`Instant`] yet.
+    pub fn is_animating(&self, now: Instant) -> bool {
+        self.start.is_some() && self.progress(now) != (1.0, 0.0)
+    }
+
+    /// Returns the [`RedrawRequest`] needed to keep the [`Animation`] moving
+    /// at the given [`Instant`], if it is still animating.
+    ///
+    /// While delayed, the redraw is requested for the moment the
+    /// [`Animation`] starts moving.
+    pub fn redraw_request(&self, now: Instant) -> Option<RedrawRequest> {
+        let start = self.start?;
+
+        if !self.is_animating(now) {
+            return None;
+        }
+
+        let begin = start + self.delay;
+
+        Some(if now < begin {
+            RedrawRequest::At(begin)
+        } else {
+            RedrawRequest::NextFrame
+        })
+    }
+
+    /// Returns the progress and velocity of the [`Animation`] at the given
+    /// [`Instant`].
+    ///
+    /// The velocity is measured in progress per second.
+    fn progress(&self, now: Instant) -> (f32, f32) {
+        let Some(start) = self.start else {
+            return (1.0, 0.0);
+        };
+
+        let elapsed = now
+            .saturating_duration_since(start)
+            .saturating_sub(self.delay)
+            .as_secs_f32();
+
+        match self.motion {
+            Motion::Transition { duration, easing } => {
+                let duration = duration.as_secs_f32();
+
+                if elapsed >= duration {
+                    return (1.0, 0.0);
+                }
+
+                let t = elapsed / duration;
+                let progress = easing.apply(t);
+
+                const STEP: f32 = 1e-3;
+                let velocity =
+                    (easing.apply(t + STEP) - progress) / (STEP * duration);
+
+                (progress, velocity)
+            }
+            Motion::Spring(spring) => {
+                let (displacement, velocity) =
+                    spring.solve(1.0, -self.velocity, elapsed);
+
+                if displacement.abs() < REST_THRESHOLD
+                    && velocity.abs() < REST_THRESHOLD
+                {
+                    (1.0, 0.0)
+                } else {
+                    (1.0 - displacement, -velocity)
+                }
+            }
+        }
+    }
+}
+
+impl<T> Default for Animation<T>
+where
+    T: Interpolable + Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+/// A value that can be animated.
+pub trait Interpolable: Clone + PartialEq {
+    /// Interpolates between the current value and the given one.
+    ///
+    /// A `progress` of `0.0` produces the current value and `1.0` the given
+    /// one. Springs and some [`Easing`] curves may produce values outside
+    /// of that range.
+    fn interpolate(&self, other: &Self, progress: f32) -> Self;
+
+    /// Returns the distance between the current value and the given one.
+    fn distance(&self, other: &Self) -> f32;
+}
+
+impl Interpolable for f32 {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        self + (other - self) * progress
+    }
+
+    fn distance(&self, other: &Self) -> f32 {
+        (other - self).abs()
+    }
+}
+
+impl Interpolable for Vector {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(&other.x, progress),
+            self.y.interpolate(&other.y, progress),
+        )
+    }
+
+    fn distance(&self, other: &Self) -> f32 {
+        let delta = *other - *self;
+
+        delta.x.hypot(delta.y)
+    }
+}
+
+impl Interpolable for Point {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        Point::new(
+            self.x.interpolate(&other.x, progress),
+            self.y.interpolate(&other.y, progress),
+        )
+    }
+
+    fn distance(&self, other: &Self) -> f32 {
+        Point::distance(self, *other)
+    }
+}
+
+impl Interpolable for Size {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        Size::new(
+            self.width.interpolate(&other.width, progress),
+            self.height.interpolate(&other.height, progress),
+        )
+    }
+
+    fn distance(&self, other: &Self) -> f32 {
+        (other.width - self.width).hypot(other.height - self.height)
+    }
+}
+
+impl Interpolable for Rectangle {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        Rectangle::new(
+            self.position().interpolate(&other.position(), progress),
+            self.size().interpolate(&other.size(), progress),
+        )
+    }
+
+    fn distance(&self, other: &Self) -> f32 {
+        self.position()
+            .distance(other.position())
+            .hypot(self.size().distance(&other.size()))
+    }
+}
+
+impl Interpolable for Radians {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        Radians(self.0.interpolate(&other.0, progress))
+    }
+
+    fn distance(&self, other: &Self) -> f32 {
+        self.0.distance(&other.0)
+    }
+}
+
+impl Interpolable for Color {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        let channel =
+            |a: f32, b: f32| a.interpolate(&b, progress).clamp(0.0, 1.0);
+
+        Color {
+            r: channel(self.r, other.r),
+            g: channel(self.g, other.g),
+            b: channel(self.b, other.b),
+            a: channel(self.a, other.a),
+        }
+    }
+
+    fn distance(&self, other: &Self) -> f32 {
+        let [r, g, b, a] = [
+            other.r - self.r,
+            other.g - self.g,
+            other.b - self.b,
+            other.a - self.a,
+        ];
+
+        (r * r + g * g + b * b + a * a).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transition_reaches_target() {
+        let now = Instant::now();
+        let mut animation = Animation::new(0.0)
+            .duration(Duration::from_millis(100))
+            .easing(Easing::Linear);
+
+        animation.go(10.0, now);
+
+        assert_eq!(animation.value(now), 0.0);
+        assert_eq!(animation.value(now + Duration::from_millis(50)), 5.0);
+        assert_eq!(animation.value(now + Duration::from_millis(100)), 10.0);
+
+        assert!(animation.is_animating(now + Duration::from_millis(50)));
+        assert!(!animation.is_animating(now + Duration::from_millis(100)));
+        assert_eq!(
+            animation.redraw_request(now + Duration::from_millis(100)),
+            None
+        );
+    }
+
+    #[test]
+    fn delay_requests_redraw_at_start() {
+        let now = Instant::now();
+        let delay = Duration::from_millis(300);
+        let mut animation = Animation::new(0.0).delay(delay);
+
+        animation.go(1.0, now);
+
+        assert_eq!(
+            animation.redraw_request(now),
+            Some(RedrawRequest::At(now + delay))
+        );
+        assert_eq!(
+            animation.redraw_request(now + delay),
+            Some(RedrawRequest::NextFrame)
+        );
+    }
+
+    #[test]
+    fn spring_settles_at_target() {
+        let now = Instant::now();
+        let mut animation = Animation::new(0.0).spring(Spring::WOBBLY);
+
+        animation.go(100.0, now);
+
+        let later = now + Duration::from_secs(5);
+
+        assert!(animation.is_animating(now + Duration::from_millis(100)));
+        assert!(!animation.is_animating(later));
+        assert_eq!(animation.value(later), 100.0);
+    }
+
+    #[test]
+    fn spring_preserves_momentum_when_retargeted() {
+        let now = Instant::now();
+        let mut animation = Animation::new(0.0).spring(Spring::default());
+
+        animation.go(100.0, now);
+
+        let interruption = now + Duration::from_millis(100);
+        let value = animation.value(interruption);
+
+        animation.go(200.0, interruption);
+
+        // The value keeps increasing right after being retargeted
+        assert_eq!(animation.value(interruption), value);
+        assert!(
+            animation.value(interruption + Duration::from_millis(16)) > value
+        );
+    }
+}
@@ -0,0 +1,95 @@
+/// A curve that describes the rate of change of a transition over time.
+///
+/// Easing curves map the linear progress of a transition, between `0.0` and
+/// `1.0`, to the actual progress of the animated value. Some curves, like
+/// [`Easing::EaseOutBack`], may briefly exceed that range.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Easing {
+    /// A constant rate of change.
+    Linear,
+    /// Starts slowly and accelerates towards the end (cubic).
+    EaseIn,
+    /// Starts quickly and decelerates towards the end (cubic).
+    EaseOut,
+    /// Starts and ends slowly, accelerating in the middle (cubic).
+    #[default]
+    EaseInOut,
+    /// Starts slowly and accelerates towards the end (quadratic).
+    EaseInQuad,
+    /// Starts quickly and decelerates towards the end (quadratic).
+    EaseOutQuad,
+    /// Starts and ends slowly, accelerating in the middle (quadratic).
+    EaseInOutQuad,
+    /// Pulls back slightly before moving towards the end.
+    EaseInBack,
+    /// Overshoots the end slightly before settling.
+    EaseOutBack,
+    /// Bounces against the end a few times before settling.
+    EaseOutBounce,
+    /// A custom curve.
+    ///
+    /// The function must return `0.0` for `0.0` and `1.0` for `1.0`.
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    /// Returns the eased progress for the given linear progress.
+    ///
+    /// The linear progress is clamped between `0.0` and `1.0`.
+    pub fn apply(self, t: f32) -> f32 {
+        const BACK: f32 = 1.70158;
+
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::EaseInQuad => t * t,
+            Easing::EaseOutQuad => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::EaseInOutQuad => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            Easing::EaseInBack => (BACK + 1.0) * t * t * t - BACK * t * t,
+            Easing::EaseOutBack => {
+                let t = t - 1.0;
+
+                1.0 + (BACK + 1.0) * t * t * t + BACK * t * t
+            }
+            Easing::EaseOutBounce => bounce(t),
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+fn bounce(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+
+        N * t * t + 0.984_375
+    }
+}
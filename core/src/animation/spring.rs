@@ -0,0 +1,96 @@
+/// The physical properties of a spring.
+///
+/// Springs produce natural motion that preserves its momentum when the
+/// target of an [`Animation`] changes halfway through.
+///
+/// [`Animation`]: super::Animation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    /// The stiffness of the spring.
+    ///
+    /// Stiffer springs move faster.
+    pub stiffness: f32,
+    /// The damping of the spring.
+    ///
+    /// Less damped springs oscillate more before settling.
+    pub damping: f32,
+}
+
+impl Spring {
+    /// A spring that moves gently without oscillating.
+    pub const GENTLE: Self = Self::new(120.0, 14.0);
+
+    /// A spring that oscillates noticeably before settling.
+    pub const WOBBLY: Self = Self::new(180.0, 12.0);
+
+    /// A spring that moves quickly and barely oscillates.
+    pub const STIFF: Self = Self::new(210.0, 20.0);
+
+    /// A spring that moves slowly without oscillating.
+    pub const SLOW: Self = Self::new(280.0, 60.0);
+
+    /// Creates a new [`Spring`] with the given stiffness and damping.
+    pub const fn new(stiffness: f32, damping: f32) -> Self {
+        Self { stiffness, damping }
+    }
+
+    /// Returns the displacement and velocity of the spring after the given
+    /// amount of seconds, given its initial displacement and velocity.
+    pub(super) fn solve(
+        self,
+        displacement: f32,
+        velocity: f32,
+        seconds: f32,
+    ) -> (f32, f32) {
+        let x0 = displacement;
+        let v0 = velocity;
+        let t = seconds;
+
+        let omega = self.stiffness.max(f32::EPSILON).sqrt();
+        let zeta = self.damping.max(0.0) / (2.0 * omega);
+
+        if (zeta - 1.0).abs() < 1e-3 {
+            // Critically damped
+            let b = v0 + omega * x0;
+            let decay = (-omega * t).exp();
+
+            let x = decay * (x0 + b * t);
+            let v = decay * (b - omega * (x0 + b * t));
+
+            (x, v)
+        } else if zeta < 1.0 {
+            // Underdamped
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+            let a = x0;
+            let b = (v0 + zeta * omega * x0) / omega_d;
+
+            let decay = (-zeta * omega * t).exp();
+            let (sin, cos) = (omega_d * t).sin_cos();
+
+            let x = decay * (a * cos + b * sin);
+            let v = decay
+                * ((b * omega_d - zeta * omega * a) * cos
+                    - (a * omega_d + zeta * omega * b) * sin);
+
+            (x, v)
+        } else {
+            // Overdamped
+            let root = omega * (zeta * zeta - 1.0).sqrt();
+            let r1 = -zeta * omega + root;
+            let r2 = -zeta * omega - root;
+
+            let c1 = (v0 - r2 * x0) / (r1 - r2);
+            let c2 = x0 - c1;
+
+            let (e1, e2) = ((r1 * t).exp(), (r2 * t).exp());
+
+            (c1 * e1 + c2 * e2, c1 * r1 * e1 + c2 * r2 * e2)
+        }
+    }
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::new(170.0, 26.0)
+    }
+}
@@ -1,4 +1,5 @@
 //! Access the clipboard.
+pub use bytes::Bytes;
 
 /// A buffer for short-term storage and transfer within and between
 /// applications.
@@ -8,6 +9,91 @@ pub trait Clipboard {
 
     /// Writes the given text contents to the [`Clipboard`].
     fn write(&mut self, kind: Kind, contents: String);
+
+    /// Reads the current content of the [`Clipboard`] in the given MIME type.
+    ///
+    /// By default, only text can be read.
+    fn read_data(&self, kind: Kind, mime: &str) -> Option<Bytes> {
+        if mime::is_text(mime) {
+            self.read(kind).map(Bytes::from)
+        } else {
+            None
+        }
+    }
+
+    /// Writes the given contents to the [`Clipboard`], each [`Data`] being
+    /// a different representation of the same content.
+    ///
+    /// By default, only the first text representation is written.
+    fn write_data(&mut self, kind: Kind, contents: Vec<Data>) {
+        let text = contents
+            .into_iter()
+            .find(|data| mime::is_text(&data.mime))
+            .and_then(|data| String::from_utf8(data.bytes.to_vec()).ok());
+
+        if let Some(text) = text {
+            self.write(kind, text);
+        }
+    }
+
+    /// Returns the MIME types the current content of the [`Clipboard`] is
+    /// available in.
+    fn formats(&self, kind: Kind) -> Vec<String> {
+        if self.read(kind).is_some() {
+            vec![String::from(mime::TEXT)]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+/// Some content of a [`Clipboard`] in a specific MIME type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Data {
+    /// The MIME type of the [`Data`]; like `text/html` or `image/png`.
+    pub mime: String,
+    /// The bytes of the [`Data`].
+    pub bytes: Bytes,
+}
+
+impl Data {
+    /// Creates some new [`Data`] with the given MIME type and bytes.
+    pub fn new(mime: impl Into<String>, bytes: impl Into<Bytes>) -> Self {
+        Self {
+            mime: mime.into(),
+            bytes: bytes.into(),
+        }
+    }
+
+    /// Creates some new [`Data`] containing plain text.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::new(mime::TEXT, text.into())
+    }
+
+    /// Returns the contents of the [`Data`] as text, if it is valid UTF-8.
+    pub fn as_text(&self) -> Option<&str> {
+        std::str::from_utf8(&self.bytes).ok()
+    }
+}
+
+/// Some common MIME types.
+pub mod mime {
+    /// Plain text encoded in UTF-8.
+    pub const TEXT: &str = "text/plain;charset=utf-8";
+
+    /// An HTML fragment.
+    pub const HTML: &str = "text/html";
+
+    /// A list of URIs; one per line.
+    pub const URI_LIST: &str = "text/uri-list";
+
+    /// An image encoded in PNG.
+    pub const PNG: &str = "image/png";
+
+    /// Returns true if the given MIME type is plain text.
+    pub fn is_text(mime: &str) -> bool {
+        mime == "text/plain" || mime.starts_with("text/plain;")
+    }
 }
 
 /// The kind of [`Clipboard`].
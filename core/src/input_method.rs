@@ -0,0 +1,28 @@
+use crate::Rectangle;
+
+/// The state of the input method requested by a widget.
+///
+/// A focused text widget can request the input method of the system to be
+/// enabled, so the user can compose text with it.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum InputMethod {
+    /// The input method should be disabled.
+    #[default]
+    Disabled,
+
+    /// The input method should be enabled.
+    Enabled {
+        /// The area of the text cursor, in logical pixels.
+        ///
+        /// The candidate window of the input method will be placed next to
+        /// it.
+        cursor: Rectangle,
+    },
+}
+
+impl InputMethod {
+    /// Returns whether the [`InputMethod`] is enabled.
+    pub fn is_enabled(&self) -> bool {
+        matches!(self, Self::Enabled { .. })
+    }
+}
@@ -1,4 +1,4 @@
-use crate::keyboard::{Key, Location, Modifiers};
+use crate::keyboard::{Ime, Key, Location, Modifiers};
 use crate::SmolStr;
 
 /// A keyboard event.
@@ -38,4 +38,7 @@ pub enum Event {
 
     /// The keyboard modifiers have changed.
     ModifiersChanged(Modifiers),
+
+    /// An input method event happened.
+    Ime(Ime),
 }
@@ -0,0 +1,29 @@
+/// An input method event.
+///
+/// Input methods allow users to compose text that cannot be typed directly
+/// with a keyboard; like Chinese, Japanese, or Korean characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ime {
+    /// The input method was enabled.
+    Enabled,
+
+    /// The text being composed has changed.
+    ///
+    /// An empty `content` means the composition was cleared.
+    Preedit {
+        /// The text being composed.
+        content: String,
+
+        /// The byte range of the cursor in the `content`, if it should be
+        /// displayed.
+        ///
+        /// When both ends are equal, the cursor is a caret.
+        cursor: Option<(usize, usize)>,
+    },
+
+    /// The composition was finished with the given text.
+    Commit(String),
+
+    /// The input method was disabled.
+    Disabled,
+}
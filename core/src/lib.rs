@@ -10,6 +10,7 @@
     html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
 )]
 pub mod alignment;
+pub mod animation;
 pub mod border;
 pub mod clipboard;
 pub mod event;
@@ -34,6 +35,7 @@ mod background;
 mod color;
 mod content_fit;
 mod element;
+mod input_method;
 mod length;
 mod padding;
 mod pixels;
@@ -48,6 +50,7 @@ mod vector;
 
 pub use alignment::Alignment;
 pub use angle::{Degrees, Radians};
+pub use animation::Animation;
 pub use background::Background;
 pub use border::Border;
 pub use clipboard::Clipboard;
@@ -57,6 +60,7 @@ pub use element::Element;
 pub use event::Event;
 pub use font::Font;
 pub use gradient::Gradient;
+pub use input_method::InputMethod;
 pub use layout::Layout;
 pub use length::Length;
 pub use overlay::Overlay;
@@ -192,6 +192,27 @@ where
                 self.operation.text_input(state, id);
             }
 
+            fn text(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                text: &str,
+            ) {
+                self.operation.text(id, bounds, text);
+            }
+
+            fn accessibility(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                node: &widget::operation::accessibility::Node,
+                requests: Option<
+                    &mut widget::operation::accessibility::Requests,
+                >,
+            ) {
+                self.operation.accessibility(id, bounds, node, requests);
+            }
+
             fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
                 self.operation.custom(state, id);
             }
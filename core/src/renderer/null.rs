@@ -77,6 +77,11 @@ impl text::Paragraph for () {
 
     fn with_text(_text: Text<&str>) -> Self {}
 
+    fn with_spans<Link>(
+        _text: Text<&[text::Span<'_, Link, Self::Font>], Self::Font>,
+    ) -> Self {
+    }
+
     fn resize(&mut self, _new_bounds: Size) {}
 
     fn compare(&self, _text: Text<&str>) -> text::Difference {
@@ -102,6 +107,14 @@ impl text::Paragraph for () {
     fn hit_test(&self, _point: Point) -> Option<text::Hit> {
         None
     }
+
+    fn hit_span(&self, _point: Point) -> Option<usize> {
+        None
+    }
+
+    fn span_bounds(&self, _index: usize) -> Vec<Rectangle> {
+        Vec::new()
+    }
 }
 
 impl text::Editor for () {
@@ -129,6 +142,14 @@ impl text::Editor for () {
         0
     }
 
+    fn folds(&self) -> Vec<std::ops::Range<usize>> {
+        Vec::new()
+    }
+
+    fn visible_lines(&self) -> Vec<(usize, f32)> {
+        Vec::new()
+    }
+
     fn perform(&mut self, _action: text::editor::Action) {}
 
     fn bounds(&self) -> Size {
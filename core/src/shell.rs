@@ -1,4 +1,5 @@
 use crate::window;
+use crate::InputMethod;
 
 /// A connection to the state of a shell.
 ///
@@ -10,6 +11,7 @@ use crate::window;
 pub struct Shell<'a, Message> {
     messages: &'a mut Vec<Message>,
     redraw_request: Option<window::RedrawRequest>,
+    input_method: InputMethod,
     is_layout_invalid: bool,
     are_widgets_invalid: bool,
 }
@@ -20,6 +22,7 @@ impl<'a, Message> Shell<'a, Message> {
         Self {
             messages,
             redraw_request: None,
+            input_method: InputMethod::Disabled,
             is_layout_invalid: false,
             are_widgets_invalid: false,
         }
@@ -53,6 +56,28 @@ impl<'a, Message> Shell<'a, Message> {
         self.redraw_request
     }
 
+    /// Requests the given [`InputMethod`] state.
+    ///
+    /// An enabled [`InputMethod`] takes precedence over a disabled one.
+    pub fn request_input_method(&mut self, input_method: InputMethod) {
+        if !self.input_method.is_enabled() {
+            self.input_method = input_method;
+        }
+    }
+
+    /// Returns the requested [`InputMethod`] state.
+    pub fn input_method(&self) -> InputMethod {
+        self.input_method
+    }
+
+    /// Returns a mutable reference to the requested [`InputMethod`] state.
+    ///
+    /// This is useful for widgets that translate the position of their
+    /// contents.
+    pub fn input_method_mut(&mut self) -> &mut InputMethod {
+        &mut self.input_method
+    }
+
     /// Returns whether the current layout is invalid or not.
     pub fn is_layout_invalid(&self) -> bool {
         self.is_layout_invalid
@@ -99,6 +124,8 @@ impl<'a, Message> Shell<'a, Message> {
             self.request_redraw(at);
         }
 
+        self.request_input_method(other.input_method);
+
         self.is_layout_invalid =
             self.is_layout_invalid || other.is_layout_invalid;
 
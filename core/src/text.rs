@@ -9,12 +9,13 @@ pub use highlighter::Highlighter;
 pub use paragraph::Paragraph;
 
 use crate::alignment;
-use crate::{Color, Pixels, Point, Rectangle, Size};
+use crate::{Background, Border, Color, Pixels, Point, Rectangle, Size};
 
+use std::borrow::Cow;
 use std::hash::{Hash, Hasher};
 
 /// A paragraph.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Text<Content = String, Font = crate::Font> {
     /// The content of the paragraph.
     pub content: Content,
@@ -161,6 +162,158 @@ pub enum Difference {
     Shape,
 }
 
+/// A span of text with its own style.
+///
+/// Spans are laid out together in a single paragraph. All of them share the
+/// size and line height of the paragraph.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span<'a, Link = (), Font = crate::Font> {
+    /// The text of the [`Span`].
+    pub text: Cow<'a, str>,
+    /// The font of the [`Span`].
+    ///
+    /// The default, `None`, means using the font of the paragraph.
+    pub font: Option<Font>,
+    /// The [`Color`] of the [`Span`].
+    ///
+    /// The default, `None`, means using the color of the paragraph.
+    pub color: Option<Color>,
+    /// The [`Highlight`] drawn behind the [`Span`], if any.
+    pub highlight: Option<Highlight>,
+    /// Whether the [`Span`] is underlined.
+    pub underline: bool,
+    /// Whether the [`Span`] is struck through.
+    pub strikethrough: bool,
+    /// The link of the [`Span`], if any.
+    pub link: Option<Link>,
+}
+
+impl<'a, Link, Font> Span<'a, Link, Font> {
+    /// Creates a new [`Span`] of text.
+    pub fn new(text: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            text: text.into(),
+            font: None,
+            color: None,
+            highlight: None,
+            underline: false,
+            strikethrough: false,
+            link: None,
+        }
+    }
+
+    /// Sets the font of the [`Span`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the font of the [`Span`], if `Some`.
+    pub fn font_maybe(mut self, font: Option<impl Into<Font>>) -> Self {
+        self.font = font.map(Into::into);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`], if `Some`.
+    pub fn color_maybe(mut self, color: Option<impl Into<Color>>) -> Self {
+        self.color = color.map(Into::into);
+        self
+    }
+
+    /// Sets the [`Background`] highlighting the [`Span`].
+    pub fn background(mut self, background: impl Into<Background>) -> Self {
+        let background = background.into();
+
+        match &mut self.highlight {
+            Some(highlight) => highlight.background = background,
+            None => {
+                self.highlight = Some(Highlight {
+                    background,
+                    border: Border::default(),
+                });
+            }
+        }
+
+        self
+    }
+
+    /// Sets the [`Border`] of the [`Highlight`] of the [`Span`].
+    ///
+    /// The [`Highlight`] is transparent unless a [`background`] is set.
+    ///
+    /// [`background`]: Self::background
+    pub fn border(mut self, border: impl Into<Border>) -> Self {
+        let highlight = self.highlight.get_or_insert(Highlight {
+            background: Background::Color(Color::TRANSPARENT),
+            border: Border::default(),
+        });
+
+        highlight.border = border.into();
+        self
+    }
+
+    /// Sets whether the [`Span`] is underlined.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Sets whether the [`Span`] is struck through.
+    pub fn strikethrough(mut self, strikethrough: bool) -> Self {
+        self.strikethrough = strikethrough;
+        self
+    }
+
+    /// Sets the link of the [`Span`].
+    pub fn link(mut self, link: impl Into<Link>) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+
+    /// Turns the [`Span`] into a static one, dropping its link.
+    pub fn to_static<T>(&self) -> Span<'static, T, Font>
+    where
+        Font: Copy,
+    {
+        Span {
+            text: Cow::Owned(self.text.clone().into_owned()),
+            font: self.font,
+            color: self.color,
+            highlight: self.highlight,
+            underline: self.underline,
+            strikethrough: self.strikethrough,
+            link: None,
+        }
+    }
+}
+
+impl<'a, Link, Font> From<&'a str> for Span<'a, Link, Font> {
+    fn from(text: &'a str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl<Link, Font> From<String> for Span<'_, Link, Font> {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+/// The background highlight of a [`Span`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Highlight {
+    /// The [`Background`] of the [`Highlight`].
+    pub background: Background,
+    /// The [`Border`] of the [`Highlight`].
+    pub border: Border,
+}
+
 /// A renderer capable of measuring and drawing [`Text`].
 pub trait Renderer: crate::Renderer {
     /// The font type used.
@@ -3,6 +3,7 @@ use crate::text::highlighter::{self, Highlighter};
 use crate::text::LineHeight;
 use crate::{Pixels, Point, Rectangle, Size};
 
+use std::ops::Range;
 use std::sync::Arc;
 
 /// A component that can be used by widgets to edit multi-line text.
@@ -16,6 +17,21 @@ pub trait Editor: Sized + Default {
     /// Returns the current [`Cursor`] of the [`Editor`].
     fn cursor(&self) -> Cursor;
 
+    /// Returns the [`Cursor`] the [`Editor`] would have if its cursor was
+    /// at the given position, selecting the text up to the given anchor.
+    ///
+    /// Positions are line and column, respectively. Returns `None` if the
+    /// text at the given positions has not been laid out yet.
+    ///
+    /// By default, no text is considered to be laid out.
+    fn cursor_at(
+        &self,
+        _position: (usize, usize),
+        _anchor: Option<(usize, usize)>,
+    ) -> Option<Cursor> {
+        None
+    }
+
     /// Returns the current cursor position of the [`Editor`].
     ///
     /// Line and column, respectively.
@@ -24,12 +40,48 @@ pub trait Editor: Sized + Default {
     /// Returns the current selected text of the [`Editor`].
     fn selection(&self) -> Option<String>;
 
+    /// Returns the position where the current selection of the [`Editor`]
+    /// started, if any.
+    ///
+    /// Line and column, respectively. The selection spans from this
+    /// position to the [`Editor::cursor_position`].
+    ///
+    /// By default, no anchor is reported.
+    fn selection_anchor(&self) -> Option<(usize, usize)> {
+        None
+    }
+
+    /// Moves the cursor of the [`Editor`] to the given position, selecting
+    /// the text up to the given anchor, if any.
+    ///
+    /// Positions are line and column, respectively, and are clamped to the
+    /// contents of the [`Editor`].
+    ///
+    /// By default, the cursor is left untouched.
+    fn set_cursor(
+        &mut self,
+        _position: (usize, usize),
+        _anchor: Option<(usize, usize)>,
+    ) {
+    }
+
     /// Returns the text of the given line in the [`Editor`], if it exists.
     fn line(&self, index: usize) -> Option<&str>;
 
     /// Returns the amount of lines in the [`Editor`].
     fn line_count(&self) -> usize;
 
+    /// Returns the ranges of lines of the [`Editor`] that are currently
+    /// folded.
+    ///
+    /// Folded lines are hidden behind the line before them, and they are
+    /// revealed once edited or reached by the cursor.
+    fn folds(&self) -> Vec<Range<usize>>;
+
+    /// Returns the index and vertical offset of the lines of the [`Editor`]
+    /// that start in its visible area, relative to the [`Editor`].
+    fn visible_lines(&self) -> Vec<(usize, f32)>;
+
     /// Performs an [`Action`] on the [`Editor`].
     fn perform(&mut self, action: Action);
 
@@ -81,12 +133,89 @@ pub enum Action {
         /// The amount of lines to scroll.
         lines: i32,
     },
+    /// Undo the last group of edits.
+    Undo,
+    /// Redo the last group of undone edits.
+    Redo,
+    /// Highlight the matches of the given [`Search`]; or stop searching,
+    /// if `None`.
+    Find(Option<Search>),
+    /// Select the next match of the current [`Search`].
+    FindNext,
+    /// Select the previous match of the current [`Search`].
+    FindPrevious,
+    /// Replace the selected match of the current [`Search`] with the given
+    /// text and select the next one.
+    ///
+    /// If the selection is not a match, the next match is selected instead.
+    Replace(Arc<String>),
+    /// Replace all the matches of the current [`Search`] with the given
+    /// text.
+    ReplaceAll(Arc<String>),
+    /// Add a new cursor at the given [`Point`], keeping the existing ones.
+    AddCursor(Point),
+    /// Select the next occurrence of the selected text with a new cursor.
+    ///
+    /// If no text is selected, the word at the cursor is selected instead.
+    SelectNextOccurrence,
+    /// Remove every cursor but the main one.
+    ClearCursors,
+    /// Hide the given range of lines, folding them into the line before it.
+    Fold(Range<usize>),
+    /// Reveal the lines folded into the line at the given index.
+    Unfold(usize),
 }
 
 impl Action {
     /// Returns whether the [`Action`] is an editing action.
     pub fn is_edit(&self) -> bool {
-        matches!(self, Self::Edit(_))
+        matches!(
+            self,
+            Self::Edit(_)
+                | Self::Undo
+                | Self::Redo
+                | Self::Replace(_)
+                | Self::ReplaceAll(_)
+        )
+    }
+}
+
+/// A text search in an [`Editor`].
+///
+/// Matches never span multiple lines.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Search {
+    /// The text to search for.
+    pub pattern: String,
+    /// Whether the pattern is a regular expression.
+    ///
+    /// The replacement text of a regular expression search may refer to
+    /// its capture groups; like `$1` or `${name}`.
+    pub is_regex: bool,
+    /// Whether letter case must match.
+    pub is_case_sensitive: bool,
+}
+
+impl Search {
+    /// Creates a new case insensitive [`Search`] for the given text.
+    pub fn new(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            is_regex: false,
+            is_case_sensitive: false,
+        }
+    }
+
+    /// Sets whether the pattern of the [`Search`] is a regular expression.
+    pub fn regex(mut self, is_regex: bool) -> Self {
+        self.is_regex = is_regex;
+        self
+    }
+
+    /// Sets whether the [`Search`] is case sensitive.
+    pub fn case_sensitive(mut self, is_case_sensitive: bool) -> Self {
+        self.is_case_sensitive = is_case_sensitive;
+        self
     }
 }
 
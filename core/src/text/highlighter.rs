@@ -40,6 +40,16 @@ pub trait Highlighter: 'static {
     /// If `change_line` has been called, this will normally be the least index
     /// that changed.
     fn current_line(&self) -> usize;
+
+    /// Returns the range of lines that can be folded into the line at the
+    /// given index, if known by the [`Highlighter`].
+    ///
+    /// An empty range means the line cannot be folded. By default, `None`
+    /// is returned and the range is inferred from the indentation of the
+    /// lines instead.
+    fn folding_range(&self, _line: usize) -> Option<Range<usize>> {
+        None
+    }
 }
 
 /// A highlighter that highlights nothing.
@@ -86,3 +96,65 @@ impl<Font> Default for Format<Font> {
         }
     }
 }
+
+/// A decoration of some range of text, independent of its highlighting.
+///
+/// Decorations are supplied by the application; for instance, to display
+/// the diagnostics of a linter or the results of a search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decoration {
+    /// The position where the decoration starts, as a line and a byte
+    /// index in that line.
+    pub start: (usize, usize),
+    /// The position where the decoration ends, as a line and a byte index
+    /// in that line.
+    pub end: (usize, usize),
+    /// The [`Underline`] of the decorated text, if any.
+    pub underline: Option<Underline>,
+    /// The background [`Color`] of the decorated text, if any.
+    pub background: Option<Color>,
+    /// The tooltip displayed when hovering the decorated text, if any.
+    pub tooltip: Option<String>,
+}
+
+impl Decoration {
+    /// Creates a new [`Decoration`] of the text between the given positions
+    /// that decorates nothing.
+    pub fn new(start: (usize, usize), end: (usize, usize)) -> Self {
+        Self {
+            start,
+            end,
+            underline: None,
+            background: None,
+            tooltip: None,
+        }
+    }
+
+    /// Sets the [`Underline`] of the [`Decoration`].
+    pub fn underline(mut self, underline: Underline) -> Self {
+        self.underline = Some(underline);
+        self
+    }
+
+    /// Sets the background [`Color`] of the [`Decoration`].
+    pub fn background(mut self, color: impl Into<Color>) -> Self {
+        self.background = Some(color.into());
+        self
+    }
+
+    /// Sets the tooltip of the [`Decoration`].
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+}
+
+/// The underline of a [`Decoration`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Underline {
+    /// A straight line of the given [`Color`].
+    Straight(Color),
+    /// A squiggly line of the given [`Color`]; normally used for errors
+    /// and warnings.
+    Squiggly(Color),
+}
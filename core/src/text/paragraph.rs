@@ -1,6 +1,6 @@
 use crate::alignment;
-use crate::text::{Difference, Hit, Text};
-use crate::{Point, Size};
+use crate::text::{Difference, Hit, Span, Text};
+use crate::{Point, Rectangle, Size};
 
 /// A text paragraph.
 pub trait Paragraph: Sized + Default {
@@ -10,6 +10,13 @@ pub trait Paragraph: Sized + Default {
     /// Creates a new [`Paragraph`] laid out with the given [`Text`].
     fn with_text(text: Text<&str, Self::Font>) -> Self;
 
+    /// Creates a new [`Paragraph`] laid out with the given [`Span`]s.
+    ///
+    /// The font of the [`Text`] is used for the spans without one.
+    fn with_spans<Link>(
+        text: Text<&[Span<'_, Link, Self::Font>], Self::Font>,
+    ) -> Self;
+
     /// Lays out the [`Paragraph`] with some new boundaries.
     fn resize(&mut self, new_bounds: Size);
 
@@ -31,6 +38,13 @@ pub trait Paragraph: Sized + Default {
     /// [`Paragraph`], returning information about the nearest character.
     fn hit_test(&self, point: Point) -> Option<Hit>;
 
+    /// Returns the index of the [`Span`] at the given point, if any.
+    fn hit_span(&self, point: Point) -> Option<usize>;
+
+    /// Returns the bounds of every line covered by the [`Span`] with the
+    /// given index, relative to the top-left corner of the [`Paragraph`].
+    fn span_bounds(&self, index: usize) -> Vec<Rectangle>;
+
     /// Returns the distance to the given grapheme index in the [`Paragraph`].
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point>;
 
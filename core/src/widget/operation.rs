@@ -1,4 +1,5 @@
 //! Query or update internal widget state.
+pub mod accessibility;
 pub mod focusable;
 pub mod scrollable;
 pub mod text_input;
@@ -44,6 +45,22 @@ pub trait Operation<T> {
     /// Operates on a widget that has text input.
     fn text_input(&mut self, _state: &mut dyn TextInput, _id: Option<&Id>) {}
 
+    /// Operates on a widget that contains some text.
+    fn text(&mut self, _id: Option<&Id>, _bounds: Rectangle, _text: &str) {}
+
+    /// Operates on a widget that can be described to assistive technologies.
+    ///
+    /// A widget that supports some [`accessibility::Action`] also exposes
+    /// the [`accessibility::Requests`] it has yet to perform.
+    fn accessibility(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _node: &accessibility::Node,
+        _requests: Option<&mut accessibility::Requests>,
+    ) {
+    }
+
     /// Operates on a custom widget with some state.
     fn custom(&mut self, _state: &mut dyn Any, _id: Option<&Id>) {}
 
@@ -104,61 +121,13 @@ where
             bounds: Rectangle,
             operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
         ) {
-            struct MapRef<'a, A> {
-                operation: &'a mut dyn Operation<A>,
-            }
-
-            impl<'a, A, B> Operation<B> for MapRef<'a, A> {
-                fn container(
-                    &mut self,
-                    id: Option<&Id>,
-                    bounds: Rectangle,
-                    operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
-                ) {
-                    let Self { operation, .. } = self;
-
-                    operation.container(id, bounds, &mut |operation| {
-                        operate_on_children(&mut MapRef { operation });
-                    });
-                }
-
-                fn scrollable(
-                    &mut self,
-                    state: &mut dyn Scrollable,
-                    id: Option<&Id>,
-                    bounds: Rectangle,
-                    translation: Vector,
-                ) {
-                    self.operation.scrollable(state, id, bounds, translation);
-                }
-
-                fn focusable(
-                    &mut self,
-                    state: &mut dyn Focusable,
-                    id: Option<&Id>,
-                ) {
-                    self.operation.focusable(state, id);
-                }
-
-                fn text_input(
-                    &mut self,
-                    state: &mut dyn TextInput,
-                    id: Option<&Id>,
-                ) {
-                    self.operation.text_input(state, id);
-                }
-
-                fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
-                    self.operation.custom(state, id);
-                }
-            }
-
             let Self { operation, .. } = self;
 
-            MapRef {
-                operation: operation.as_mut(),
-            }
-            .container(id, bounds, operate_on_children);
+            black_box(operation.as_mut()).container(
+                id,
+                bounds,
+                operate_on_children,
+            );
         }
 
         fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
@@ -179,6 +148,20 @@ where
             self.operation.text_input(state, id);
         }
 
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            self.operation.text(id, bounds, text);
+        }
+
+        fn accessibility(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            node: &accessibility::Node,
+            requests: Option<&mut accessibility::Requests>,
+        ) {
+            self.operation.accessibility(id, bounds, node, requests);
+        }
+
         fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
             self.operation.custom(state, id);
         }
@@ -201,6 +184,73 @@ where
     }
 }
 
+/// Wraps the [`Operation`] in a black box, erasing its returning type.
+///
+/// The resulting [`Operation`] can traverse any widget tree, but it never
+/// produces an [`Outcome`]; it must be obtained from the original
+/// [`Operation`] instead.
+pub fn black_box<'a, A, B>(
+    operation: &'a mut dyn Operation<A>,
+) -> impl Operation<B> + 'a {
+    #[allow(missing_debug_implementations)]
+    struct BlackBox<'a, A> {
+        operation: &'a mut dyn Operation<A>,
+    }
+
+    impl<'a, A, B> Operation<B> for BlackBox<'a, A> {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+        ) {
+            let Self { operation, .. } = self;
+
+            operation.container(id, bounds, &mut |operation| {
+                operate_on_children(&mut BlackBox { operation });
+            });
+        }
+
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            translation: Vector,
+        ) {
+            self.operation.scrollable(state, id, bounds, translation);
+        }
+
+        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+            self.operation.focusable(state, id);
+        }
+
+        fn text_input(&mut self, state: &mut dyn TextInput, id: Option<&Id>) {
+            self.operation.text_input(state, id);
+        }
+
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            self.operation.text(id, bounds, text);
+        }
+
+        fn accessibility(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            node: &accessibility::Node,
+            requests: Option<&mut accessibility::Requests>,
+        ) {
+            self.operation.accessibility(id, bounds, node, requests);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            self.operation.custom(state, id);
+        }
+    }
+
+    BlackBox { operation }
+}
+
 /// Produces an [`Operation`] that applies the given [`Operation`] to the
 /// children of a container with the given [`Id`].
 pub fn scope<T: 'static>(
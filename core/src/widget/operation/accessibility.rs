@@ -0,0 +1,575 @@
+//! Describe widgets to assistive technologies.
+use crate::widget::operation::Operation;
+use crate::widget::Id;
+use crate::{Rectangle, Vector};
+
+/// The description of a widget for assistive technologies, like screen
+/// readers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// The [`Role`] of the widget.
+    pub role: Role,
+    /// The label of the widget, if any.
+    pub label: Option<String>,
+    /// The current [`Value`] of the widget, if any.
+    pub value: Option<Value>,
+    /// Whether the widget is focused.
+    pub is_focused: bool,
+    /// Whether the widget is disabled.
+    pub is_disabled: bool,
+    /// Whether the widget is toggled, if it can be toggled.
+    pub is_toggled: Option<bool>,
+    /// The [`Action`]s the widget supports.
+    pub actions: Vec<Action>,
+}
+
+impl Node {
+    /// Creates a new [`Node`] with the given [`Role`].
+    pub fn new(role: Role) -> Self {
+        Self {
+            role,
+            label: None,
+            value: None,
+            is_focused: false,
+            is_disabled: false,
+            is_toggled: None,
+            actions: Vec::new(),
+        }
+    }
+
+    /// Sets the label of the [`Node`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the [`Value`] of the [`Node`].
+    pub fn value(mut self, value: impl Into<Value>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets whether the [`Node`] is focused.
+    pub fn focused(mut self, is_focused: bool) -> Self {
+        self.is_focused = is_focused;
+        self
+    }
+
+    /// Sets whether the [`Node`] is disabled.
+    ///
+    /// A disabled [`Node`] does not expose any [`Action`].
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets whether the [`Node`] is toggled.
+    pub fn toggled(mut self, is_toggled: bool) -> Self {
+        self.is_toggled = Some(is_toggled);
+        self
+    }
+
+    /// Adds an [`Action`] supported by the [`Node`].
+    pub fn action(mut self, action: Action) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Returns true if the [`Node`] supports the given [`Action`].
+    pub fn supports(&self, action: Action) -> bool {
+        !self.is_disabled && self.actions.contains(&action)
+    }
+}
+
+/// The role of a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Role {
+    Button,
+    CheckBox,
+    ComboBox,
+    Image,
+    Label,
+    MultilineTextInput,
+    ProgressIndicator,
+    RadioButton,
+    ScrollView,
+    Slider,
+    SpinButton,
+    Switch,
+    Tab,
+    TabList,
+    TextInput,
+}
+
+/// The value of a widget.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// Some text.
+    Text(String),
+    /// A number within a range.
+    Number {
+        /// The current number.
+        value: f64,
+        /// The minimum number allowed.
+        min: f64,
+        /// The maximum number allowed.
+        max: f64,
+        /// The amount the number changes with every step, if any.
+        step: Option<f64>,
+    },
+}
+
+impl From<String> for Value {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_owned())
+    }
+}
+
+/// An action that an assistive technology can request on a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// Focus the widget.
+    Focus,
+    /// Press the widget, like when clicking it.
+    Press,
+    /// Replace the [`Value`] of the widget.
+    SetValue,
+    /// Increment the numeric [`Value`] of the widget by one step.
+    Increment,
+    /// Decrement the numeric [`Value`] of the widget by one step.
+    Decrement,
+}
+
+/// A request of an assistive technology to perform an [`Action`] on a
+/// widget.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Request {
+    /// Focus the widget.
+    Focus,
+    /// Press the widget, like when clicking it.
+    Press,
+    /// Replace the text of the widget.
+    SetText(String),
+    /// Replace the numeric value of the widget.
+    SetNumber(f64),
+    /// Increment the numeric value of the widget by one step.
+    Increment,
+    /// Decrement the numeric value of the widget by one step.
+    Decrement,
+}
+
+impl Request {
+    /// Returns the [`Action`] of the [`Request`].
+    pub fn action(&self) -> Action {
+        match self {
+            Self::Focus => Action::Focus,
+            Self::Press => Action::Press,
+            Self::SetText(_) | Self::SetNumber(_) => Action::SetValue,
+            Self::Increment => Action::Increment,
+            Self::Decrement => Action::Decrement,
+        }
+    }
+}
+
+/// The [`Request`]s of assistive technologies that a widget has yet to
+/// perform.
+///
+/// A widget that supports some [`Action`] keeps its [`Requests`] in its
+/// state and exposes them when describing itself, so a [`perform`] operation
+/// can fill them. The widget performs them the next time it processes an
+/// [`Event`], like a redraw request.
+///
+/// [`Event`]: crate::Event
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Requests(Vec<Request>);
+
+impl Requests {
+    /// Adds a [`Request`] to perform.
+    pub fn push(&mut self, request: Request) {
+        self.0.push(request);
+    }
+
+    /// Takes the [`Request`]s to perform, in the order they were made.
+    pub fn take(&mut self) -> Vec<Request> {
+        std::mem::take(&mut self.0)
+    }
+}
+
+/// The position of a [`Node`] in the accessibility tree; an index for each
+/// level of the tree.
+pub type Path = Vec<usize>;
+
+/// The widget targeted by a [`perform`] operation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Target {
+    /// The widget with the given [`Id`].
+    Id(Id),
+    /// The widget described by the [`Node`] at the given [`Path`].
+    Path(Path),
+}
+
+/// A [`Node`] found in a user interface, together with its descendants.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    /// The [`Id`] of the widget, if any.
+    pub id: Option<Id>,
+    /// The [`Path`] of the [`Node`] in the accessibility tree.
+    pub path: Path,
+    /// The bounds of the widget, in window coordinates.
+    pub bounds: Rectangle,
+    /// The [`Node`] describing the widget.
+    pub node: Node,
+    /// The descendants of the widget.
+    pub children: Vec<Entry>,
+}
+
+impl Entry {
+    /// Returns the label of the [`Entry`], falling back to the labels of its
+    /// descendants if it has none.
+    pub fn label(&self) -> Option<String> {
+        if let Some(label) = &self.node.label {
+            return Some(label.clone());
+        }
+
+        let labels: Vec<String> =
+            self.children.iter().filter_map(Entry::label).collect();
+
+        (!labels.is_empty()).then(|| labels.join(" "))
+    }
+}
+
+/// Keeps track of the [`Path`] of the [`Node`]s described by the widgets of
+/// a user interface.
+///
+/// A widget that describes itself and then operates on its children as a
+/// container, with the same bounds, becomes the parent of the [`Node`]s
+/// described by them. Any other container is transparent.
+#[derive(Debug, Default)]
+struct Tracker {
+    parent: Path,
+    next: usize,
+    last: Option<Rectangle>,
+}
+
+impl Tracker {
+    /// Returns the [`Path`] of a new [`Node`] with the given bounds.
+    fn node(&mut self, bounds: Rectangle) -> Path {
+        let mut path = self.parent.clone();
+        path.push(self.next);
+
+        self.next += 1;
+        self.last = Some(bounds);
+
+        path
+    }
+
+    /// Enters a container with the given bounds, returning what is needed
+    /// to [`exit`](Self::exit) it.
+    fn enter(&mut self, bounds: Rectangle) -> Option<usize> {
+        if self.last.take() != Some(bounds) {
+            return None;
+        }
+
+        self.parent.push(self.next - 1);
+
+        Some(std::mem::replace(&mut self.next, 0))
+    }
+
+    fn exit(&mut self, entered: Option<usize>) {
+        if let Some(next) = entered {
+            let _ = self.parent.pop();
+            self.next = next;
+        }
+
+        self.last = None;
+    }
+}
+
+/// An [`Operation`] that collects the accessibility tree of a user interface.
+#[derive(Debug, Default)]
+pub struct Collect {
+    roots: Vec<Entry>,
+    tracker: Tracker,
+    translation: Vector,
+    pending_translation: Option<Vector>,
+}
+
+impl Collect {
+    /// Creates a new [`Collect`] operation.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Finishes the [`Collect`] operation and returns the root entries of
+    /// the accessibility tree.
+    pub fn finish(self) -> Vec<Entry> {
+        self.roots
+    }
+}
+
+impl<T> Operation<T> for Collect {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        let offset = self.pending_translation.take().unwrap_or(Vector::ZERO);
+        let entered = self.tracker.enter(bounds);
+
+        self.translation = self.translation + offset;
+        operate_on_children(self);
+        self.translation = self.translation - offset;
+
+        self.tracker.exit(entered);
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn super::Scrollable,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.pending_translation = Some(translation);
+    }
+
+    fn accessibility(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        node: &Node,
+        _requests: Option<&mut Requests>,
+    ) {
+        let path = self.tracker.node(bounds);
+
+        let Some((index, ancestors)) = path.split_last() else {
+            return;
+        };
+
+        let mut siblings = &mut self.roots;
+
+        for ancestor in ancestors {
+            let Some(parent) = siblings.get_mut(*ancestor) else {
+                return;
+            };
+
+            siblings = &mut parent.children;
+        }
+
+        debug_assert_eq!(siblings.len(), *index);
+
+        siblings.push(Entry {
+            id: id.cloned(),
+            path,
+            bounds: bounds - self.translation,
+            node: node.clone(),
+            children: Vec::new(),
+        });
+    }
+}
+
+/// Produces an [`Operation`] that sends the given [`Request`] to the widget
+/// at the given [`Target`], if it supports its [`Action`].
+///
+/// A [`Request::Focus`] also unfocuses any other widget.
+pub fn perform<T>(target: Target, request: Request) -> impl Operation<T> {
+    struct Perform {
+        target: Target,
+        request: Option<Request>,
+        is_focus: bool,
+        tracker: Tracker,
+    }
+
+    impl<T> Operation<T> for Perform {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            let entered = self.tracker.enter(bounds);
+
+            operate_on_children(self);
+
+            self.tracker.exit(entered);
+        }
+
+        fn focusable(
+            &mut self,
+            state: &mut dyn super::Focusable,
+            _id: Option<&Id>,
+        ) {
+            if self.is_focus {
+                state.unfocus();
+            }
+        }
+
+        fn accessibility(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            node: &Node,
+            requests: Option<&mut Requests>,
+        ) {
+            let path = self.tracker.node(bounds);
+
+            let is_target = match &self.target {
+                Target::Id(target) => id == Some(target),
+                Target::Path(target) => *target == path,
+            };
+
+            if !is_target {
+                return;
+            }
+
+            match (requests, self.request.take()) {
+                (Some(requests), Some(request))
+                    if node.supports(request.action()) =>
+                {
+                    requests.push(request);
+                }
+                (_, request) => {
+                    self.request = request;
+                }
+            }
+        }
+    }
+
+    Perform {
+        target,
+        is_focus: request == Request::Focus,
+        request: Some(request),
+        tracker: Tracker::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::operation::Focusable;
+    use crate::{Point, Size};
+
+    fn button() -> Node {
+        Node::new(Role::Button).action(Action::Press)
+    }
+
+    #[test]
+    fn containers_with_the_bounds_of_a_node_nest_its_children() {
+        let outer = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let inner = Rectangle::new(Point::ORIGIN, Size::new(50.0, 50.0));
+
+        let mut collect = Collect::new();
+
+        Operation::<()>::accessibility(
+            &mut collect,
+            None,
+            outer,
+            &button(),
+            None,
+        );
+
+        Operation::<()>::container(&mut collect, None, outer, &mut |op| {
+            op.accessibility(None, inner, &Node::new(Role::Label), None);
+
+            // Containers with other bounds are transparent
+            op.container(None, outer, &mut |op| {
+                op.accessibility(None, inner, &Node::new(Role::Label), None);
+            });
+        });
+
+        Operation::<()>::accessibility(
+            &mut collect,
+            None,
+            inner,
+            &button(),
+            None,
+        );
+
+        let roots = collect.finish();
+
+        assert_eq!(roots.len(), 2);
+        assert_eq!(roots[0].path, [0]);
+        assert_eq!(roots[1].path, [1]);
+
+        let children = &roots[0].children;
+
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].path, [0, 0]);
+        assert_eq!(children[1].path, [0, 1]);
+        assert!(roots[1].children.is_empty());
+    }
+
+    #[test]
+    fn perform_sends_requests_to_the_target() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(10.0, 10.0));
+        let mut requests = [Requests::default(), Requests::default()];
+
+        let mut describe = |operation: &mut dyn Operation<()>| {
+            for (index, requests) in requests.iter_mut().enumerate() {
+                let id = Id::new(if index == 0 { "first" } else { "second" });
+
+                operation.accessibility(
+                    Some(&id),
+                    bounds,
+                    &button(),
+                    Some(requests),
+                );
+            }
+        };
+
+        describe(&mut perform(Target::Path(vec![1]), Request::Press));
+        describe(&mut perform(Target::Id(Id::new("first")), Request::Press));
+
+        // Unsupported actions are ignored
+        describe(&mut perform(Target::Path(vec![0]), Request::Increment));
+
+        assert_eq!(requests[0].take(), [Request::Press]);
+        assert_eq!(requests[1].take(), [Request::Press]);
+    }
+
+    #[test]
+    fn focus_requests_unfocus_other_widgets() {
+        #[derive(Default)]
+        struct State(bool);
+
+        impl Focusable for State {
+            fn is_focused(&self) -> bool {
+                self.0
+            }
+
+            fn focus(&mut self) {
+                self.0 = true;
+            }
+
+            fn unfocus(&mut self) {
+                self.0 = false;
+            }
+        }
+
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(10.0, 10.0));
+        let mut focused = State(true);
+        let mut requests = Requests::default();
+
+        let mut operation = perform(Target::Path(vec![0]), Request::Focus);
+
+        Operation::<()>::accessibility(
+            &mut operation,
+            None,
+            bounds,
+            &Node::new(Role::TextInput).action(Action::Focus),
+            Some(&mut requests),
+        );
+        Operation::<()>::focusable(&mut operation, &mut focused, None);
+
+        assert!(!focused.is_focused());
+        assert_eq!(requests.take(), [Request::Focus]);
+    }
+}
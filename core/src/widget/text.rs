@@ -4,7 +4,9 @@ use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text::{self, Paragraph};
+use crate::widget::operation::accessibility;
 use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
 use crate::{
     Color, Element, Layout, Length, Pixels, Point, Rectangle, Size, Theme,
     Widget,
@@ -194,6 +196,23 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        _state: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.text(None, layout.bounds(), &self.fragment);
+        operation.accessibility(
+            None,
+            layout.bounds(),
+            &accessibility::Node::new(accessibility::Role::Label)
+                .label(self.fragment.as_ref()),
+            None,
+        );
+    }
+
     fn draw(
         &self,
         tree: &Tree,
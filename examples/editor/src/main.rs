@@ -150,7 +150,7 @@ impl Editor {
             horizontal_space(),
             pick_list(
                 highlighter::Theme::ALL,
-                Some(self.theme),
+                Some(&self.theme),
                 Message::ThemeSelected
             )
             .text_size(14)
@@ -187,7 +187,7 @@ impl Editor {
                 .on_action(Message::ActionPerformed)
                 .highlight::<Highlighter>(
                     highlighter::Settings {
-                        theme: self.theme,
+                        theme: self.theme.clone(),
                         extension: self
                             .file
                             .as_deref()
@@ -195,6 +195,7 @@ impl Editor {
                             .and_then(ffi::OsStr::to_str)
                             .map(str::to_string)
                             .unwrap_or(String::from("rs")),
+                        syntaxes: highlighter::Syntaxes::default(),
                     },
                     |highlight, _theme| highlight.to_format()
                 ),
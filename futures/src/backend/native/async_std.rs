@@ -30,6 +30,11 @@ pub mod time {
         Subscription::from_recipe(Every(duration))
     }
 
+    /// Returns a future that completes after the given `duration`.
+    pub(crate) fn sleep(duration: std::time::Duration) -> crate::BoxFuture<()> {
+        Box::pin(async_std::task::sleep(duration))
+    }
+
     #[derive(Debug)]
     struct Every(std::time::Duration);
 
@@ -34,6 +34,11 @@ pub mod time {
         Subscription::from_recipe(Every(duration))
     }
 
+    /// Returns a future that completes after the given `duration`.
+    pub(crate) fn sleep(duration: std::time::Duration) -> crate::BoxFuture<()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+
     #[derive(Debug)]
     struct Every(std::time::Duration);
 
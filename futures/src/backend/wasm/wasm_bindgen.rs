@@ -29,6 +29,13 @@ pub mod time {
         Subscription::from_recipe(Every(duration))
     }
 
+    /// Returns a future that completes after the given `duration`.
+    pub(crate) fn sleep(duration: std::time::Duration) -> crate::BoxFuture<()> {
+        Box::pin(async move {
+            let _ = wasm_timer::Delay::new(duration).await;
+        })
+    }
+
     #[derive(Debug)]
     struct Every(std::time::Duration);
 
@@ -0,0 +1,228 @@
+//! Watch the filesystem for changes.
+use crate::subscription::{self, Subscription};
+
+use futures::channel::mpsc;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// A change in the filesystem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// A file or directory was created.
+    Created(PathBuf),
+
+    /// A file or directory was modified.
+    Modified(PathBuf),
+
+    /// A file or directory was removed.
+    Removed(PathBuf),
+}
+
+/// An error produced while watching the filesystem.
+#[derive(Debug, Clone)]
+pub struct Error(Arc<notify::Error>);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Returns a [`Subscription`] that watches the file or directory at the
+/// given path and produces an [`Event`] every time it changes.
+///
+/// If the path is a directory, only its direct children will be watched.
+/// Use [`watch_recursive`] to watch all of its descendants.
+///
+/// The watcher runs on its own thread and, therefore, it works with any
+/// executor.
+pub fn watch(path: impl AsRef<Path>) -> Subscription<Result<Event, Error>> {
+    watch_with(path.as_ref().to_path_buf(), false)
+}
+
+/// Returns a [`Subscription`] that watches the directory at the given path,
+/// including all of its descendants, and produces an [`Event`] every time
+/// any of them changes.
+pub fn watch_recursive(
+    path: impl AsRef<Path>,
+) -> Subscription<Result<Event, Error>> {
+    watch_with(path.as_ref().to_path_buf(), true)
+}
+
+fn watch_with(
+    path: PathBuf,
+    recursive: bool,
+) -> Subscription<Result<Event, Error>> {
+    use futures::future;
+    use futures::{SinkExt, StreamExt};
+    use notify::Watcher;
+
+    #[derive(Hash)]
+    struct Watch;
+
+    subscription::channel(
+        (Watch, path.clone(), recursive),
+        100,
+        move |mut output| async move {
+            let (sender, mut receiver) = mpsc::unbounded();
+
+            let watcher = notify::recommended_watcher(move |result| {
+                let _ = sender.unbounded_send(result);
+            })
+            .and_then(|mut watcher| {
+                watcher.watch(
+                    &path,
+                    if recursive {
+                        notify::RecursiveMode::Recursive
+                    } else {
+                        notify::RecursiveMode::NonRecursive
+                    },
+                )?;
+
+                Ok(watcher)
+            });
+
+            match watcher {
+                Ok(_watcher) => {
+                    while let Some(result) = receiver.next().await {
+                        match result {
+                            Ok(event) => {
+                                for event in events(event) {
+                                    let _ = output.send(Ok(event)).await;
+                                }
+                            }
+                            Err(error) => {
+                                let _ = output
+                                    .send(Err(Error(Arc::new(error))))
+                                    .await;
+                            }
+                        }
+                    }
+                }
+                Err(error) => {
+                    let _ = output.send(Err(Error(Arc::new(error)))).await;
+                }
+            }
+
+            future::pending().await
+        },
+    )
+}
+
+fn events(event: notify::Event) -> Vec<Event> {
+    use notify::event::{EventKind, ModifyKind, RenameMode};
+
+    let mut paths = event.paths.into_iter();
+
+    match event.kind {
+        EventKind::Create(_) => paths.map(Event::Created).collect(),
+        EventKind::Remove(_) => paths.map(Event::Removed).collect(),
+        EventKind::Modify(ModifyKind::Name(RenameMode::From)) => {
+            paths.map(Event::Removed).collect()
+        }
+        EventKind::Modify(ModifyKind::Name(RenameMode::To)) => {
+            paths.map(Event::Created).collect()
+        }
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => paths
+            .next()
+            .map(Event::Removed)
+            .into_iter()
+            .chain(paths.map(Event::Created))
+            .collect(),
+        EventKind::Modify(_) => paths.map(Event::Modified).collect(),
+        EventKind::Access(_) | EventKind::Any | EventKind::Other => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use notify::event::{
+        AccessKind, CreateKind, DataChange, EventKind, ModifyKind, RemoveKind,
+        RenameMode,
+    };
+
+    fn notify_event(kind: EventKind, paths: &[&str]) -> notify::Event {
+        paths.iter().fold(notify::Event::new(kind), |event, path| {
+            event.add_path(PathBuf::from(path))
+        })
+    }
+
+    fn path(path: &str) -> PathBuf {
+        PathBuf::from(path)
+    }
+
+    #[test]
+    fn creations_and_removals() {
+        assert_eq!(
+            events(notify_event(
+                EventKind::Create(CreateKind::File),
+                &["a", "b"]
+            )),
+            [Event::Created(path("a")), Event::Created(path("b"))]
+        );
+
+        assert_eq!(
+            events(notify_event(EventKind::Remove(RemoveKind::Any), &["a"])),
+            [Event::Removed(path("a"))]
+        );
+    }
+
+    #[test]
+    fn modifications() {
+        assert_eq!(
+            events(notify_event(
+                EventKind::Modify(ModifyKind::Data(DataChange::Content)),
+                &["config.toml"]
+            )),
+            [Event::Modified(path("config.toml"))]
+        );
+
+        assert_eq!(
+            events(notify_event(EventKind::Modify(ModifyKind::Any), &["a"])),
+            [Event::Modified(path("a"))]
+        );
+    }
+
+    #[test]
+    fn renames() {
+        assert_eq!(
+            events(notify_event(
+                EventKind::Modify(ModifyKind::Name(RenameMode::From)),
+                &["old"]
+            )),
+            [Event::Removed(path("old"))]
+        );
+
+        assert_eq!(
+            events(notify_event(
+                EventKind::Modify(ModifyKind::Name(RenameMode::To)),
+                &["new"]
+            )),
+            [Event::Created(path("new"))]
+        );
+
+        assert_eq!(
+            events(notify_event(
+                EventKind::Modify(ModifyKind::Name(RenameMode::Both)),
+                &["old", "new"]
+            )),
+            [Event::Removed(path("old")), Event::Created(path("new"))]
+        );
+    }
+
+    #[test]
+    fn accesses_are_ignored() {
+        assert_eq!(
+            events(notify_event(EventKind::Access(AccessKind::Any), &["a"])),
+            []
+        );
+
+        assert_eq!(events(notify_event(EventKind::Any, &["a"])), []);
+        assert_eq!(events(notify_event(EventKind::Other, &["a"])), []);
+    }
+}
@@ -17,6 +17,12 @@ pub mod executor;
 pub mod keyboard;
 pub mod subscription;
 
+#[cfg(all(feature = "fs", not(target_arch = "wasm32")))]
+pub mod fs;
+
+#[cfg(all(feature = "process", not(target_arch = "wasm32")))]
+pub mod process;
+
 pub use executor::Executor;
 pub use maybe::{MaybeSend, MaybeSync};
 pub use platform::*;
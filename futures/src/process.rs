@@ -0,0 +1,431 @@
+//! Run child processes and listen to their output.
+use crate::subscription::{self, Subscription};
+
+use futures::channel::mpsc;
+use shared_child::SharedChild;
+use std::ffi::{OsStr, OsString};
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitStatus, Stdio};
+use std::sync::Arc;
+use std::thread;
+
+/// The description of a child process to run.
+///
+/// It identifies the [`Subscription`] returned by [`run`]; therefore, the
+/// same [`Process`] will only be spawned once while it is being subscribed
+/// to. Use [`Subscription::with`] to run multiple instances of it at once.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Process {
+    program: OsString,
+    args: Vec<OsString>,
+    envs: Vec<(OsString, OsString)>,
+    current_dir: Option<PathBuf>,
+}
+
+impl Process {
+    /// Creates a new [`Process`] that will run the given program.
+    pub fn new(program: impl AsRef<OsStr>) -> Self {
+        Self {
+            program: program.as_ref().to_os_string(),
+            args: Vec::new(),
+            envs: Vec::new(),
+            current_dir: None,
+        }
+    }
+
+    /// Adds an argument to the [`Process`].
+    pub fn arg(mut self, arg: impl AsRef<OsStr>) -> Self {
+        self.args.push(arg.as_ref().to_os_string());
+        self
+    }
+
+    /// Adds multiple arguments to the [`Process`].
+    pub fn args(
+        mut self,
+        args: impl IntoIterator<Item = impl AsRef<OsStr>>,
+    ) -> Self {
+        self.args
+            .extend(args.into_iter().map(|arg| arg.as_ref().to_os_string()));
+        self
+    }
+
+    /// Sets an environment variable for the [`Process`].
+    pub fn env(
+        mut self,
+        key: impl AsRef<OsStr>,
+        value: impl AsRef<OsStr>,
+    ) -> Self {
+        self.envs
+            .push((key.as_ref().to_os_string(), value.as_ref().to_os_string()));
+        self
+    }
+
+    /// Sets the working directory of the [`Process`].
+    pub fn current_dir(mut self, dir: impl AsRef<Path>) -> Self {
+        self.current_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    fn spawn(&self) -> io::Result<SharedChild> {
+        let mut command = process::Command::new(&self.program);
+
+        let _ = command
+            .args(&self.args)
+            .envs(self.envs.iter().map(|(key, value)| (key, value)))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        if let Some(dir) = &self.current_dir {
+            let _ = command.current_dir(dir);
+        }
+
+        SharedChild::spawn(&mut command)
+    }
+}
+
+/// An event produced by a running [`Process`].
+#[derive(Debug, Clone)]
+pub enum Event {
+    /// The [`Process`] was spawned.
+    ///
+    /// The [`Handle`] can be used to interact with it.
+    Spawned(Handle),
+
+    /// The [`Process`] wrote a line to its standard output.
+    Stdout(String),
+
+    /// The [`Process`] wrote a line to its standard error.
+    Stderr(String),
+
+    /// The [`Process`] exited with the given [`ExitStatus`].
+    Exited(ExitStatus),
+
+    /// The [`Process`] could not be spawned or waited on.
+    Failed(Error),
+}
+
+/// A handle to a running [`Process`].
+#[derive(Debug, Clone)]
+pub struct Handle {
+    sender: mpsc::UnboundedSender<Input>,
+}
+
+impl Handle {
+    /// Writes the given bytes to the standard input of the [`Process`].
+    pub fn write(&self, bytes: impl Into<Vec<u8>>) {
+        let _ = self.sender.unbounded_send(Input::Write(bytes.into()));
+    }
+
+    /// Closes the standard input of the [`Process`].
+    pub fn close_stdin(&self) {
+        let _ = self.sender.unbounded_send(Input::Close);
+    }
+
+    /// Kills the [`Process`].
+    pub fn kill(&self) {
+        let _ = self.sender.unbounded_send(Input::Kill);
+    }
+}
+
+/// An error produced by a [`Process`].
+#[derive(Debug, Clone)]
+pub struct Error(Arc<io::Error>);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug)]
+enum Input {
+    Write(Vec<u8>),
+    Close,
+    Kill,
+}
+
+/// Returns a [`Subscription`] that spawns the given [`Process`] and produces
+/// an [`Event`] for every line of its output.
+///
+/// The first [`Event`] will be [`Event::Spawned`], which contains the
+/// [`Handle`] of the [`Process`]. Once the [`Process`] finishes,
+/// [`Event::Exited`] is produced after all of its output.
+///
+/// The [`Process`] is killed when the [`Subscription`] is dropped.
+///
+/// The [`Process`] is driven by its own threads and, therefore, it works
+/// with any executor.
+pub fn run(process: Process) -> Subscription<Event> {
+    use futures::future;
+    use futures::{SinkExt, StreamExt};
+
+    subscription::channel(process.clone(), 100, move |mut output| async move {
+        let child = match process.spawn() {
+            Ok(child) => child,
+            Err(error) => {
+                let _ =
+                    output.send(Event::Failed(Error(Arc::new(error)))).await;
+
+                return future::pending().await;
+            }
+        };
+
+        let (sender, mut receiver) = mpsc::unbounded();
+        let (input, inputs) = mpsc::unbounded();
+
+        let readers = [
+            child.take_stdout().map(|stdout| {
+                read_lines(stdout, sender.clone(), Event::Stdout)
+            }),
+            child.take_stderr().map(|stderr| {
+                read_lines(stderr, sender.clone(), Event::Stderr)
+            }),
+        ];
+
+        let stdin = child.take_stdin();
+        let child = Arc::new(child);
+
+        let _ = thread::spawn({
+            let child = child.clone();
+
+            move || write_input(stdin, inputs, &child)
+        });
+
+        let _ = thread::spawn({
+            let child = child.clone();
+            let input = input.clone();
+
+            move || {
+                let result = child.wait();
+
+                // Stop writing any input once the process is gone
+                input.close_channel();
+
+                for reader in readers.into_iter().flatten() {
+                    let _ = reader.join();
+                }
+
+                let _ = sender.unbounded_send(match result {
+                    Ok(status) => Event::Exited(status),
+                    Err(error) => Event::Failed(Error(Arc::new(error))),
+                });
+            }
+        });
+
+        let _guard = Guard(child);
+
+        let _ = output.send(Event::Spawned(Handle { sender: input })).await;
+
+        while let Some(event) = receiver.next().await {
+            let _ = output.send(event).await;
+        }
+
+        future::pending().await
+    })
+}
+
+struct Guard(Arc<SharedChild>);
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+    }
+}
+
+fn read_lines(
+    reader: impl io::Read + Send + 'static,
+    sender: mpsc::UnboundedSender<Event>,
+    to_event: fn(String) -> Event,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        let mut reader = io::BufReader::new(reader);
+        let mut line = Vec::new();
+
+        while let Ok(read) = reader.read_until(b'\n', &mut line) {
+            if read == 0 {
+                break;
+            }
+
+            if line.ends_with(b"\n") {
+                let _ = line.pop();
+
+                if line.ends_with(b"\r") {
+                    let _ = line.pop();
+                }
+            }
+
+            let _ = sender.unbounded_send(to_event(
+                String::from_utf8_lossy(&line).into_owned(),
+            ));
+
+            line.clear();
+        }
+    })
+}
+
+fn write_input(
+    mut stdin: Option<process::ChildStdin>,
+    mut inputs: mpsc::UnboundedReceiver<Input>,
+    child: &SharedChild,
+) {
+    use futures::StreamExt;
+
+    while let Some(input) = futures::executor::block_on(inputs.next()) {
+        match input {
+            Input::Write(bytes) => {
+                if let Some(writer) = &mut stdin {
+                    if writer
+                        .write_all(&bytes)
+                        .and_then(|()| writer.flush())
+                        .is_err()
+                    {
+                        stdin = None;
+                    }
+                }
+            }
+            Input::Close => {
+                stdin = None;
+            }
+            Input::Kill => {
+                let _ = child.kill();
+            }
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    use futures::executor;
+    use futures::stream::{self, StreamExt};
+
+    /// Runs the given [`Process`] until it exits, returning its [`Handle`]
+    /// and every other [`Event`] it produced.
+    fn run_until_exit(
+        process: Process,
+        on_spawn: impl FnOnce(&Handle),
+    ) -> (Handle, Vec<Event>) {
+        let mut recipes = run(process).into_recipes();
+        let recipe = recipes.pop().expect("Subscription should have a recipe");
+
+        let mut events = recipe.stream(stream::empty().boxed());
+
+        executor::block_on(async move {
+            let Some(Event::Spawned(handle)) = events.next().await else {
+                panic!("Process should be spawned first");
+            };
+
+            on_spawn(&handle);
+
+            let mut produced = Vec::new();
+
+            while let Some(event) = events.next().await {
+                let is_exit = matches!(event, Event::Exited(_));
+
+                produced.push(event);
+
+                if is_exit {
+                    break;
+                }
+            }
+
+            (handle, produced)
+        })
+    }
+
+    fn lines(events: &[Event]) -> (Vec<&str>, Vec<&str>) {
+        let stdout = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Stdout(line) => Some(line.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        let stderr = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Stderr(line) => Some(line.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        (stdout, stderr)
+    }
+
+    fn exit_status(events: &[Event]) -> ExitStatus {
+        match events.last() {
+            Some(Event::Exited(status)) => *status,
+            event => panic!("Process should have exited, found: {event:?}"),
+        }
+    }
+
+    #[test]
+    fn output_is_produced_before_exiting() {
+        let (handle, events) = run_until_exit(
+            Process::new("sh")
+                .arg("-c")
+                .arg("echo hello; echo world; echo oops >&2; exit 3"),
+            |_| {},
+        );
+
+        assert_eq!(lines(&events), (vec!["hello", "world"], vec!["oops"]));
+        assert_eq!(exit_status(&events).code(), Some(3));
+
+        // The input of an exited process is closed
+        assert!(handle.sender.is_closed());
+    }
+
+    #[test]
+    fn input_is_written_to_stdin() {
+        let (_handle, events) = run_until_exit(
+            Process::new("sh")
+                .arg("-c")
+                .arg("read line; echo \"got $line\""),
+            |handle| handle.write("iced\n"),
+        );
+
+        assert_eq!(lines(&events), (vec!["got iced"], vec![]));
+        assert!(exit_status(&events).success());
+    }
+
+    #[test]
+    fn closing_stdin_ends_input() {
+        let (_handle, events) = run_until_exit(Process::new("cat"), |handle| {
+            handle.write("one\ntwo\n");
+            handle.close_stdin();
+        });
+
+        assert_eq!(lines(&events), (vec!["one", "two"], vec![]));
+        assert!(exit_status(&events).success());
+    }
+
+    #[test]
+    fn killed_processes_exit() {
+        let (_handle, events) =
+            run_until_exit(Process::new("sleep").arg("60"), Handle::kill);
+
+        assert_eq!(events.len(), 1);
+        assert!(!exit_status(&events).success());
+    }
+
+    #[test]
+    fn missing_programs_fail() {
+        let mut recipes = run(Process::new("iced-this-program-does-not-exist"))
+            .into_recipes();
+
+        let recipe = recipes.pop().expect("Subscription should have a recipe");
+        let mut events = recipe.stream(stream::empty().boxed());
+
+        assert!(matches!(
+            executor::block_on(events.next()),
+            Some(Event::Failed(_))
+        ));
+    }
+}
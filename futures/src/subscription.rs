@@ -12,6 +12,14 @@ use futures::never::Never;
 use std::any::TypeId;
 use std::hash::Hash;
 
+#[cfg(any(
+    feature = "tokio",
+    feature = "async-std",
+    feature = "smol",
+    target_arch = "wasm32"
+))]
+use std::time::Duration;
+
 /// A stream of runtime events.
 ///
 /// It is the input of a [`Subscription`].
@@ -118,6 +126,122 @@ impl<Message> Subscription<Message> {
                 .collect(),
         }
     }
+
+    /// Transforms the [`Subscription`] output with the given function,
+    /// discarding any output for which it returns `None`.
+    ///
+    /// # Panics
+    /// The closure provided must be a non-capturing closure. The method
+    /// will panic in debug mode otherwise.
+    pub fn filter_map<F, A>(mut self, f: F) -> Subscription<A>
+    where
+        Message: 'static,
+        F: Fn(Message) -> Option<A> + MaybeSend + Clone + 'static,
+        A: MaybeSend + 'static,
+    {
+        debug_assert!(
+            std::mem::size_of::<F>() == 0,
+            "the closure {} provided in `Subscription::filter_map` is capturing",
+            std::any::type_name::<F>(),
+        );
+
+        Subscription {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(move |recipe| {
+                    Box::new(FilterMap::new(recipe, f.clone()))
+                        as Box<dyn Recipe<Output = A>>
+                })
+                .collect(),
+        }
+    }
+
+    /// Debounces the [`Subscription`] by the given [`Duration`].
+    ///
+    /// An output will only be produced once the [`Subscription`] has stayed
+    /// silent for the given [`Duration`]; in which case, only the latest
+    /// output is produced.
+    ///
+    /// This is useful to react to a burst of events only once it is over;
+    /// like waiting for the user to stop typing before searching.
+    ///
+    /// It needs the timer of an async backend and, therefore, it is only
+    /// available when the `tokio`, `async-std`, or `smol` feature is
+    /// enabled, or on Wasm.
+    #[cfg(any(
+        feature = "tokio",
+        feature = "async-std",
+        feature = "smol",
+        target_arch = "wasm32"
+    ))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(
+            feature = "tokio",
+            feature = "async-std",
+            feature = "smol",
+            target_arch = "wasm32"
+        )))
+    )]
+    pub fn debounce(mut self, duration: Duration) -> Self
+    where
+        Message: MaybeSend + 'static,
+    {
+        Self {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(Debounce { recipe, duration })
+                        as Box<dyn Recipe<Output = Message>>
+                })
+                .collect(),
+        }
+    }
+
+    /// Throttles the [`Subscription`] by the given [`Duration`].
+    ///
+    /// At most one output will be produced per [`Duration`]. The first output
+    /// of a burst is produced right away and the latest one, if any, once
+    /// the [`Duration`] has passed. Any outputs in between are discarded.
+    ///
+    /// This is useful to keep up with a continuous stream of events without
+    /// flooding the application; like tracking the position of the mouse.
+    ///
+    /// It needs the timer of an async backend and, therefore, it is only
+    /// available when the `tokio`, `async-std`, or `smol` feature is
+    /// enabled, or on Wasm.
+    #[cfg(any(
+        feature = "tokio",
+        feature = "async-std",
+        feature = "smol",
+        target_arch = "wasm32"
+    ))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(
+            feature = "tokio",
+            feature = "async-std",
+            feature = "smol",
+            target_arch = "wasm32"
+        )))
+    )]
+    pub fn throttle(mut self, duration: Duration) -> Self
+    where
+        Message: MaybeSend + 'static,
+    {
+        Self {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(Throttle { recipe, duration })
+                        as Box<dyn Recipe<Output = Message>>
+                })
+                .collect(),
+        }
+    }
 }
 
 impl<Message> std::fmt::Debug for Subscription<Message> {
@@ -234,6 +358,250 @@ where
     }
 }
 
+struct FilterMap<A, B, F>
+where
+    F: Fn(A) -> Option<B> + 'static,
+{
+    recipe: Box<dyn Recipe<Output = A>>,
+    mapper: F,
+}
+
+impl<A, B, F> FilterMap<A, B, F>
+where
+    F: Fn(A) -> Option<B> + 'static,
+{
+    fn new(recipe: Box<dyn Recipe<Output = A>>, mapper: F) -> Self {
+        FilterMap { recipe, mapper }
+    }
+}
+
+impl<A, B, F> Recipe for FilterMap<A, B, F>
+where
+    A: 'static,
+    B: MaybeSend + 'static,
+    F: Fn(A) -> Option<B> + 'static + MaybeSend,
+{
+    type Output = B;
+
+    fn hash(&self, state: &mut Hasher) {
+        TypeId::of::<F>().hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: EventStream) -> BoxStream<Self::Output> {
+        use futures::future;
+        use futures::StreamExt;
+
+        let mapper = self.mapper;
+
+        Box::pin(
+            self.recipe
+                .stream(input)
+                .filter_map(move |output| future::ready(mapper(output))),
+        )
+    }
+}
+
+#[cfg(any(
+    feature = "tokio",
+    feature = "async-std",
+    feature = "smol",
+    target_arch = "wasm32"
+))]
+struct Debounce<A> {
+    recipe: Box<dyn Recipe<Output = A>>,
+    duration: Duration,
+}
+
+#[cfg(any(
+    feature = "tokio",
+    feature = "async-std",
+    feature = "smol",
+    target_arch = "wasm32"
+))]
+impl<A> Recipe for Debounce<A>
+where
+    A: MaybeSend + 'static,
+{
+    type Output = A;
+
+    fn hash(&self, state: &mut Hasher) {
+        TypeId::of::<Self>().hash(state);
+        self.duration.hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: EventStream) -> BoxStream<Self::Output> {
+        use crate::backend::default::time;
+
+        let duration = self.duration;
+
+        crate::boxed_stream(debounce(self.recipe.stream(input), move || {
+            time::sleep(duration)
+        }))
+    }
+}
+
+#[cfg(any(
+    feature = "tokio",
+    feature = "async-std",
+    feature = "smol",
+    target_arch = "wasm32"
+))]
+struct Throttle<A> {
+    recipe: Box<dyn Recipe<Output = A>>,
+    duration: Duration,
+}
+
+#[cfg(any(
+    feature = "tokio",
+    feature = "async-std",
+    feature = "smol",
+    target_arch = "wasm32"
+))]
+impl<A> Recipe for Throttle<A>
+where
+    A: MaybeSend + 'static,
+{
+    type Output = A;
+
+    fn hash(&self, state: &mut Hasher) {
+        TypeId::of::<Self>().hash(state);
+        self.duration.hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: EventStream) -> BoxStream<Self::Output> {
+        use crate::backend::default::time;
+
+        let duration = self.duration;
+
+        crate::boxed_stream(throttle(self.recipe.stream(input), move || {
+            time::sleep(duration)
+        }))
+    }
+}
+
+/// Produces the latest output of the stream once a new timer has expired
+/// without any other outputs.
+#[cfg_attr(
+    not(any(
+        feature = "tokio",
+        feature = "async-std",
+        feature = "smol",
+        target_arch = "wasm32"
+    )),
+    allow(dead_code)
+)]
+fn debounce<S, T>(
+    stream: S,
+    timer: impl Fn() -> T,
+) -> impl Stream<Item = S::Item>
+where
+    S: Stream + Unpin,
+    T: Future<Output = ()> + Unpin,
+{
+    use futures::future::{self, Either};
+    use futures::stream::{self, StreamExt};
+
+    stream::unfold(
+        (Some(stream), None, timer),
+        |(mut stream, mut latest, timer)| async move {
+            loop {
+                let Some(current) = stream.as_mut() else {
+                    return latest
+                        .take()
+                        .map(|output| (output, (None, None, timer)));
+                };
+
+                if latest.is_none() {
+                    latest = Some(current.next().await?);
+                    continue;
+                }
+
+                match future::select(current.next(), timer()).await {
+                    Either::Left((Some(output), _)) => {
+                        latest = Some(output);
+                    }
+                    Either::Left((None, _)) => {
+                        stream = None;
+                    }
+                    Either::Right(_) => {
+                        return latest
+                            .take()
+                            .map(|output| (output, (stream, None, timer)));
+                    }
+                }
+            }
+        },
+    )
+}
+
+/// Produces the first output of the stream right away, and then the latest
+/// output, if any, every time a timer expires.
+#[cfg_attr(
+    not(any(
+        feature = "tokio",
+        feature = "async-std",
+        feature = "smol",
+        target_arch = "wasm32"
+    )),
+    allow(dead_code)
+)]
+fn throttle<S, T>(
+    stream: S,
+    timer: impl Fn() -> T,
+) -> impl Stream<Item = S::Item>
+where
+    S: Stream + Unpin,
+    T: Future<Output = ()> + Unpin,
+{
+    use futures::future::{self, Either};
+    use futures::stream::{self, StreamExt};
+
+    stream::unfold(
+        (Some(stream), None, None, timer),
+        |(mut stream, mut latest, mut window, timer)| async move {
+            loop {
+                let Some(current) = stream.as_mut() else {
+                    return latest
+                        .take()
+                        .map(|output| (output, (None, None, None, timer)));
+                };
+
+                let Some(delay) = window.as_mut() else {
+                    let output = current.next().await?;
+                    let delay = timer();
+
+                    return Some((output, (stream, None, Some(delay), timer)));
+                };
+
+                match future::select(current.next(), delay).await {
+                    Either::Left((Some(output), _)) => {
+                        latest = Some(output);
+                    }
+                    Either::Left((None, _)) => {
+                        stream = None;
+                    }
+                    Either::Right(_) => {
+                        let Some(output) = latest.take() else {
+                            window = None;
+                            continue;
+                        };
+
+                        let delay = timer();
+
+                        return Some((
+                            output,
+                            (stream, None, Some(delay), timer),
+                        ));
+                    }
+                }
+            }
+        },
+    )
+}
+
 /// Returns a [`Subscription`] that will call the given function to create and
 /// asynchronously run the given [`Stream`].
 pub fn run<S, Message>(builder: fn() -> S) -> Subscription<Message>
@@ -431,3 +799,120 @@ where
         crate::boxed_stream((self.spawn)(input))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::channel::oneshot;
+    use futures::future::FutureExt;
+    use futures::stream::StreamExt;
+    use futures::task;
+    use std::sync::{Arc, Mutex};
+    use std::task::Poll;
+
+    /// A set of timers that only expire when told to.
+    #[derive(Debug, Clone, Default)]
+    struct Timers(Arc<Mutex<Vec<Option<oneshot::Sender<()>>>>>);
+
+    impl Timers {
+        fn start(&self) -> impl Future<Output = ()> + Unpin {
+            let (sender, receiver) = oneshot::channel();
+            self.0.lock().unwrap().push(Some(sender));
+
+            receiver.map(|_| ())
+        }
+
+        fn expire(&self, timer: usize) {
+            let sender = self.0.lock().unwrap()[timer].take();
+
+            let _ = sender.expect("Timer should be running").send(());
+        }
+
+        fn count(&self) -> usize {
+            self.0.lock().unwrap().len()
+        }
+    }
+
+    fn poll<S: Stream + Unpin>(stream: &mut S) -> Poll<Option<S::Item>> {
+        let waker = task::noop_waker();
+
+        stream.poll_next_unpin(&mut task::Context::from_waker(&waker))
+    }
+
+    #[test]
+    fn debounce_produces_the_latest_output_once_silent() {
+        let timers = Timers::default();
+        let (sender, receiver) = mpsc::unbounded();
+
+        let mut debounced = Box::pin(debounce(receiver, {
+            let timers = timers.clone();
+            move || timers.start()
+        }));
+
+        assert_eq!(poll(&mut debounced), Poll::Pending);
+        assert_eq!(timers.count(), 0);
+
+        sender.unbounded_send(1).unwrap();
+        assert_eq!(poll(&mut debounced), Poll::Pending);
+        assert_eq!(timers.count(), 1);
+
+        // Every new output restarts the timer
+        sender.unbounded_send(2).unwrap();
+        sender.unbounded_send(3).unwrap();
+        assert_eq!(poll(&mut debounced), Poll::Pending);
+        assert_eq!(timers.count(), 3);
+
+        timers.expire(0);
+        timers.expire(1);
+        assert_eq!(poll(&mut debounced), Poll::Pending);
+
+        timers.expire(2);
+        assert_eq!(poll(&mut debounced), Poll::Ready(Some(3)));
+        assert_eq!(poll(&mut debounced), Poll::Pending);
+
+        sender.unbounded_send(4).unwrap();
+        assert_eq!(poll(&mut debounced), Poll::Pending);
+
+        drop(sender);
+        assert_eq!(poll(&mut debounced), Poll::Ready(Some(4)));
+        assert_eq!(poll(&mut debounced), Poll::Ready(None));
+    }
+
+    #[test]
+    fn throttle_produces_the_first_and_latest_outputs() {
+        let timers = Timers::default();
+        let (sender, receiver) = mpsc::unbounded();
+
+        let mut throttled = Box::pin(throttle(receiver, {
+            let timers = timers.clone();
+            move || timers.start()
+        }));
+
+        sender.unbounded_send(1).unwrap();
+        assert_eq!(poll(&mut throttled), Poll::Ready(Some(1)));
+        assert_eq!(timers.count(), 1);
+
+        sender.unbounded_send(2).unwrap();
+        sender.unbounded_send(3).unwrap();
+        assert_eq!(poll(&mut throttled), Poll::Pending);
+
+        timers.expire(0);
+        assert_eq!(poll(&mut throttled), Poll::Ready(Some(3)));
+        assert_eq!(timers.count(), 2);
+
+        // A silent window ends the burst
+        timers.expire(1);
+        assert_eq!(poll(&mut throttled), Poll::Pending);
+        assert_eq!(timers.count(), 2);
+
+        sender.unbounded_send(4).unwrap();
+        assert_eq!(poll(&mut throttled), Poll::Ready(Some(4)));
+        assert_eq!(timers.count(), 3);
+
+        sender.unbounded_send(5).unwrap();
+        drop(sender);
+        assert_eq!(poll(&mut throttled), Poll::Ready(Some(5)));
+        assert_eq!(poll(&mut throttled), Poll::Ready(None));
+    }
+}
@@ -10,6 +10,7 @@ use crate::text;
 use cosmic_text::Edit as _;
 
 use std::fmt;
+use std::ops::Range;
 use std::sync::{self, Arc};
 
 /// A multi-line text editor.
@@ -18,6 +19,7 @@ pub struct Editor(Option<Arc<Internal>>);
 
 struct Internal {
     editor: cosmic_text::Editor,
+    folds: Vec<Fold>,
     font: Font,
     bounds: Size,
     topmost_line_changed: Option<usize>,
@@ -83,157 +85,214 @@ impl editor::Editor for Editor {
     }
 
     fn line(&self, index: usize) -> Option<&str> {
-        self.buffer()
-            .lines
-            .get(index)
-            .map(cosmic_text::BufferLine::text)
+        let internal = self.internal();
+
+        let line = match internal.to_buffer(index) {
+            Ok(line) => internal.editor.buffer().lines.get(line),
+            Err((fold, line)) => internal.folds[fold].lines.get(line),
+        };
+
+        line.map(cosmic_text::BufferLine::text)
     }
 
     fn line_count(&self) -> usize {
-        self.buffer().lines.len()
-    }
+        let internal = self.internal();
 
-    fn selection(&self) -> Option<String> {
-        self.internal().editor.copy_selection()
+        internal.editor.buffer().lines.len()
+            + internal
+                .folds
+                .iter()
+                .map(|fold| fold.lines.len())
+                .sum::<usize>()
     }
 
-    fn cursor(&self) -> editor::Cursor {
+    fn folds(&self) -> Vec<Range<usize>> {
         let internal = self.internal();
+        let mut hidden = 0;
 
-        let cursor = internal.editor.cursor();
+        internal
+            .folds
+            .iter()
+            .map(|fold| {
+                let start = fold.line + hidden + 1;
+                hidden += fold.lines.len();
+
+                start..start + fold.lines.len()
+            })
+            .collect()
+    }
+
+    fn visible_lines(&self) -> Vec<(usize, f32)> {
+        let internal = self.internal();
         let buffer = internal.editor.buffer();
 
-        match internal.editor.select_opt() {
-            Some(selection) => {
-                let (start, end) = if cursor < selection {
-                    (cursor, selection)
-                } else {
-                    (selection, cursor)
-                };
+        let start = buffer.scroll();
+        let end = start + buffer.visible_lines();
+        let line_height = buffer.metrics().line_height;
 
-                let line_height = buffer.metrics().line_height;
-                let selected_lines = end.line - start.line + 1;
+        let mut visual_line = 0;
+        let mut lines = Vec::new();
 
-                let visual_lines_offset =
-                    visual_lines_offset(start.line, buffer);
+        for (i, line) in buffer.lines.iter().enumerate() {
+            let Some(layout) = line.layout_opt() else {
+                break;
+            };
 
-                let regions = buffer
-                    .lines
-                    .iter()
-                    .skip(start.line)
-                    .take(selected_lines)
-                    .enumerate()
-                    .flat_map(|(i, line)| {
-                        highlight_line(
-                            line,
-                            if i == 0 { start.index } else { 0 },
-                            if i == selected_lines - 1 {
-                                end.index
-                            } else {
-                                line.text().len()
-                            },
-                        )
-                    })
-                    .enumerate()
-                    .filter_map(|(visual_line, (x, width))| {
-                        if width > 0.0 {
-                            Some(Rectangle {
-                                x,
-                                width,
-                                y: (visual_line as i32 + visual_lines_offset)
-                                    as f32
-                                    * line_height,
-                                height: line_height,
-                            })
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
+            if visual_line >= end {
+                break;
+            }
 
-                Cursor::Selection(regions)
+            if visual_line >= start {
+                lines.push((
+                    internal.to_document(i),
+                    (visual_line - start) as f32 * line_height,
+                ));
             }
-            _ => {
-                let line_height = buffer.metrics().line_height;
 
-                let visual_lines_offset =
-                    visual_lines_offset(cursor.line, buffer);
+            visual_line += layout.len() as i32;
+        }
 
-                let line = buffer
-                    .lines
-                    .get(cursor.line)
-                    .expect("Cursor line should be present");
+        lines
+    }
 
-                let layout = line
-                    .layout_opt()
-                    .as_ref()
-                    .expect("Line layout should be cached");
+    fn selection(&self) -> Option<String> {
+        let internal = self.internal();
 
-                let mut lines = layout.iter().enumerate();
+        if internal.folds.is_empty() {
+            return internal.editor.copy_selection();
+        }
 
-                let (visual_line, offset) = lines
-                    .find_map(|(i, line)| {
-                        let start = line
-                            .glyphs
-                            .first()
-                            .map(|glyph| glyph.start)
-                            .unwrap_or(0);
-                        let end = line
-                            .glyphs
-                            .last()
-                            .map(|glyph| glyph.end)
-                            .unwrap_or(0);
+        let cursor = self.cursor_position();
+        let anchor = self.selection_anchor()?;
 
-                        let is_cursor_before_start = start > cursor.index;
+        let (start, end) = if cursor < anchor {
+            (cursor, anchor)
+        } else {
+            (anchor, cursor)
+        };
 
-                        let is_cursor_before_end = match cursor.affinity {
-                            cosmic_text::Affinity::Before => {
-                                cursor.index <= end
-                            }
-                            cosmic_text::Affinity::After => cursor.index < end,
-                        };
-
-                        if is_cursor_before_start {
-                            // Sometimes, the glyph we are looking for is right
-                            // between lines. This can happen when a line wraps
-                            // on a space.
-                            // In that case, we can assume the cursor is at the
-                            // end of the previous line.
-                            // i is guaranteed to be > 0 because `start` is always
-                            // 0 for the first line, so there is no way for the
-                            // cursor to be before it.
-                            Some((i - 1, layout[i - 1].w))
-                        } else if is_cursor_before_end {
-                            let offset = line
-                                .glyphs
-                                .iter()
-                                .take_while(|glyph| cursor.index > glyph.start)
-                                .map(|glyph| glyph.w)
-                                .sum();
-
-                            Some((i, offset))
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or((
-                        layout.len().saturating_sub(1),
-                        layout.last().map(|line| line.w).unwrap_or(0.0),
-                    ));
-
-                Cursor::Caret(Point::new(
-                    offset,
-                    (visual_lines_offset + visual_line as i32) as f32
-                        * line_height,
-                ))
+        let mut selection = String::new();
+
+        for index in start.0..=end.0 {
+            let line = self.line(index)?;
+
+            let from = if index == start.0 { start.1 } else { 0 };
+            let to = if index == end.0 { end.1 } else { line.len() };
+
+            if index > start.0 {
+                selection.push('\n');
+            }
+
+            selection.push_str(line.get(from..to)?);
+        }
+
+        Some(selection)
+    }
+
+    fn selection_anchor(&self) -> Option<(usize, usize)> {
+        let internal = self.internal();
+
+        internal
+            .editor
+            .select_opt()
+            .map(|anchor| (internal.to_document(anchor.line), anchor.index))
+    }
+
+    fn set_cursor(
+        &mut self,
+        position: (usize, usize),
+        anchor: Option<(usize, usize)>,
+    ) {
+        let editor =
+            self.0.take().expect("Editor should always be initialized");
+
+        // TODO: Handle multiple strong references somehow
+        let mut internal = Arc::try_unwrap(editor)
+            .expect("Editor cannot have multiple strong references");
+
+        // Reveal the folded lines the cursor lands on
+        for (line, _) in [Some(position), anchor].into_iter().flatten() {
+            if let Err((fold, _)) = internal.to_buffer(line) {
+                internal.reveal(fold);
+            }
+        }
+
+        let to_cursor = |(line, index): (usize, usize)| {
+            let lines = &internal.editor.buffer().lines;
+            let line = internal
+                .to_buffer(line)
+                .unwrap_or(lines.len())
+                .min(lines.len().saturating_sub(1));
+
+            let text = lines.get(line).map(cosmic_text::BufferLine::text);
+            let mut index = index.min(text.map_or(0, str::len));
+
+            while text.is_some_and(|text| !text.is_char_boundary(index)) {
+                index -= 1;
             }
+
+            cosmic_text::Cursor::new(line, index)
+        };
+
+        let cursor = to_cursor(position);
+        let anchor = anchor.map(to_cursor).filter(|anchor| *anchor != cursor);
+
+        internal.editor.set_cursor(cursor);
+        internal.editor.set_select_opt(anchor);
+
+        // Scroll the new cursor into view, once the editor has been sized
+        if internal.editor.buffer().visible_lines() > 0 {
+            let mut font_system =
+                text::font_system().write().expect("Write font system");
+
+            internal
+                .editor
+                .buffer_mut()
+                .shape_until_cursor(font_system.raw(), cursor);
         }
+
+        self.0 = Some(Arc::new(internal));
+    }
+
+    fn cursor(&self) -> editor::Cursor {
+        let internal = self.internal();
+
+        cursor(
+            internal.editor.buffer(),
+            internal.editor.cursor(),
+            internal.editor.select_opt(),
+        )
+        .expect("Line layout should be cached")
+    }
+
+    fn cursor_at(
+        &self,
+        position: (usize, usize),
+        anchor: Option<(usize, usize)>,
+    ) -> Option<editor::Cursor> {
+        let internal = self.internal();
+
+        let to_cursor = |(line, index): (usize, usize)| {
+            Some(cosmic_text::Cursor::new(
+                internal.to_buffer(line).ok()?,
+                index,
+            ))
+        };
+
+        let position = to_cursor(position)?;
+        let anchor = match anchor.map(to_cursor) {
+            Some(anchor) => Some(anchor?).filter(|anchor| *anchor != position),
+            None => None,
+        };
+
+        cursor(internal.editor.buffer(), position, anchor)
     }
 
     fn cursor_position(&self) -> (usize, usize) {
-        let cursor = self.internal().editor.cursor();
+        let internal = self.internal();
+        let cursor = internal.editor.cursor();
 
-        (cursor.line, cursor.index)
+        (internal.to_document(cursor.line), cursor.index)
     }
 
     fn perform(&mut self, action: Action) {
@@ -247,6 +306,36 @@ impl editor::Editor for Editor {
         let mut internal = Arc::try_unwrap(editor)
             .expect("Editor cannot have multiple strong references");
 
+        // Edits reveal the folded lines they touch, and shift the folds
+        // after them
+        let edit = if let Action::Edit(edit) = &action {
+            let cursor = internal.editor.cursor();
+            let selection = internal.editor.select_opt().unwrap_or(cursor);
+
+            let start = cursor.min(selection);
+            let is_joining_lines =
+                *edit == Edit::Backspace && start == cursor.max(selection);
+
+            internal.reveal_lines(
+                if is_joining_lines && start.index == 0 {
+                    start.line.saturating_sub(1)
+                } else {
+                    start.line
+                },
+                cursor.max(selection).line,
+            );
+
+            let cursor = internal.editor.cursor();
+            let selection = internal.editor.select_opt().unwrap_or(cursor);
+
+            Some((
+                cursor.max(selection).line,
+                internal.editor.buffer().lines.len(),
+            ))
+        } else {
+            None
+        };
+
         let editor = &mut internal.editor;
 
         match action {
@@ -422,7 +511,7 @@ impl editor::Editor for Editor {
                 let selection = editor.select_opt().unwrap_or(cursor);
 
                 internal.topmost_line_changed =
-                    Some(cursor.min(selection).line);
+                    Some(internal.to_document(cursor.min(selection).line));
             }
 
             // Mouse events
@@ -465,6 +554,42 @@ impl editor::Editor for Editor {
                     );
                 }
             }
+
+            // History, searches, and additional cursors are kept by the
+            // owner of the editor
+            Action::Undo
+            | Action::Redo
+            | Action::Find(_)
+            | Action::FindNext
+            | Action::FindPrevious
+            | Action::Replace(_)
+            | Action::ReplaceAll(_)
+            | Action::AddCursor(_)
+            | Action::SelectNextOccurrence
+            | Action::ClearCursors => {}
+
+            Action::Fold(lines) => {
+                internal.fold(lines);
+            }
+            Action::Unfold(line) => {
+                if let Some(fold) = internal
+                    .folds
+                    .iter()
+                    .position(|fold| internal.to_document(fold.line) == line)
+                {
+                    internal.reveal(fold);
+                }
+            }
+        }
+
+        if let Some((last_line, line_count)) = edit {
+            let new_line_count = internal.editor.buffer().lines.len();
+
+            for fold in &mut internal.folds {
+                if fold.line > last_line {
+                    fold.line = (fold.line + new_line_count) - line_count;
+                }
+            }
         }
 
         self.0 = Some(Arc::new(internal));
@@ -500,7 +625,7 @@ impl editor::Editor for Editor {
         if font_system.version() != internal.version {
             log::trace!("Updating `FontSystem` of `Editor`...");
 
-            for line in internal.editor.buffer_mut().lines.iter_mut() {
+            for line in internal.lines_mut() {
                 line.reset();
             }
 
@@ -511,7 +636,7 @@ impl editor::Editor for Editor {
         if new_font != internal.font {
             log::trace!("Updating font of `Editor`...");
 
-            for line in internal.editor.buffer_mut().lines.iter_mut() {
+            for line in internal.lines_mut() {
                 let _ = line.set_attrs_list(cosmic_text::AttrsList::new(
                     text::to_attributes(new_font),
                 ));
@@ -592,6 +717,7 @@ impl editor::Editor for Editor {
             })
             .unwrap_or(buffer.lines.len().saturating_sub(1));
 
+        let last_visible_line = internal.to_document(last_visible_line);
         let current_line = highlighter.current_line();
 
         if current_line > last_visible_line {
@@ -609,8 +735,12 @@ impl editor::Editor for Editor {
 
         let attributes = text::to_attributes(font);
 
-        for line in &mut internal.editor.buffer_mut().lines
-            [current_line..=last_visible_line]
+        // Folded lines are highlighted too, since they may change the state
+        // of the highlighter
+        for line in internal
+            .lines_mut()
+            .skip(current_line)
+            .take(last_visible_line + 1 - current_line)
         {
             let mut list = cosmic_text::AttrsList::new(attributes);
 
@@ -641,6 +771,196 @@ impl editor::Editor for Editor {
     }
 }
 
+impl Internal {
+    /// Returns the index in the document of the given line of the buffer.
+    fn to_document(&self, line: usize) -> usize {
+        line + self
+            .folds
+            .iter()
+            .take_while(|fold| fold.line < line)
+            .map(|fold| fold.lines.len())
+            .sum::<usize>()
+    }
+
+    /// Returns the index in the buffer of the given line of the document.
+    ///
+    /// If the line is folded, the index of its [`Fold`] and its index in
+    /// the [`Fold`] are returned instead.
+    fn to_buffer(&self, line: usize) -> Result<usize, (usize, usize)> {
+        let mut hidden = 0;
+
+        for (i, fold) in self.folds.iter().enumerate() {
+            let start = fold.line + hidden + 1;
+
+            if line < start {
+                break;
+            }
+
+            if line < start + fold.lines.len() {
+                return Err((i, line - start));
+            }
+
+            hidden += fold.lines.len();
+        }
+
+        Ok(line - hidden)
+    }
+
+    /// Returns all the lines of the document, folded or not, in order.
+    fn lines_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut cosmic_text::BufferLine> {
+        let mut folds = self.folds.iter_mut().peekable();
+
+        self.editor
+            .buffer_mut()
+            .lines
+            .iter_mut()
+            .enumerate()
+            .flat_map(move |(i, line)| {
+                let hidden = folds
+                    .next_if(|fold| fold.line == i)
+                    .map(|fold| fold.lines.iter_mut())
+                    .into_iter()
+                    .flatten();
+
+                std::iter::once(line).chain(hidden)
+            })
+    }
+
+    /// Hides the given lines of the document behind the line before them.
+    fn fold(&mut self, lines: Range<usize>) {
+        let line_count = self.editor.buffer().lines.len()
+            + self
+                .folds
+                .iter()
+                .map(|fold| fold.lines.len())
+                .sum::<usize>();
+
+        if lines.start == 0 || lines.is_empty() || lines.end > line_count {
+            return;
+        }
+
+        // Folds cannot overlap
+        let overlapping: Vec<_> = self
+            .folds
+            .iter()
+            .enumerate()
+            .filter(|(_, fold)| {
+                let header = self.to_document(fold.line);
+
+                header < lines.end
+                    && lines.start <= header + fold.lines.len() + 1
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        for fold in overlapping.into_iter().rev() {
+            self.reveal(fold);
+        }
+
+        let Ok(line) = self.to_buffer(lines.start - 1) else {
+            return;
+        };
+
+        let amount = lines.len();
+        let hidden = line + 1..line + 1 + amount;
+
+        let line_length = self.editor.buffer().lines[line].text().len();
+
+        let hide = |cursor: cosmic_text::Cursor| {
+            if hidden.contains(&cursor.line) {
+                cosmic_text::Cursor::new(line, line_length)
+            } else if cursor.line >= hidden.end {
+                cosmic_text::Cursor {
+                    line: cursor.line - amount,
+                    ..cursor
+                }
+            } else {
+                cursor
+            }
+        };
+
+        let cursor = hide(self.editor.cursor());
+        let anchor = self
+            .editor
+            .select_opt()
+            .map(hide)
+            .filter(|anchor| *anchor != cursor);
+
+        let buffer = self.editor.buffer_mut();
+        let lines = buffer.lines.drain(hidden).collect();
+        buffer.set_redraw(true);
+
+        self.editor.set_cursor(cursor);
+        self.editor.set_select_opt(anchor);
+
+        for fold in &mut self.folds {
+            if fold.line > line {
+                fold.line -= amount;
+            }
+        }
+
+        let index = self.folds.partition_point(|fold| fold.line < line);
+        self.folds.insert(index, Fold { line, lines });
+    }
+
+    /// Reveals the lines of the [`Fold`] at the given index.
+    fn reveal(&mut self, index: usize) {
+        let Fold { line, mut lines } = self.folds.remove(index);
+        let amount = lines.len();
+
+        for line in &mut lines {
+            line.reset_layout();
+        }
+
+        let buffer = self.editor.buffer_mut();
+        let _ = buffer.lines.splice(line + 1..line + 1, lines);
+        buffer.set_redraw(true);
+
+        let reveal = |cursor: cosmic_text::Cursor| {
+            if cursor.line > line {
+                cosmic_text::Cursor {
+                    line: cursor.line + amount,
+                    ..cursor
+                }
+            } else {
+                cursor
+            }
+        };
+
+        let cursor = reveal(self.editor.cursor());
+        let anchor = self.editor.select_opt().map(reveal);
+
+        self.editor.set_cursor(cursor);
+        self.editor.set_select_opt(anchor);
+
+        for fold in &mut self.folds[index..] {
+            fold.line += amount;
+        }
+    }
+
+    /// Reveals the lines folded into any of the lines of the buffer in the
+    /// given range.
+    fn reveal_lines(&mut self, first: usize, mut last: usize) {
+        while let Some(fold) = self
+            .folds
+            .iter()
+            .position(|fold| first <= fold.line && fold.line <= last)
+        {
+            last += self.folds[fold].lines.len();
+
+            self.reveal(fold);
+        }
+    }
+}
+
+/// A range of lines hidden behind the line of the buffer before them.
+struct Fold {
+    line: usize,
+    lines: Vec<cosmic_text::BufferLine>,
+}
+
 impl Default for Editor {
     fn default() -> Self {
         Self(Some(Arc::new(Internal::default())))
@@ -664,6 +984,7 @@ impl Default for Internal {
                     line_height: 1.0,
                 },
             )),
+            folds: Vec::new(),
             font: Font::default(),
             bounds: Size::ZERO,
             topmost_line_changed: None,
@@ -705,17 +1026,136 @@ impl PartialEq for Weak {
     }
 }
 
+fn cursor(
+    buffer: &cosmic_text::Buffer,
+    cursor: cosmic_text::Cursor,
+    selection: Option<cosmic_text::Cursor>,
+) -> Option<Cursor> {
+    match selection {
+        Some(selection) => {
+            let (start, end) = if cursor < selection {
+                (cursor, selection)
+            } else {
+                (selection, cursor)
+            };
+
+            let line_height = buffer.metrics().line_height;
+            let selected_lines = end.line - start.line + 1;
+
+            let visual_lines_offset = visual_lines_offset(start.line, buffer)?;
+
+            let lines = buffer
+                .lines
+                .iter()
+                .skip(start.line)
+                .take(selected_lines)
+                .enumerate()
+                .map(|(i, line)| {
+                    highlight_line(
+                        line,
+                        if i == 0 { start.index } else { 0 },
+                        if i == selected_lines - 1 {
+                            end.index
+                        } else {
+                            line.text().len()
+                        },
+                    )
+                })
+                .collect::<Option<Vec<_>>>()?;
+
+            let regions = lines
+                .into_iter()
+                .flatten()
+                .enumerate()
+                .filter_map(|(visual_line, (x, width))| {
+                    if width > 0.0 {
+                        Some(Rectangle {
+                            x,
+                            width,
+                            y: (visual_line as i32 + visual_lines_offset)
+                                as f32
+                                * line_height,
+                            height: line_height,
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            Some(Cursor::Selection(regions))
+        }
+        _ => {
+            let line_height = buffer.metrics().line_height;
+
+            let visual_lines_offset = visual_lines_offset(cursor.line, buffer)?;
+
+            let layout =
+                buffer.lines.get(cursor.line)?.layout_opt().as_ref()?;
+
+            let mut lines = layout.iter().enumerate();
+
+            let (visual_line, offset) = lines
+                .find_map(|(i, line)| {
+                    let start = line
+                        .glyphs
+                        .first()
+                        .map(|glyph| glyph.start)
+                        .unwrap_or(0);
+                    let end =
+                        line.glyphs.last().map(|glyph| glyph.end).unwrap_or(0);
+
+                    let is_cursor_before_start = start > cursor.index;
+
+                    let is_cursor_before_end = match cursor.affinity {
+                        cosmic_text::Affinity::Before => cursor.index <= end,
+                        cosmic_text::Affinity::After => cursor.index < end,
+                    };
+
+                    if is_cursor_before_start {
+                        // Sometimes, the glyph we are looking for is right
+                        // between lines. This can happen when a line wraps
+                        // on a space.
+                        // In that case, we can assume the cursor is at the
+                        // end of the previous line.
+                        // i is guaranteed to be > 0 because `start` is always
+                        // 0 for the first line, so there is no way for the
+                        // cursor to be before it.
+                        Some((i - 1, layout[i - 1].w))
+                    } else if is_cursor_before_end {
+                        let offset = line
+                            .glyphs
+                            .iter()
+                            .take_while(|glyph| cursor.index > glyph.start)
+                            .map(|glyph| glyph.w)
+                            .sum();
+
+                        Some((i, offset))
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or((
+                    layout.len().saturating_sub(1),
+                    layout.last().map(|line| line.w).unwrap_or(0.0),
+                ));
+
+            Some(Cursor::Caret(Point::new(
+                offset,
+                (visual_lines_offset + visual_line as i32) as f32 * line_height,
+            )))
+        }
+    }
+}
+
 fn highlight_line(
     line: &cosmic_text::BufferLine,
     from: usize,
     to: usize,
-) -> impl Iterator<Item = (f32, f32)> + '_ {
-    let layout = line
-        .layout_opt()
-        .as_ref()
-        .expect("Line layout should be cached");
+) -> Option<impl Iterator<Item = (f32, f32)> + '_> {
+    let layout = line.layout_opt().as_ref()?;
 
-    layout.iter().map(move |visual_line| {
+    Some(layout.iter().map(move |visual_line| {
         let start = visual_line
             .glyphs
             .first()
@@ -752,23 +1192,21 @@ fn highlight_line(
 
             (x, width)
         }
-    })
+    }))
 }
 
-fn visual_lines_offset(line: usize, buffer: &cosmic_text::Buffer) -> i32 {
+fn visual_lines_offset(
+    line: usize,
+    buffer: &cosmic_text::Buffer,
+) -> Option<i32> {
     let visual_lines_before_start: usize = buffer
         .lines
         .iter()
         .take(line)
-        .map(|line| {
-            line.layout_opt()
-                .as_ref()
-                .expect("Line layout should be cached")
-                .len()
-        })
-        .sum();
-
-    visual_lines_before_start as i32 - buffer.scroll()
+        .map(|line| line.layout_opt().as_ref().map(Vec::len))
+        .sum::<Option<usize>>()?;
+
+    Some(visual_lines_before_start as i32 - buffer.scroll())
 }
 
 fn motion_to_action(motion: Motion) -> cosmic_text::Action {
@@ -787,3 +1225,176 @@ fn motion_to_action(motion: Motion) -> cosmic_text::Action {
         Motion::DocumentEnd => cosmic_text::Action::BufferEnd,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::text::editor::Editor as _;
+
+    const TEXT: &str = "0\n1\n2\n3\n4\n5";
+
+    fn internal(editor: &mut Editor) -> &mut Internal {
+        editor
+            .0
+            .as_mut()
+            .and_then(Arc::get_mut)
+            .expect("Editor should be uniquely owned")
+    }
+
+    fn move_to(editor: &mut Editor, line: usize, index: usize) {
+        let internal = internal(editor);
+
+        internal
+            .editor
+            .set_cursor(cosmic_text::Cursor::new(line, index));
+        internal.editor.set_select_opt(None);
+    }
+
+    fn lines(editor: &Editor) -> Vec<&str> {
+        (0..editor.line_count())
+            .map(|i| editor.line(i).expect("Line should exist"))
+            .collect()
+    }
+
+    fn assert_invariants(editor: &Editor) {
+        let internal = editor.internal();
+        let hidden: usize =
+            internal.folds.iter().map(|fold| fold.lines.len()).sum();
+
+        assert_eq!(
+            editor.line_count(),
+            internal.editor.buffer().lines.len() + hidden
+        );
+        assert_eq!(editor.line(editor.line_count()), None);
+
+        for line in 0..editor.line_count() {
+            if let Ok(buffer) = internal.to_buffer(line) {
+                assert_eq!(internal.to_document(buffer), line);
+            }
+        }
+    }
+
+    #[test]
+    fn lines_are_mapped_around_folds() {
+        let mut editor = Editor::with_text(TEXT);
+        editor.perform(Action::Fold(2..4));
+
+        let internal = editor.internal();
+
+        assert_eq!(internal.editor.buffer().lines.len(), 4);
+
+        assert_eq!(internal.to_document(0), 0);
+        assert_eq!(internal.to_document(1), 1);
+        assert_eq!(internal.to_document(2), 4);
+        assert_eq!(internal.to_document(3), 5);
+
+        assert_eq!(internal.to_buffer(1), Ok(1));
+        assert_eq!(internal.to_buffer(2), Err((0, 0)));
+        assert_eq!(internal.to_buffer(3), Err((0, 1)));
+        assert_eq!(internal.to_buffer(4), Ok(2));
+        assert_eq!(internal.to_buffer(5), Ok(3));
+
+        assert_eq!(editor.folds(), [2..4]);
+        assert_eq!(lines(&editor), ["0", "1", "2", "3", "4", "5"]);
+        assert_invariants(&editor);
+    }
+
+    #[test]
+    fn folds_are_revealed_by_unfolding_their_header() {
+        let mut editor = Editor::with_text(TEXT);
+        editor.perform(Action::Fold(2..4));
+        editor.perform(Action::Fold(5..6));
+
+        assert_eq!(editor.folds(), [2..4, 5..6]);
+        assert_invariants(&editor);
+
+        editor.perform(Action::Unfold(2));
+        assert_eq!(editor.folds(), [2..4, 5..6]);
+
+        editor.perform(Action::Unfold(1));
+        assert_eq!(editor.folds(), [5..6]);
+        assert_eq!(editor.internal().editor.buffer().lines.len(), 5);
+        assert_invariants(&editor);
+
+        editor.perform(Action::Unfold(4));
+        assert!(editor.folds().is_empty());
+        assert_eq!(lines(&editor), ["0", "1", "2", "3", "4", "5"]);
+        assert_invariants(&editor);
+    }
+
+    #[test]
+    fn overlapping_folds_are_merged() {
+        let mut editor = Editor::with_text(TEXT);
+        editor.perform(Action::Fold(2..3));
+        editor.perform(Action::Fold(4..5));
+        editor.perform(Action::Fold(1..5));
+
+        assert_eq!(editor.folds(), [1..5]);
+        assert_eq!(lines(&editor), ["0", "1", "2", "3", "4", "5"]);
+        assert_invariants(&editor);
+    }
+
+    #[test]
+    fn invalid_folds_are_ignored() {
+        let mut editor = Editor::with_text(TEXT);
+        editor.perform(Action::Fold(0..2));
+        editor.perform(Action::Fold(3..3));
+        editor.perform(Action::Fold(4..7));
+
+        assert!(editor.folds().is_empty());
+    }
+
+    #[test]
+    fn edits_before_a_fold_shift_it() {
+        let mut editor = Editor::with_text(TEXT);
+        editor.perform(Action::Fold(2..4));
+
+        move_to(&mut editor, 0, 1);
+        editor.perform(Action::Edit(Edit::Enter));
+
+        assert_eq!(editor.folds(), [3..5]);
+        assert_eq!(lines(&editor), ["0", "", "1", "2", "3", "4", "5"]);
+        assert_invariants(&editor);
+    }
+
+    #[test]
+    fn edits_inside_a_fold_reveal_it() {
+        let mut editor = Editor::with_text(TEXT);
+        editor.perform(Action::Fold(2..4));
+
+        move_to(&mut editor, 1, 1);
+        editor.perform(Action::Edit(Edit::Insert('!')));
+
+        assert!(editor.folds().is_empty());
+        assert_eq!(lines(&editor), ["0", "1!", "2", "3", "4", "5"]);
+        assert_invariants(&editor);
+    }
+
+    #[test]
+    fn joining_the_line_after_a_fold_reveals_it() {
+        let mut editor = Editor::with_text(TEXT);
+        editor.perform(Action::Fold(2..4));
+
+        move_to(&mut editor, 2, 0);
+        editor.perform(Action::Edit(Edit::Backspace));
+
+        assert!(editor.folds().is_empty());
+        assert_eq!(lines(&editor), ["0", "1", "2", "34", "5"]);
+        assert_invariants(&editor);
+    }
+
+    #[test]
+    fn edits_after_a_fold_keep_it() {
+        let mut editor = Editor::with_text(TEXT);
+        editor.perform(Action::Fold(2..4));
+
+        move_to(&mut editor, 3, 1);
+        editor.perform(Action::Edit(Edit::Enter));
+        editor.perform(Action::Edit(Edit::Insert('6')));
+
+        assert_eq!(editor.folds(), [2..4]);
+        assert_eq!(lines(&editor), ["0", "1", "2", "3", "4", "5", "6"]);
+        assert_invariants(&editor);
+    }
+}
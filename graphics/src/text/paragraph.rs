@@ -1,8 +1,8 @@
 //! Draw paragraphs.
 use crate::core;
 use crate::core::alignment;
-use crate::core::text::{Hit, LineHeight, Shaping, Text};
-use crate::core::{Font, Pixels, Point, Size};
+use crate::core::text::{Hit, LineHeight, Shaping, Span, Text};
+use crate::core::{Font, Pixels, Point, Rectangle, Size};
 use crate::text;
 
 use std::fmt;
@@ -15,6 +15,7 @@ pub struct Paragraph(Option<Arc<Internal>>);
 struct Internal {
     buffer: cosmic_text::Buffer,
     content: String, // TODO: Reuse from `buffer` (?)
+    spans: Vec<Span<'static, (), Font>>,
     font: Font,
     shaping: Shaping,
     horizontal_alignment: alignment::Horizontal,
@@ -93,6 +94,63 @@ impl core::text::Paragraph for Paragraph {
         Self(Some(Arc::new(Internal {
             buffer,
             content: text.content.to_owned(),
+            spans: Vec::new(),
+            font: text.font,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+            shaping: text.shaping,
+            bounds: text.bounds,
+            min_bounds,
+            version: font_system.version(),
+        })))
+    }
+
+    fn with_spans<Link>(text: Text<&[Span<'_, Link, Font>]>) -> Self {
+        log::trace!("Allocating rich paragraph: {} spans", text.content.len());
+
+        let mut font_system =
+            text::font_system().write().expect("Write font system");
+
+        let mut buffer = cosmic_text::Buffer::new(
+            font_system.raw(),
+            cosmic_text::Metrics::new(
+                text.size.into(),
+                text.line_height.to_absolute(text.size).into(),
+            ),
+        );
+
+        buffer.set_size(
+            font_system.raw(),
+            text.bounds.width,
+            text.bounds.height,
+        );
+
+        // Spans are always shaped with advanced shaping, since basic shaping
+        // applies the attributes of the first span of a run to all of its
+        // glyphs
+        buffer.set_rich_text(
+            font_system.raw(),
+            text.content.iter().enumerate().map(|(index, span)| {
+                let attributes =
+                    text::to_attributes(span.font.unwrap_or(text.font))
+                        .metadata(index);
+
+                let attributes = match span.color {
+                    Some(color) => attributes.color(text::to_color(color)),
+                    None => attributes,
+                };
+
+                (span.text.as_ref(), attributes)
+            }),
+            cosmic_text::Shaping::Advanced,
+        );
+
+        let min_bounds = text::measure(&buffer);
+
+        Self(Some(Arc::new(Internal {
+            buffer,
+            content: text.content.iter().map(|span| &*span.text).collect(),
+            spans: text.content.iter().map(Span::to_static).collect(),
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
@@ -128,20 +186,35 @@ impl core::text::Paragraph for Paragraph {
             Err(internal) => {
                 let metrics = internal.buffer.metrics();
 
+                let size = Pixels(metrics.font_size);
+                let line_height =
+                    LineHeight::Absolute(Pixels(metrics.line_height));
+
                 // If there is a strong reference somewhere, we recompute the
                 // buffer from scratch
-                *self = Self::with_text(Text {
-                    content: &internal.content,
-                    bounds: internal.bounds,
-                    size: Pixels(metrics.font_size),
-                    line_height: LineHeight::Absolute(Pixels(
-                        metrics.line_height,
-                    )),
-                    font: internal.font,
-                    horizontal_alignment: internal.horizontal_alignment,
-                    vertical_alignment: internal.vertical_alignment,
-                    shaping: internal.shaping,
-                });
+                *self = if internal.spans.is_empty() {
+                    Self::with_text(Text {
+                        content: &internal.content,
+                        bounds: new_bounds,
+                        size,
+                        line_height,
+                        font: internal.font,
+                        horizontal_alignment: internal.horizontal_alignment,
+                        vertical_alignment: internal.vertical_alignment,
+                        shaping: internal.shaping,
+                    })
+                } else {
+                    Self::with_spans(Text {
+                        content: internal.spans.as_slice(),
+                        bounds: new_bounds,
+                        size,
+                        line_height,
+                        font: internal.font,
+                        horizontal_alignment: internal.horizontal_alignment,
+                        vertical_alignment: internal.vertical_alignment,
+                        shaping: internal.shaping,
+                    })
+                };
             }
         }
     }
@@ -153,6 +226,7 @@ impl core::text::Paragraph for Paragraph {
 
         if paragraph.version != font_system.version
             || paragraph.content != text.content
+            || !paragraph.spans.is_empty()
             || metrics.font_size != text.size.0
             || metrics.line_height != text.line_height.to_absolute(text.size).0
             || paragraph.font != text.font
@@ -186,6 +260,60 @@ impl core::text::Paragraph for Paragraph {
         Some(Hit::CharOffset(cursor.index))
     }
 
+    fn hit_span(&self, point: Point) -> Option<usize> {
+        let internal = self.internal();
+        let line_height = internal.buffer.metrics().line_height;
+
+        let run = internal.buffer.layout_runs().find(|run| {
+            point.y >= run.line_top && point.y < run.line_top + line_height
+        })?;
+
+        let glyph = run
+            .glyphs
+            .iter()
+            .find(|glyph| point.x >= glyph.x && point.x < glyph.x + glyph.w)?;
+
+        Some(glyph.metadata)
+    }
+
+    fn span_bounds(&self, index: usize) -> Vec<Rectangle> {
+        let internal = self.internal();
+        let line_height = internal.buffer.metrics().line_height;
+
+        let mut bounds = Vec::new();
+
+        for run in internal.buffer.layout_runs() {
+            let mut current: Option<(f32, f32)> = None;
+
+            let mut push = |(start, end): (f32, f32)| {
+                bounds.push(Rectangle {
+                    x: start,
+                    y: run.line_top,
+                    width: end - start,
+                    height: line_height,
+                });
+            };
+
+            for glyph in run.glyphs {
+                if glyph.metadata == index {
+                    let (start, end) =
+                        current.get_or_insert((glyph.x, glyph.x + glyph.w));
+
+                    *start = start.min(glyph.x);
+                    *end = end.max(glyph.x + glyph.w);
+                } else if let Some(range) = current.take() {
+                    push(range);
+                }
+            }
+
+            if let Some(range) = current {
+                push(range);
+            }
+        }
+
+        bounds
+    }
+
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point> {
         use unicode_segmentation::UnicodeSegmentation;
 
@@ -254,6 +382,7 @@ impl fmt::Debug for Paragraph {
 impl PartialEq for Internal {
     fn eq(&self, other: &Self) -> bool {
         self.content == other.content
+            && self.spans == other.spans
             && self.font == other.font
             && self.shaping == other.shaping
             && self.horizontal_alignment == other.horizontal_alignment
@@ -272,6 +401,7 @@ impl Default for Internal {
                 line_height: 1.0,
             }),
             content: String::new(),
+            spans: Vec::new(),
             font: Font::default(),
             shaping: Shaping::default(),
             horizontal_alignment: alignment::Horizontal::Left,
@@ -1,16 +1,24 @@
 //! A syntax highlighter for iced.
 use iced_core as core;
 
+#[cfg(feature = "tree-sitter")]
+pub mod tree_sitter;
+
 use crate::core::text::highlighter::{self, Format};
+use crate::core::theme::Palette;
 use crate::core::{Color, Font};
 
 use once_cell::sync::Lazy;
+use std::fmt;
+use std::io;
 use std::ops::Range;
+use std::path::Path;
+use std::sync::Arc;
 use syntect::highlighting;
 use syntect::parsing;
 
-static SYNTAXES: Lazy<parsing::SyntaxSet> =
-    Lazy::new(parsing::SyntaxSet::load_defaults_nonewlines);
+static SYNTAXES: Lazy<Arc<parsing::SyntaxSet>> =
+    Lazy::new(|| Arc::new(parsing::SyntaxSet::load_defaults_nonewlines()));
 
 static THEMES: Lazy<highlighting::ThemeSet> =
     Lazy::new(highlighting::ThemeSet::load_defaults);
@@ -20,8 +28,9 @@ const LINES_PER_SNAPSHOT: usize = 50;
 /// A syntax highlighter.
 #[derive(Debug)]
 pub struct Highlighter {
-    syntax: &'static parsing::SyntaxReference,
-    highlighter: highlighting::Highlighter<'static>,
+    syntaxes: Syntaxes,
+    syntax: usize,
+    theme: Theme,
     caches: Vec<(parsing::ParseState, parsing::ScopeStack)>,
     current_line: usize,
 }
@@ -34,33 +43,25 @@ impl highlighter::Highlighter for Highlighter {
         Box<dyn Iterator<Item = (Range<usize>, Self::Highlight)> + 'a>;
 
     fn new(settings: &Self::Settings) -> Self {
-        let syntax = SYNTAXES
-            .find_syntax_by_token(&settings.extension)
-            .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
-
-        let highlighter = highlighting::Highlighter::new(
-            &THEMES.themes[settings.theme.key()],
-        );
+        let syntaxes = settings.syntaxes.clone();
+        let syntax = syntaxes.find(&settings.extension);
 
-        let parser = parsing::ParseState::new(syntax);
+        let parser = parsing::ParseState::new(&syntaxes.0.syntaxes()[syntax]);
         let stack = parsing::ScopeStack::new();
 
         Highlighter {
+            syntaxes,
             syntax,
-            highlighter,
+            theme: settings.theme.clone(),
             caches: vec![(parser, stack)],
             current_line: 0,
         }
     }
 
     fn update(&mut self, new_settings: &Self::Settings) {
-        self.syntax = SYNTAXES
-            .find_syntax_by_token(&new_settings.extension)
-            .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
-
-        self.highlighter = highlighting::Highlighter::new(
-            &THEMES.themes[new_settings.theme.key()],
-        );
+        self.syntaxes = new_settings.syntaxes.clone();
+        self.syntax = self.syntaxes.find(&new_settings.extension);
+        self.theme = new_settings.theme.clone();
 
         // Restart the highlighter
         self.change_line(0);
@@ -80,7 +81,9 @@ impl highlighter::Highlighter for Highlighter {
         let (parser, stack) =
             self.caches.last().cloned().unwrap_or_else(|| {
                 (
-                    parsing::ParseState::new(self.syntax),
+                    parsing::ParseState::new(
+                        &self.syntaxes.0.syntaxes()[self.syntax],
+                    ),
                     parsing::ScopeStack::new(),
                 )
             });
@@ -101,9 +104,11 @@ impl highlighter::Highlighter for Highlighter {
         let (parser, stack) =
             self.caches.last_mut().expect("Caches must not be empty");
 
-        let ops = parser.parse_line(line, &SYNTAXES).unwrap_or_default();
+        let ops = parser
+            .parse_line(line, &self.syntaxes.0)
+            .unwrap_or_default();
 
-        let highlighter = &self.highlighter;
+        let highlighter = highlighting::Highlighter::new(self.theme.settings());
 
         Box::new(
             ScopeRangeIterator {
@@ -146,6 +151,118 @@ pub struct Settings {
     /// The [`Highlighter`] will use the extension to automatically determine
     /// the grammar to use for highlighting.
     pub extension: String,
+    /// The [`Syntaxes`] the grammar will be chosen from.
+    pub syntaxes: Syntaxes,
+}
+
+/// A set of syntax definitions a [`Highlighter`] can use.
+///
+/// By default, it only contains the definitions bundled with the
+/// [`Highlighter`].
+#[derive(Clone)]
+pub struct Syntaxes(Arc<parsing::SyntaxSet>);
+
+impl Syntaxes {
+    /// Creates a new set of [`Syntaxes`] containing the bundled definitions
+    /// and the given additional ones.
+    pub fn new(syntaxes: impl IntoIterator<Item = Syntax>) -> Self {
+        let mut builder = SYNTAXES.as_ref().clone().into_builder();
+
+        for syntax in syntaxes {
+            builder.add(syntax.0);
+        }
+
+        Self(Arc::new(builder.build()))
+    }
+
+    /// Returns the names of the syntax definitions in the [`Syntaxes`].
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.syntaxes().iter().map(|syntax| syntax.name.as_str())
+    }
+
+    fn find(&self, token: &str) -> usize {
+        let syntax = self
+            .0
+            .find_syntax_by_token(token)
+            .unwrap_or_else(|| self.0.find_syntax_plain_text());
+
+        self.0
+            .syntaxes()
+            .iter()
+            .position(|candidate| std::ptr::eq(candidate, syntax))
+            .unwrap_or_default()
+    }
+}
+
+impl Default for Syntaxes {
+    fn default() -> Self {
+        Self(SYNTAXES.clone())
+    }
+}
+
+impl PartialEq for Syntaxes {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for Syntaxes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+/// A syntax definition in the `.sublime-syntax` format.
+#[derive(Debug, Clone)]
+pub struct Syntax(parsing::SyntaxDefinition);
+
+impl Syntax {
+    /// Parses a [`Syntax`] from the contents of a `.sublime-syntax` file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Self::parse(bytes, None)
+    }
+
+    /// Loads a [`Syntax`] from the `.sublime-syntax` file at the given path.
+    ///
+    /// The name of the file is used as the name of the [`Syntax`] if its
+    /// definition has none.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)?;
+
+        Self::parse(&bytes, path.file_stem().and_then(std::ffi::OsStr::to_str))
+    }
+
+    fn parse(bytes: &[u8], name: Option<&str>) -> Result<Self, Error> {
+        let definition = std::str::from_utf8(bytes)
+            .map_err(|error| Error::InvalidSyntax(error.to_string()))?;
+
+        parsing::SyntaxDefinition::load_from_str(definition, false, name)
+            .map(Self)
+            .map_err(|error| Error::InvalidSyntax(error.to_string()))
+    }
+}
+
+/// An error produced when loading a [`Syntax`] or a [`Theme`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// An input/output operation failed.
+    #[error("input/output operation failed: {0}")]
+    IOFailed(Arc<io::Error>),
+
+    /// The syntax definition is not valid.
+    #[error("invalid syntax definition: {0}")]
+    InvalidSyntax(String),
+
+    /// The theme is not valid.
+    #[error("invalid theme: {0}")]
+    InvalidTheme(String),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::IOFailed(Arc::new(error))
+    }
 }
 
 /// A highlight produced by a [`Highlighter`].
@@ -185,13 +302,15 @@ impl Highlight {
 
 /// A highlighting theme.
 #[allow(missing_docs)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Theme {
     SolarizedDark,
     Base16Mocha,
     Base16Ocean,
     Base16Eighties,
     InspiredGitHub,
+    /// A [`Theme`] loaded at runtime or derived from a [`Palette`].
+    Custom(Arc<Custom>),
 }
 
 impl Theme {
@@ -204,40 +323,139 @@ impl Theme {
         Self::InspiredGitHub,
     ];
 
+    /// Parses a custom [`Theme`] from the contents of a `.tmTheme` file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let theme = highlighting::ThemeSet::load_from_reader(
+            &mut io::Cursor::new(bytes),
+        )
+        .map_err(|error| Error::InvalidTheme(error.to_string()))?;
+
+        Ok(Self::Custom(Arc::new(Custom::new(
+            theme.name.clone().unwrap_or_else(|| String::from("Custom")),
+            theme,
+        ))))
+    }
+
+    /// Loads a custom [`Theme`] from the `.tmTheme` file at the given path.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    /// Derives a custom [`Theme`] from the given [`Palette`], so highlighted
+    /// code matches the rest of an application.
+    pub fn from_palette(name: impl Into<String>, palette: Palette) -> Self {
+        let color = |color: Color| {
+            let [r, g, b, a] = color.into_rgba8();
+
+            highlighting::Color { r, g, b, a }
+        };
+
+        let item = |scope: &str, foreground: Color| highlighting::ThemeItem {
+            scope: scope.parse().expect("Parse scope selector"),
+            style: highlighting::StyleModifier {
+                foreground: Some(color(foreground)),
+                ..highlighting::StyleModifier::default()
+            },
+        };
+
+        let name = name.into();
+
+        let theme = highlighting::Theme {
+            name: Some(name.clone()),
+            settings: highlighting::ThemeSettings {
+                foreground: Some(color(palette.text)),
+                background: Some(color(palette.background)),
+                ..highlighting::ThemeSettings::default()
+            },
+            scopes: vec![
+                item("comment", palette.text.scale_alpha(0.6)),
+                item("keyword, storage", palette.primary),
+                item("string", palette.success),
+                item("constant", palette.danger),
+            ],
+            ..highlighting::Theme::default()
+        };
+
+        Self::Custom(Arc::new(Custom::new(name, theme)))
+    }
+
     /// Returns `true` if the [`Theme`] is dark, and false otherwise.
-    pub fn is_dark(self) -> bool {
+    pub fn is_dark(&self) -> bool {
         match self {
             Self::SolarizedDark
             | Self::Base16Mocha
             | Self::Base16Ocean
             | Self::Base16Eighties => true,
             Self::InspiredGitHub => false,
+            Self::Custom(custom) => custom.is_dark,
         }
     }
 
-    fn key(self) -> &'static str {
-        match self {
+    fn settings(&self) -> &highlighting::Theme {
+        let key = match self {
             Theme::SolarizedDark => "Solarized (dark)",
             Theme::Base16Mocha => "base16-mocha.dark",
             Theme::Base16Ocean => "base16-ocean.dark",
             Theme::Base16Eighties => "base16-eighties.dark",
             Theme::InspiredGitHub => "InspiredGitHub",
-        }
+            Theme::Custom(custom) => return &custom.theme,
+        };
+
+        &THEMES.themes[key]
     }
 }
 
-impl std::fmt::Display for Theme {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl From<&core::Theme> for Theme {
+    fn from(theme: &core::Theme) -> Self {
+        Self::from_palette(theme.to_string(), theme.palette())
+    }
+}
+
+impl fmt::Display for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Theme::SolarizedDark => write!(f, "Solarized Dark"),
             Theme::Base16Mocha => write!(f, "Mocha"),
             Theme::Base16Ocean => write!(f, "Ocean"),
             Theme::Base16Eighties => write!(f, "Eighties"),
             Theme::InspiredGitHub => write!(f, "Inspired GitHub"),
+            Theme::Custom(custom) => custom.fmt(f),
         }
     }
 }
 
+/// A custom highlighting [`Theme`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Custom {
+    name: String,
+    theme: highlighting::Theme,
+    is_dark: bool,
+}
+
+impl Custom {
+    fn new(name: String, theme: highlighting::Theme) -> Self {
+        let is_dark = theme.settings.background.is_some_and(|color| {
+            let luma = 0.299 * f32::from(color.r)
+                + 0.587 * f32::from(color.g)
+                + 0.114 * f32::from(color.b);
+
+            luma < 128.0
+        });
+
+        Self {
+            name,
+            theme,
+            is_dark,
+        }
+    }
+}
+
+impl fmt::Display for Custom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
 struct ScopeRangeIterator {
     ops: Vec<(usize, parsing::ScopeStackOp)>,
     line_length: usize,
@@ -272,3 +490,52 @@ impl Iterator for ScopeRangeIterator {
         Some((range, op))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::highlighter::Highlighter as _;
+
+    const SYNTAX: &str = r"%YAML 1.2
+---
+name: Dsl
+file_extensions: [dsl]
+scope: source.dsl
+contexts:
+  main:
+    - match: '\b(let)\b'
+      scope: keyword.other.dsl
+";
+
+    #[test]
+    fn it_highlights_custom_syntaxes() {
+        let syntax = Syntax::from_bytes(SYNTAX.as_bytes()).unwrap();
+        let syntaxes = Syntaxes::new([syntax]);
+
+        assert!(syntaxes.names().any(|name| name == "Dsl"));
+
+        let theme = Theme::from_palette("Dark", Palette::DARK);
+        assert!(theme.is_dark());
+
+        let mut highlighter = Highlighter::new(&Settings {
+            theme,
+            extension: String::from("dsl"),
+            syntaxes,
+        });
+
+        let highlights: Vec<_> = highlighter
+            .highlight_line("let x")
+            .map(|(range, highlight)| (range, highlight.color()))
+            .collect();
+
+        assert_eq!(highlights[0], (0..3, Some(Palette::DARK.primary)));
+    }
+
+    #[test]
+    fn it_rejects_invalid_themes() {
+        assert!(matches!(
+            Theme::from_bytes(b"not a theme"),
+            Err(Error::InvalidTheme(_))
+        ));
+    }
+}
@@ -0,0 +1,425 @@
+//! Highlight text using [tree-sitter] grammars.
+//!
+//! [tree-sitter]: https://tree-sitter.github.io
+use crate::core::text::highlighter::{self, Format};
+use crate::core::theme::Palette;
+use crate::core::Font;
+
+use streaming_iterator::StreamingIterator;
+
+use std::borrow::Cow;
+use std::fmt;
+use std::mem;
+use std::ops::Range;
+use std::sync::Arc;
+
+pub use ::tree_sitter::Language;
+
+/// The maximum amount of lines that are looked ahead to find lines that
+/// have been removed.
+const MAX_LOOKAHEAD: usize = 50;
+
+/// A syntax highlighter driven by a tree-sitter grammar.
+///
+/// The [`Highlighter`] keeps its own copy of the lines it is fed and
+/// re-parses them incrementally as they change.
+pub struct Highlighter {
+    parser: ::tree_sitter::Parser,
+    query: Option<::tree_sitter::Query>,
+    cursor: ::tree_sitter::QueryCursor,
+    names: Vec<Arc<str>>,
+    tree: Option<::tree_sitter::Tree>,
+    source: String,
+    starts: Vec<usize>,
+    displaced: Vec<String>,
+    is_dirty: bool,
+    current_line: usize,
+}
+
+impl Highlighter {
+    fn configure(&mut self, settings: &Settings) {
+        let query =
+            self.parser
+                .set_language(&settings.language)
+                .ok()
+                .and_then(|()| {
+                    ::tree_sitter::Query::new(
+                        &settings.language,
+                        &settings.highlights,
+                    )
+                    .ok()
+                });
+
+        self.names = query
+            .iter()
+            .flat_map(::tree_sitter::Query::capture_names)
+            .map(|name| Arc::from(*name))
+            .collect();
+
+        self.query = query;
+        self.tree = None;
+        self.is_dirty = true;
+    }
+
+    fn line(&self, index: usize) -> &str {
+        &self.source[self.starts[index]..self.starts[index + 1] - 1]
+    }
+
+    fn line_count(&self) -> usize {
+        self.starts.len() - 1
+    }
+
+    /// Replaces the given range of lines with some new lines, editing the
+    /// syntax tree accordingly.
+    fn splice(&mut self, lines: Range<usize>, new_lines: &[impl AsRef<str>]) {
+        let start_byte = self.starts[lines.start];
+        let old_end_byte = self.starts[lines.end];
+
+        let mut text = String::new();
+        let mut starts = Vec::with_capacity(new_lines.len());
+
+        for line in new_lines {
+            text.push_str(line.as_ref());
+            text.push('\n');
+
+            starts.push(start_byte + text.len());
+        }
+
+        let new_end_byte = start_byte + text.len();
+
+        if let Some(tree) = &mut self.tree {
+            tree.edit(&::tree_sitter::InputEdit {
+                start_byte,
+                old_end_byte,
+                new_end_byte,
+                start_position: ::tree_sitter::Point::new(lines.start, 0),
+                old_end_position: ::tree_sitter::Point::new(lines.end, 0),
+                new_end_position: ::tree_sitter::Point::new(
+                    lines.start + new_lines.len(),
+                    0,
+                ),
+            });
+        }
+
+        self.source.replace_range(start_byte..old_end_byte, &text);
+
+        let _ = self.starts.splice(lines.start + 1..lines.end + 1, starts);
+
+        for start in &mut self.starts[lines.start + new_lines.len() + 1..] {
+            *start = *start + new_end_byte - old_end_byte;
+        }
+
+        self.is_dirty = true;
+    }
+
+    /// Synchronizes the line at the given index with its new text.
+    ///
+    /// The [`Highlighter`] is only notified of the first line that changed,
+    /// so insertions and removals of whole lines need to be inferred by
+    /// comparing the new text with the lines it already knows.
+    fn synchronize(&mut self, index: usize, text: &str) {
+        if index < self.line_count() && self.line(index) == text {
+            self.displaced.clear();
+            return;
+        }
+
+        // The lines replaced before were pushed down by new lines
+        if self.displaced.first().is_some_and(|line| line == text) {
+            let displaced = mem::take(&mut self.displaced);
+            let index = index.min(self.line_count());
+
+            self.splice(index..index, &displaced);
+            return;
+        }
+
+        if index >= self.line_count() {
+            let count = self.line_count();
+            let mut lines = vec![""; index - count];
+            lines.push(text);
+
+            self.splice(count..count, &lines);
+            return;
+        }
+
+        let removed = (index + 1..self.line_count().min(index + MAX_LOOKAHEAD))
+            .find(|i| self.line(*i) == text);
+
+        if let Some(end) = removed {
+            // Keep the removed lines around, in case they were not removed
+            // after all
+            self.displaced =
+                (index + 1..end).map(|i| self.line(i).to_owned()).collect();
+
+            self.splice(index..end, &[] as &[&str]);
+            return;
+        }
+
+        let line = self.line(index).to_owned();
+
+        self.splice(index..index + 1, &[text]);
+        self.displaced.push(line);
+    }
+
+    fn parse(&mut self) {
+        if self.query.is_some() {
+            self.tree = self.parser.parse(&self.source, self.tree.as_ref());
+        }
+
+        self.is_dirty = false;
+    }
+}
+
+impl highlighter::Highlighter for Highlighter {
+    type Settings = Settings;
+    type Highlight = Highlight;
+
+    type Iterator<'a> = std::vec::IntoIter<(Range<usize>, Self::Highlight)>;
+
+    fn new(settings: &Self::Settings) -> Self {
+        let mut highlighter = Self {
+            parser: ::tree_sitter::Parser::new(),
+            query: None,
+            cursor: ::tree_sitter::QueryCursor::new(),
+            names: Vec::new(),
+            tree: None,
+            source: String::new(),
+            starts: vec![0],
+            displaced: Vec::new(),
+            is_dirty: true,
+            current_line: 0,
+        };
+
+        highlighter.configure(settings);
+        highlighter
+    }
+
+    fn update(&mut self, new_settings: &Self::Settings) {
+        self.configure(new_settings);
+
+        // Restart the highlighter
+        self.change_line(0);
+    }
+
+    fn change_line(&mut self, line: usize) {
+        self.current_line = self.current_line.min(line);
+        self.displaced.clear();
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        let index = self.current_line;
+        self.current_line += 1;
+
+        self.synchronize(index, line);
+
+        if self.is_dirty {
+            self.parse();
+        }
+
+        let (Some(query), Some(tree)) = (&self.query, &self.tree) else {
+            return Vec::new().into_iter();
+        };
+
+        let start = self.starts[index];
+        let mut captures_by_byte = vec![None; line.len()];
+
+        let _ = self.cursor.set_byte_range(start..start + line.len());
+
+        let mut captures = self.cursor.captures(
+            query,
+            tree.root_node(),
+            self.source.as_bytes(),
+        );
+
+        let mut last_node = None;
+
+        while let Some((found, index)) = captures.next() {
+            let capture = found.captures[*index];
+
+            // Only the first pattern matching a node is used
+            if last_node == Some(capture.node.id())
+                || self.names[capture.index as usize].starts_with('_')
+            {
+                continue;
+            }
+
+            last_node = Some(capture.node.id());
+
+            let range = capture.node.byte_range();
+
+            for byte in
+                range.start.max(start)..range.end.min(start + line.len())
+            {
+                captures_by_byte[byte - start] = Some(capture.index as usize);
+            }
+        }
+
+        let mut highlights = Vec::new();
+        let mut range_start = 0;
+
+        for end in 1..=captures_by_byte.len() {
+            let capture = captures_by_byte[range_start];
+
+            if end < captures_by_byte.len() && captures_by_byte[end] == capture
+            {
+                continue;
+            }
+
+            if let Some(capture) = capture {
+                highlights.push((
+                    range_start..end,
+                    Highlight {
+                        name: self.names[capture].clone(),
+                    },
+                ));
+            }
+
+            range_start = end;
+        }
+
+        highlights.into_iter()
+    }
+
+    fn current_line(&self) -> usize {
+        self.current_line
+    }
+}
+
+impl fmt::Debug for Highlighter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Highlighter")
+            .field("names", &self.names)
+            .field("current_line", &self.current_line)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The settings of a tree-sitter [`Highlighter`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    /// The [`Language`] of the grammar used for parsing.
+    pub language: Language,
+    /// The source of the query that captures the nodes to highlight.
+    ///
+    /// Grammars normally ship with one in a `highlights.scm` file.
+    pub highlights: Cow<'static, str>,
+}
+
+impl Settings {
+    /// Creates new [`Settings`] with the given [`Language`] and highlights
+    /// query.
+    pub fn new(
+        language: impl Into<Language>,
+        highlights: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self {
+            language: language.into(),
+            highlights: highlights.into(),
+        }
+    }
+}
+
+/// A highlight produced by a tree-sitter [`Highlighter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Highlight {
+    name: Arc<str>,
+}
+
+impl Highlight {
+    /// Returns the name of the capture of this [`Highlight`]; like
+    /// `keyword` or `string.special`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the [`Format`] of the [`Highlight`] using the colors of the
+    /// given [`Palette`].
+    ///
+    /// Only the most common capture names are formatted. You can use
+    /// [`name`] to format the rest yourself.
+    ///
+    /// [`name`]: Self::name
+    pub fn to_format(&self, palette: &Palette) -> Format<Font> {
+        let kind = self.name.split('.').next().unwrap_or_default();
+
+        let color = match kind {
+            "comment" => Some(palette.text.scale_alpha(0.6)),
+            "keyword" | "attribute" => Some(palette.primary),
+            "string" | "escape" | "character" => Some(palette.success),
+            "constant" | "number" | "boolean" => Some(palette.danger),
+            _ => None,
+        };
+
+        Format { color, font: None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::highlighter::Highlighter as _;
+
+    fn highlighter(lines: &[&str]) -> Highlighter {
+        let mut highlighter = Highlighter::new(&Settings::new(
+            tree_sitter_rust::LANGUAGE,
+            tree_sitter_rust::HIGHLIGHTS_QUERY,
+        ));
+
+        for line in lines {
+            let _ = highlighter.highlight_line(line);
+        }
+
+        highlighter
+    }
+
+    fn names(highlighter: &mut Highlighter, line: &str) -> Vec<String> {
+        highlighter
+            .highlight_line(line)
+            .map(|(range, highlight)| {
+                format!("{}:{}", &line[range], highlight.name())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn it_highlights_captures() {
+        let mut highlighter = highlighter(&[]);
+
+        assert_eq!(
+            names(&mut highlighter, "fn main() { \"hi\" }"),
+            [
+                "fn:keyword",
+                "main:function",
+                "():punctuation.bracket",
+                "{:punctuation.bracket",
+                "\"hi\":string",
+                "}:punctuation.bracket",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_infers_inserted_and_removed_lines() {
+        let lines = ["fn a() {}", "fn b() {}", "fn c() {}", "fn d() {}"];
+        let mut highlighter = highlighter(&lines);
+
+        highlighter.change_line(1);
+
+        for line in ["// new", "fn b() {}", "fn c() {}", "fn d() {}"] {
+            let _ = highlighter.highlight_line(line);
+        }
+
+        assert_eq!(highlighter.line_count(), 5);
+        assert_eq!(
+            highlighter.source,
+            "fn a() {}\n// new\nfn b() {}\nfn c() {}\nfn d() {}\n"
+        );
+
+        highlighter.change_line(1);
+
+        for line in ["fn c() {}", "fn d() {}"] {
+            let _ = highlighter.highlight_line(line);
+        }
+
+        assert_eq!(highlighter.source, "fn a() {}\nfn c() {}\nfn d() {}\n");
+    }
+}
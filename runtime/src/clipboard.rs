@@ -1,6 +1,7 @@
 //! Access the clipboard.
 use crate::command::{self, Command};
-use crate::core::clipboard::Kind;
+use crate::core::clipboard::{mime, Bytes, Data, Kind};
+use crate::core::image;
 use crate::futures::MaybeSend;
 
 use std::fmt;
@@ -14,6 +15,17 @@ pub enum Action<T> {
 
     /// Write the given contents to the clipboard.
     Write(String, Kind),
+
+    /// Read the clipboard in the given MIME type and produce `T` with the
+    /// result.
+    ReadData(Box<dyn Fn(Option<Bytes>) -> T>, Kind, String),
+
+    /// Write the given representations of some contents to the clipboard.
+    WriteData(Vec<Data>, Kind),
+
+    /// Read the MIME types available in the clipboard and produce `T` with
+    /// the result.
+    ReadFormats(Box<dyn Fn(Vec<String>) -> T>, Kind),
 }
 
 impl<T> Action<T> {
@@ -30,6 +42,16 @@ impl<T> Action<T> {
                 Action::Read(Box::new(move |s| f(o(s))), target)
             }
             Self::Write(content, target) => Action::Write(content, target),
+            Self::ReadData(o, target, mime) => {
+                Action::ReadData(Box::new(move |data| f(o(data))), target, mime)
+            }
+            Self::WriteData(contents, target) => {
+                Action::WriteData(contents, target)
+            }
+            Self::ReadFormats(o, target) => Action::ReadFormats(
+                Box::new(move |formats| f(o(formats))),
+                target,
+            ),
         }
     }
 }
@@ -39,6 +61,18 @@ impl<T> fmt::Debug for Action<T> {
         match self {
             Self::Read(_, target) => write!(f, "Action::Read{target:?}"),
             Self::Write(_, target) => write!(f, "Action::Write({target:?})"),
+            Self::ReadData(_, target, mime) => {
+                write!(f, "Action::ReadData({target:?}, {mime:?})")
+            }
+            Self::WriteData(contents, target) => {
+                let formats: Vec<_> =
+                    contents.iter().map(|data| &data.mime).collect();
+
+                write!(f, "Action::WriteData({target:?}, {formats:?})")
+            }
+            Self::ReadFormats(_, target) => {
+                write!(f, "Action::ReadFormats({target:?})")
+            }
         }
     }
 }
@@ -78,3 +112,51 @@ pub fn write_primary<Message>(contents: String) -> Command<Message> {
         Kind::Primary,
     )))
 }
+
+/// Read the current contents of the clipboard in the given MIME type.
+pub fn read_data<Message>(
+    mime: impl Into<String>,
+    f: impl Fn(Option<Bytes>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadData(
+        Box::new(f),
+        Kind::Standard,
+        mime.into(),
+    )))
+}
+
+/// Write the given representations of some contents to the clipboard.
+///
+/// Each [`Data`] should be the same contents in a different MIME type, so
+/// the application pasting them can choose the one it understands best.
+pub fn write_data<Message>(
+    contents: impl IntoIterator<Item = Data>,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::WriteData(
+        contents.into_iter().collect(),
+        Kind::Standard,
+    )))
+}
+
+/// Read the MIME types the current contents of the clipboard are available
+/// in.
+pub fn read_formats<Message>(
+    f: impl Fn(Vec<String>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadFormats(
+        Box::new(f),
+        Kind::Standard,
+    )))
+}
+
+/// Read the current image of the clipboard, if any.
+pub fn read_image<Message>(
+    f: impl Fn(Option<image::Handle>) -> Message + 'static,
+) -> Command<Message> {
+    read_data(mime::PNG, move |png| f(png.map(image::Handle::from_bytes)))
+}
+
+/// Write the given image, encoded in PNG, to the clipboard.
+pub fn write_image<Message>(png: impl Into<Bytes>) -> Command<Message> {
+    write_data([Data::new(mime::PNG, png)])
+}
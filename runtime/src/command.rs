@@ -1,7 +1,9 @@
 //! Run asynchronous actions.
 mod action;
+mod chain;
 
 pub use action::Action;
+pub use chain::{Chain, Continuation};
 
 use crate::core::widget;
 use crate::futures::futures;
@@ -79,6 +81,171 @@ impl<T> Command<T> {
         Self(Internal::Batch(batch))
     }
 
+    /// Chains a new [`Command`] to be performed once the current one
+    /// produces a value.
+    ///
+    /// The given function is called with every output of the current
+    /// [`Command`], and the [`Command`] it returns is run right away.
+    /// This allows multi-step workflows to be expressed without any
+    /// intermediate messages; for instance, fetching the size of a window
+    /// and then using it inside an async block:
+    ///
+    /// ```no_run
+    /// # use iced_runtime::core::window::Id;
+    /// # use iced_runtime::{window, Command};
+    /// # async fn save_layout(_size: iced_runtime::core::Size) {}
+    /// # #[derive(Debug, Clone)]
+    /// # enum Message { LayoutSaved }
+    /// let command: Command<Message> =
+    ///     window::fetch_size(Id::MAIN, |size| size).then(|size| {
+    ///         Command::perform(save_layout(size), |_| Message::LayoutSaved)
+    ///     });
+    /// ```
+    pub fn then<A>(
+        self,
+        f: impl Fn(T) -> Command<A> + MaybeSend + Sync + Clone + 'static,
+    ) -> Command<A>
+    where
+        T: MaybeSend + 'static,
+        A: 'static,
+    {
+        match self.0 {
+            Internal::None => Command::none(),
+            Internal::Single(action) => Command::single(action.then(f)),
+            Internal::Batch(batch) => Command(Internal::Batch(
+                batch
+                    .into_iter()
+                    .map(|action| action.then(f.clone()))
+                    .collect(),
+            )),
+        }
+    }
+
+    /// Collects all of the outputs of the [`Command`] into a single `Vec`,
+    /// which is produced once every action of the [`Command`] has finished.
+    ///
+    /// This is useful to turn the results of a [`Command::batch`] into a
+    /// single message.
+    pub fn collect(self) -> Command<Vec<T>>
+    where
+        T: MaybeSend + 'static,
+    {
+        use futures::StreamExt;
+
+        let (sender, receiver) = mpsc::unbounded();
+
+        let command = self.then(move |output| {
+            let _ = sender.unbounded_send(output);
+
+            Command::none()
+        });
+
+        Command::batch([
+            command,
+            Command::perform(receiver.collect(), std::convert::identity),
+        ])
+    }
+
+    /// Turns the [`Command`] into a [`Future`] that resolves to its first
+    /// output, returning it alongside the [`Command`] that must be run for
+    /// the [`Future`] to resolve.
+    ///
+    /// The [`Future`] resolves to `None` if the [`Command`] finishes or is
+    /// dropped without producing any output.
+    ///
+    /// This allows the results of some runtime actions to be awaited inside
+    /// an async block:
+    ///
+    /// ```no_run
+    /// # use iced_runtime::core::window::Id;
+    /// # use iced_runtime::{window, Command};
+    /// # async fn save_layout(_size: iced_runtime::core::Size) {}
+    /// # #[derive(Debug, Clone)]
+    /// # enum Message { LayoutSaved }
+    /// let (fetch_size, size) =
+    ///     window::fetch_size(Id::MAIN, std::convert::identity).awaitable();
+    ///
+    /// let command: Command<Message> = Command::batch([
+    ///     fetch_size,
+    ///     Command::perform(
+    ///         async move {
+    ///             if let Some(size) = size.await {
+    ///                 save_layout(size).await;
+    ///             }
+    ///         },
+    ///         |_| Message::LayoutSaved,
+    ///     ),
+    /// ]);
+    /// ```
+    pub fn awaitable<A>(
+        self,
+    ) -> (Command<A>, impl Future<Output = Option<T>> + MaybeSend)
+    where
+        T: MaybeSend + 'static,
+        A: 'static,
+    {
+        use futures::StreamExt;
+
+        let (sender, mut receiver) = mpsc::unbounded();
+
+        let command = self.then(move |output| {
+            let _ = sender.unbounded_send(output);
+
+            Command::none()
+        });
+
+        (command, async move { receiver.next().await })
+    }
+
+    /// Makes the [`Command`] abortable, returning it alongside a [`Handle`]
+    /// that can be used to stop any of its running futures and streams.
+    ///
+    /// Aborted futures will not produce any result and aborted streams will
+    /// stop producing items. Any other kind of [`Action`] runs to completion
+    /// as usual.
+    ///
+    /// Dropping the [`Handle`] aborts the [`Command`]; therefore, it must be
+    /// kept around for as long as the [`Command`] should run. Use
+    /// [`Handle::detach`] to let the [`Command`] run to completion instead.
+    pub fn abortable(self) -> (Self, Handle)
+    where
+        T: MaybeSend + 'static,
+    {
+        use futures::future::{self, AbortHandle, Abortable};
+        use futures::stream::{self, StreamExt};
+
+        let mut handles = Vec::new();
+
+        let mut abortable = |action| match action {
+            Action::Future(future) => {
+                let (handle, registration) = AbortHandle::new_pair();
+                handles.push(handle);
+
+                Action::Stream(Box::pin(
+                    stream::once(Abortable::new(future, registration))
+                        .filter_map(|result| future::ready(result.ok())),
+                ))
+            }
+            Action::Stream(stream) => {
+                let (handle, registration) = AbortHandle::new_pair();
+                handles.push(handle);
+
+                Action::Stream(Box::pin(Abortable::new(stream, registration)))
+            }
+            action => action,
+        };
+
+        let command = match self.0 {
+            Internal::None => Internal::None,
+            Internal::Single(action) => Internal::Single(abortable(action)),
+            Internal::Batch(batch) => {
+                Internal::Batch(batch.into_iter().map(abortable).collect())
+            }
+        };
+
+        (Self(command), Handle { handles })
+    }
+
     /// Applies a transformation to the result of a [`Command`].
     pub fn map<A>(
         self,
@@ -112,6 +279,23 @@ impl<T> Command<T> {
     }
 }
 
+impl<T> Command<Option<T>> {
+    /// Chains a new [`Command`] to be performed once the current one
+    /// produces `Some` value.
+    ///
+    /// Any `None` outputs of the current [`Command`] are discarded.
+    pub fn and_then<A>(
+        self,
+        f: impl Fn(T) -> Command<A> + MaybeSend + Sync + Clone + 'static,
+    ) -> Command<A>
+    where
+        T: MaybeSend + 'static,
+        A: 'static,
+    {
+        self.then(move |option| option.map_or_else(Command::none, f.clone()))
+    }
+}
+
 impl<Message> From<()> for Command<Message> {
     fn from(_value: ()) -> Self {
         Self::none()
@@ -126,6 +310,48 @@ impl<T> fmt::Debug for Command<T> {
     }
 }
 
+/// A handle to an abortable [`Command`].
+///
+/// It can be obtained by calling [`Command::abortable`].
+///
+/// The [`Command`] is aborted when the [`Handle`] is dropped, unless it is
+/// detached with [`Handle::detach`].
+#[derive(Debug)]
+#[must_use = "dropping a `Handle` aborts its `Command`"]
+pub struct Handle {
+    handles: Vec<futures::future::AbortHandle>,
+}
+
+impl Handle {
+    /// Aborts the [`Command`] of this [`Handle`].
+    ///
+    /// Futures and streams that are already finished are not affected.
+    pub fn abort(&self) {
+        for handle in &self.handles {
+            handle.abort();
+        }
+    }
+
+    /// Drops the [`Handle`] without aborting its [`Command`], letting it
+    /// run to completion.
+    pub fn detach(mut self) {
+        self.handles.clear();
+    }
+
+    /// Returns `true` if the [`Command`] of this [`Handle`] has been aborted.
+    pub fn is_aborted(&self) -> bool {
+        self.handles
+            .iter()
+            .any(futures::future::AbortHandle::is_aborted)
+    }
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        self.abort();
+    }
+}
+
 /// Creates a [`Command`] that produces the `Message`s published from a [`Future`]
 /// to an [`mpsc::Sender`] with the given bounds.
 pub fn channel<Fut, Message>(
@@ -145,3 +371,178 @@ where
 
     Command::single(Action::Stream(Box::pin(stream::select(receiver, runner))))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::executor;
+    use futures::stream::{self, BoxStream, StreamExt};
+
+    enum Step<T> {
+        Output(T),
+        Continue(Continuation<T>),
+    }
+
+    // A minimal runtime that resolves chains like a shell would
+    fn run<T>(command: Command<T>) -> Vec<T>
+    where
+        T: Send + 'static,
+    {
+        let mut running = stream::SelectAll::new();
+        running.extend(command.actions().into_iter().map(into_stream));
+
+        executor::block_on(async move {
+            let mut outputs = Vec::new();
+
+            while let Some(step) = running.next().await {
+                match step {
+                    Step::Output(output) => outputs.push(output),
+                    Step::Continue(continuation) => running.extend(
+                        continuation().actions().into_iter().map(into_stream),
+                    ),
+                }
+            }
+
+            outputs
+        })
+    }
+
+    fn into_stream<T>(action: Action<T>) -> BoxStream<'static, Step<T>>
+    where
+        T: Send + 'static,
+    {
+        match action {
+            Action::Future(future) => {
+                stream::once(future).map(Step::Output).boxed()
+            }
+            Action::Stream(stream) => stream.map(Step::Output).boxed(),
+            Action::Chain(chain) => match chain.resolve(Step::Continue) {
+                Action::Future(future) => stream::once(future).boxed(),
+                Action::Stream(stream) => stream,
+                action => panic!("Unsupported action: {action:?}"),
+            },
+            action => panic!("Unsupported action: {action:?}"),
+        }
+    }
+
+    fn numbers() -> Command<u32> {
+        Command::batch([
+            Command::perform(async { 1 }, std::convert::identity),
+            Command::run(stream::iter([2, 3]), std::convert::identity),
+        ])
+    }
+
+    #[test]
+    fn aborted_commands_produce_nothing() {
+        let (command, handle) = numbers().abortable();
+
+        handle.abort();
+
+        assert!(handle.is_aborted());
+        assert!(run(command).is_empty());
+    }
+
+    #[test]
+    fn dropping_a_handle_aborts_its_command() {
+        let (command, handle) = numbers().abortable();
+
+        drop(handle);
+
+        assert!(run(command).is_empty());
+    }
+
+    #[test]
+    fn detached_commands_run_to_completion() {
+        let (command, handle) = numbers().abortable();
+
+        handle.detach();
+
+        let mut outputs = run(command);
+        outputs.sort_unstable();
+
+        assert_eq!(outputs, [1, 2, 3]);
+    }
+
+    #[test]
+    fn chains_resolve_into_continuations() {
+        let command = Command::perform(async { 2 }, std::convert::identity)
+            .then(|n: u32| {
+                Command::perform(async move { n * 10 }, std::convert::identity)
+            });
+
+        let mut actions = command.actions();
+        assert_eq!(actions.len(), 1);
+
+        let Some(Action::Chain(chain)) = actions.pop() else {
+            panic!("Command should be a chain");
+        };
+
+        let Action::Future(continuation) = chain.resolve(|next| next) else {
+            panic!("Chain should resolve into a future");
+        };
+
+        let next = executor::block_on(continuation)();
+
+        assert_eq!(run(next), [20]);
+    }
+
+    #[test]
+    fn then_runs_a_command_for_every_output() {
+        let command = numbers().then(|n| {
+            Command::run(stream::iter([n, n * 10]), std::convert::identity)
+        });
+
+        let mut outputs = run(command);
+        outputs.sort_unstable();
+
+        assert_eq!(outputs, [1, 2, 3, 10, 20, 30]);
+    }
+
+    #[test]
+    fn and_then_discards_none() {
+        let command = Command::run(
+            stream::iter([Some(1), None, Some(2)]),
+            std::convert::identity,
+        )
+        .and_then(|n: u32| Command::perform(async move { n }, Some));
+
+        let mut outputs = run(command);
+        outputs.sort_unstable();
+
+        assert_eq!(outputs, [Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn collect_produces_every_output_of_a_batch() {
+        let mut outputs = run(numbers().collect());
+        assert_eq!(outputs.len(), 1);
+
+        let outputs = &mut outputs[0];
+        outputs.sort_unstable();
+
+        assert_eq!(outputs, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn collect_of_nothing_is_empty() {
+        assert_eq!(run(Command::<u32>::none().collect()), [Vec::new()]);
+    }
+
+    #[test]
+    fn awaitable_resolves_to_the_first_output() {
+        let (command, output) = numbers().awaitable::<()>();
+
+        assert!(run(command).is_empty());
+        assert!(matches!(executor::block_on(output), Some(1..=3)));
+    }
+
+    #[test]
+    fn awaitable_resolves_to_none_without_outputs() {
+        let (command, output) = Command::<u32>::none().awaitable::<()>();
+
+        drop(command);
+
+        assert_eq!(executor::block_on(output), None);
+    }
+}
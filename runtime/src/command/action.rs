@@ -1,4 +1,5 @@
 use crate::clipboard;
+use crate::command::{Chain, Command};
 use crate::core::widget;
 use crate::font;
 use crate::futures::MaybeSend;
@@ -44,6 +45,11 @@ pub enum Action<T> {
         tagger: Box<dyn Fn(Result<(), font::Error>) -> T>,
     },
 
+    /// Run an action and then the [`Command`] produced by each of its outputs.
+    ///
+    /// [`Command`]: crate::Command
+    Chain(Chain<T>),
+
     /// A custom action supported by a specific runtime.
     Custom(Box<dyn Any>),
 }
@@ -75,7 +81,27 @@ impl<T> Action<T> {
                 bytes,
                 tagger: Box::new(move |result| f(tagger(result))),
             },
+            Self::Chain(chain) => Action::Chain(chain.map(f)),
+            Self::Custom(custom) => Action::Custom(custom),
+        }
+    }
+
+    /// Chains a new [`Command`] to every output of the [`Action`].
+    ///
+    /// Custom actions do not produce any output and, therefore, are
+    /// left untouched.
+    pub fn then<A>(
+        self,
+        f: impl Fn(T) -> Command<A> + MaybeSend + Sync + Clone + 'static,
+    ) -> Action<A>
+    where
+        T: MaybeSend + 'static,
+        A: 'static,
+    {
+        match self {
+            Self::Chain(chain) => Action::Chain(chain.then(f)),
             Self::Custom(custom) => Action::Custom(custom),
+            action => Action::Chain(Chain::new(action, f)),
         }
     }
 }
@@ -94,6 +120,7 @@ impl<T> fmt::Debug for Action<T> {
             Self::System(action) => write!(f, "Action::System({action:?})"),
             Self::Widget(_action) => write!(f, "Action::Widget"),
             Self::LoadFont { .. } => write!(f, "Action::LoadFont"),
+            Self::Chain(chain) => write!(f, "Action::{chain:?}"),
             Self::Custom(_) => write!(f, "Action::Custom"),
         }
     }
@@ -0,0 +1,135 @@
+use crate::command::{Action, Command};
+use crate::futures::MaybeSend;
+
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+
+#[cfg(not(target_arch = "wasm32"))]
+type Output = Box<dyn Any + Send>;
+
+#[cfg(target_arch = "wasm32")]
+type Output = Box<dyn Any>;
+
+#[cfg(not(target_arch = "wasm32"))]
+type Then<T> = Arc<dyn Fn(Output) -> Command<T> + Send + Sync>;
+
+#[cfg(target_arch = "wasm32")]
+type Then<T> = Arc<dyn Fn(Output) -> Command<T>>;
+
+#[cfg(not(target_arch = "wasm32"))]
+type Map<T, A> = Arc<dyn Fn(T) -> A + Send + Sync>;
+
+#[cfg(target_arch = "wasm32")]
+type Map<T, A> = Arc<dyn Fn(T) -> A>;
+
+/// The [`Command`] produced by an output of a [`Chain`], ready to be run.
+#[cfg(not(target_arch = "wasm32"))]
+pub type Continuation<T> = Box<dyn FnOnce() -> Command<T> + Send>;
+
+/// The [`Command`] produced by an output of a [`Chain`], ready to be run.
+#[cfg(target_arch = "wasm32")]
+pub type Continuation<T> = Box<dyn FnOnce() -> Command<T>>;
+
+/// An [`Action`] followed by the [`Command`] to run for each of its outputs.
+///
+/// A [`Chain`] can be created with [`Command::then`].
+pub struct Chain<T> {
+    action: Box<Action<Output>>,
+    then: Then<T>,
+}
+
+impl<T> Chain<T> {
+    /// Creates a new [`Chain`] that runs the [`Command`] produced by
+    /// the given function for every output of the [`Action`].
+    pub fn new<A>(
+        action: Action<A>,
+        f: impl Fn(A) -> Command<T> + MaybeSend + Sync + 'static,
+    ) -> Self
+    where
+        A: MaybeSend + 'static,
+    {
+        Self {
+            action: Box::new(action.map(|output| Box::new(output) as Output)),
+            then: Arc::new(move |output: Output| {
+                f(*output
+                    .downcast()
+                    .expect("Downcast output of chained action"))
+            }),
+        }
+    }
+
+    /// Resolves the [`Chain`] into an [`Action`] that produces
+    /// a [`Continuation`] for every output of the original [`Action`].
+    ///
+    /// A runtime must run the [`Command`] of each [`Continuation`] as soon
+    /// as it is produced.
+    pub fn resolve<A>(
+        self,
+        f: impl Fn(Continuation<T>) -> A + MaybeSend + Sync + 'static,
+    ) -> Action<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        let then = self.then;
+
+        self.action.map(move |output| {
+            let then = then.clone();
+
+            f(Box::new(move || then(output)))
+        })
+    }
+
+    /// Chains a new [`Command`] to every output of the [`Chain`].
+    pub fn then<A>(
+        self,
+        f: impl Fn(T) -> Command<A> + MaybeSend + Sync + Clone + 'static,
+    ) -> Chain<A>
+    where
+        T: MaybeSend + 'static,
+        A: 'static,
+    {
+        let then = self.then;
+
+        Chain {
+            action: self.action,
+            then: Arc::new(move |output| then(output).then(f.clone())),
+        }
+    }
+
+    /// Applies a transformation to the result of a [`Chain`].
+    pub fn map<A>(
+        self,
+        f: impl Fn(T) -> A + 'static + MaybeSend + Sync,
+    ) -> Chain<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        Chain {
+            action: self.action,
+            then: map(self.then, Arc::new(f)),
+        }
+    }
+}
+
+// Mapping is done with boxed functions; otherwise, every nested chain would
+// instantiate a new closure type and compilation would never finish.
+fn map<T, A>(then: Then<T>, f: Map<T, A>) -> Then<A>
+where
+    T: 'static,
+    A: 'static,
+{
+    Arc::new(move |output| {
+        let f = f.clone();
+
+        then(output).map(move |value| f(value))
+    })
+}
+
+impl<T> fmt::Debug for Chain<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Chain({:?})", self.action)
+    }
+}
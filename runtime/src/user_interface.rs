@@ -5,7 +5,9 @@ use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::widget;
 use crate::core::window;
-use crate::core::{Clipboard, Element, Layout, Rectangle, Shell, Size, Vector};
+use crate::core::{
+    Clipboard, Element, InputMethod, Layout, Rectangle, Shell, Size, Vector,
+};
 use crate::overlay;
 
 /// A set of interactive graphical elements with a specific [`Layout`].
@@ -187,6 +189,7 @@ where
 
         let mut outdated = false;
         let mut redraw_request = None;
+        let mut input_method = InputMethod::Disabled;
 
         let mut manual_overlay = ManuallyDrop::new(
             self.root
@@ -231,6 +234,10 @@ where
                     _ => {}
                 }
 
+                if !input_method.is_enabled() {
+                    input_method = shell.input_method();
+                }
+
                 if shell.is_layout_invalid() {
                     let _ = ManuallyDrop::into_inner(manual_overlay);
 
@@ -333,6 +340,10 @@ where
                     _ => {}
                 }
 
+                if !input_method.is_enabled() {
+                    input_method = shell.input_method();
+                }
+
                 shell.revalidate_layout(|| {
                     self.base = self.root.as_widget().layout(
                         &mut self.state,
@@ -355,7 +366,10 @@ where
             if outdated {
                 State::Outdated
             } else {
-                State::Updated { redraw_request }
+                State::Updated {
+                    redraw_request,
+                    input_method,
+                }
             },
             event_statuses,
         )
@@ -646,5 +660,8 @@ pub enum State {
     Updated {
         /// The [`window::RedrawRequest`] when a redraw should be performed.
         redraw_request: Option<window::RedrawRequest>,
+
+        /// The [`InputMethod`] state requested by the widgets.
+        input_method: InputMethod,
     },
 }
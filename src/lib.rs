@@ -194,14 +194,15 @@ pub mod advanced;
 pub mod multi_window;
 
 pub use crate::core::alignment;
+pub use crate::core::animation;
 pub use crate::core::border;
 pub use crate::core::color;
 pub use crate::core::gradient;
 pub use crate::core::theme;
 pub use crate::core::{
-    Alignment, Background, Border, Color, ContentFit, Degrees, Gradient,
-    Length, Padding, Pixels, Point, Radians, Rectangle, Rotation, Shadow, Size,
-    Theme, Transformation, Vector,
+    Alignment, Animation, Background, Border, Color, ContentFit, Degrees,
+    Gradient, Length, Padding, Pixels, Point, Radians, Rectangle, Rotation,
+    Shadow, Size, Theme, Transformation, Vector,
 };
 
 pub mod clipboard {
@@ -256,7 +257,7 @@ pub mod mouse {
 
 pub mod command {
     //! Run asynchronous actions.
-    pub use crate::runtime::command::{channel, Command};
+    pub use crate::runtime::command::{channel, Command, Handle};
 }
 
 pub mod subscription {
@@ -273,6 +274,18 @@ pub mod system {
     pub use crate::shell::system::*;
 }
 
+#[cfg(feature = "fs")]
+pub mod fs {
+    //! Watch the filesystem for changes.
+    pub use iced_futures::fs::*;
+}
+
+#[cfg(all(feature = "process", not(target_arch = "wasm32")))]
+pub mod process {
+    //! Run child processes and listen to their output.
+    pub use iced_futures::process::*;
+}
+
 pub mod overlay {
     //! Display interactive elements on top of other widgets.
 
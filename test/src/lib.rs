@@ -0,0 +1,483 @@
+//! Test your iced applications in headless mode.
+//!
+//! A [`Simulator`] builds a [`UserInterface`] out of an [`Element`] without
+//! any window or graphics backend. You can then find widgets with a
+//! [`Selector`], interact with them by injecting synthetic events, and assert
+//! on the messages produced.
+//!
+//! When using the [`iced_tiny_skia`] renderer, a [`Simulator`] can also take
+//! a [`Snapshot`] of the user interface and compare it against a PNG image
+//! stored on disk, without the need of a display or a GPU.
+//!
+//! # Example
+//! ```
+//! use iced_test::simulator;
+//! use iced_widget::{button, column, text};
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum Message {
+//!     Increment,
+//! }
+//!
+//! let counter: iced_widget::Column<'_, Message, iced_widget::Theme, ()> =
+//!     column![text("0"), button("+").on_press(Message::Increment)];
+//!
+//! let mut ui = simulator(counter);
+//!
+//! assert!(ui.find("0").is_ok());
+//! assert!(ui.click("+").is_ok());
+//!
+//! assert_eq!(ui.into_messages().collect::<Vec<_>>(), [Message::Increment]);
+//! ```
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
+)]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+pub use iced_runtime as runtime;
+pub use iced_runtime::core;
+
+pub mod selector;
+pub mod snapshot;
+
+pub use selector::{Selector, Target};
+pub use snapshot::Snapshot;
+
+use crate::core::clipboard;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::mouse;
+use crate::core::{Element, Point, Size};
+use crate::runtime::user_interface::{self, UserInterface};
+
+use std::borrow::Cow;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// The default size of the viewport of a [`Simulator`].
+pub const DEFAULT_SIZE: Size = Size::new(1024.0, 768.0);
+
+/// Creates a new [`Simulator`] for the given [`Element`] with the
+/// [`DEFAULT_SIZE`].
+pub fn simulator<'a, Message, Theme, Renderer>(
+    element: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Simulator<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer + Default,
+{
+    Simulator::new(element)
+}
+
+/// A user interface that can be interacted with and inspected without a
+/// window.
+#[allow(missing_debug_implementations)]
+pub struct Simulator<'a, Message, Theme = core::Theme, Renderer = ()> {
+    raw: UserInterface<'a, Message, Theme, Renderer>,
+    renderer: Renderer,
+    size: Size,
+    clipboard: Clipboard,
+    cursor: mouse::Cursor,
+    messages: Vec<Message>,
+}
+
+/// An error produced by a [`Simulator`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// No widget matched the [`Selector`].
+    #[error("no widget matched the selector: {0:?}")]
+    NotFound(Selector),
+
+    /// An input/output operation failed.
+    #[error("input/output operation failed: {0}")]
+    IOFailed(Arc<io::Error>),
+
+    /// A PNG image could not be decoded.
+    #[error("PNG image could not be decoded: {0}")]
+    PngDecodingFailed(Arc<png::DecodingError>),
+
+    /// A PNG image could not be encoded.
+    #[error("PNG image could not be encoded: {0}")]
+    PngEncodingFailed(Arc<png::EncodingError>),
+
+    /// A PNG image has an unsupported color format.
+    #[error("PNG image has an unsupported color format: {0:?}")]
+    UnsupportedPngFormat(png::ColorType),
+
+    /// The reference image of a [`Snapshot`] does not exist.
+    #[error("reference snapshot not found: {}", .0.display())]
+    SnapshotNotFound(PathBuf),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::IOFailed(Arc::new(error))
+    }
+}
+
+impl From<png::DecodingError> for Error {
+    fn from(error: png::DecodingError) -> Self {
+        Self::PngDecodingFailed(Arc::new(error))
+    }
+}
+
+impl From<png::EncodingError> for Error {
+    fn from(error: png::EncodingError) -> Self {
+        Self::PngEncodingFailed(Arc::new(error))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Simulator<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer + Default,
+{
+    /// Creates a new [`Simulator`] for the given [`Element`] with the
+    /// [`DEFAULT_SIZE`].
+    pub fn new(
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self::with_size(DEFAULT_SIZE, element)
+    }
+
+    /// Creates a new [`Simulator`] for the given [`Element`] with the given
+    /// viewport [`Size`].
+    pub fn with_size(
+        size: impl Into<Size>,
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self::with_cache(size, user_interface::Cache::default(), element)
+    }
+
+    /// Creates a new [`Simulator`] for the given [`Element`] reusing the
+    /// widget state of a previous [`Simulator`].
+    ///
+    /// You can obtain a [`user_interface::Cache`] by calling
+    /// [`Simulator::into_cache`].
+    pub fn with_cache(
+        size: impl Into<Size>,
+        cache: user_interface::Cache,
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let size = size.into();
+        let mut renderer = Renderer::default();
+
+        let raw = UserInterface::build(element, size, cache, &mut renderer);
+
+        Self {
+            raw,
+            renderer,
+            size,
+            clipboard: Clipboard::default(),
+            cursor: mouse::Cursor::Unavailable,
+            messages: Vec::new(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Simulator<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// Finds the first widget matching the given [`Selector`].
+    pub fn find(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<Target, Error> {
+        let selector = selector.into();
+        let mut find = selector::Find::new(selector.clone());
+
+        self.raw.operate(&self.renderer, &mut find);
+
+        find.target().ok_or(Error::NotFound(selector))
+    }
+
+    /// Moves the mouse cursor to the given position.
+    pub fn point_at(&mut self, position: impl Into<Point>) {
+        let position = position.into();
+
+        self.cursor = mouse::Cursor::Available(position);
+
+        let _ = self
+            .simulate([Event::Mouse(mouse::Event::CursorMoved { position })]);
+    }
+
+    /// Clicks the center of the first widget matching the given [`Selector`]
+    /// with the left mouse button.
+    pub fn click(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<Target, Error> {
+        let target = self.find(selector)?;
+
+        self.point_at(target.bounds.center());
+
+        let _ = self.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        Ok(target)
+    }
+
+    /// Presses and releases the given key.
+    pub fn tap_key(&mut self, key: impl Into<keyboard::Key>) -> event::Status {
+        self.tap_key_with(key, keyboard::Modifiers::default())
+    }
+
+    /// Presses and releases the given key while holding the given
+    /// [`keyboard::Modifiers`].
+    pub fn tap_key_with(
+        &mut self,
+        key: impl Into<keyboard::Key>,
+        modifiers: keyboard::Modifiers,
+    ) -> event::Status {
+        let key = key.into();
+
+        let text = match key.as_ref() {
+            keyboard::Key::Character(c) if !modifiers.command() => {
+                Some(core::SmolStr::new(c))
+            }
+            _ => None,
+        };
+
+        let statuses = self.simulate([
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)),
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: key.clone(),
+                location: keyboard::Location::Standard,
+                modifiers,
+                text,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key,
+                location: keyboard::Location::Standard,
+                modifiers,
+            }),
+            Event::Keyboard(keyboard::Event::ModifiersChanged(
+                keyboard::Modifiers::default(),
+            )),
+        ]);
+
+        statuses
+            .into_iter()
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Types the given text, one character at a time, into the focused
+    /// widget.
+    pub fn typewrite(&mut self, text: &str) -> event::Status {
+        text.chars()
+            .map(|c| {
+                self.tap_key(keyboard::Key::Character(core::SmolStr::new(
+                    c.to_string(),
+                )))
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Processes the given events, returning the [`event::Status`] of each
+    /// one.
+    pub fn simulate(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<event::Status> {
+        let events: Vec<Event> = events.into_iter().collect();
+
+        let (_state, statuses) = self.raw.update(
+            &events,
+            self.cursor,
+            &mut self.renderer,
+            &mut self.clipboard,
+            &mut self.messages,
+        );
+
+        statuses
+    }
+
+    /// Returns the text contents of the clipboard of the [`Simulator`].
+    pub fn clipboard(&self) -> Option<&str> {
+        self.clipboard
+            .contents
+            .iter()
+            .find(|data| clipboard::mime::is_text(&data.mime))
+            .and_then(clipboard::Data::as_text)
+    }
+
+    /// Returns the contents of the clipboard of the [`Simulator`] in every
+    /// MIME type they are available in.
+    pub fn clipboard_data(&self) -> &[clipboard::Data] {
+        &self.clipboard.contents
+    }
+
+    /// Sets the text contents of the clipboard of the [`Simulator`].
+    pub fn set_clipboard(&mut self, contents: impl Into<Cow<'static, str>>) {
+        self.set_clipboard_data([clipboard::Data::text(
+            contents.into().into_owned(),
+        )]);
+    }
+
+    /// Sets the contents of the clipboard of the [`Simulator`] in multiple
+    /// MIME types.
+    pub fn set_clipboard_data(
+        &mut self,
+        contents: impl IntoIterator<Item = clipboard::Data>,
+    ) {
+        self.clipboard.contents = contents.into_iter().collect();
+    }
+
+    /// Returns the messages produced so far by the [`Simulator`].
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Consumes the [`Simulator`] and returns the messages it produced.
+    pub fn into_messages(self) -> impl Iterator<Item = Message> {
+        self.messages.into_iter()
+    }
+
+    /// Consumes the [`Simulator`] and returns its [`user_interface::Cache`],
+    /// so the widget state can be reused with [`Simulator::with_cache`].
+    pub fn into_cache(self) -> user_interface::Cache {
+        self.raw.into_cache()
+    }
+}
+
+#[derive(Debug, Default)]
+struct Clipboard {
+    contents: Vec<clipboard::Data>,
+}
+
+impl core::Clipboard for Clipboard {
+    fn read(&self, kind: clipboard::Kind) -> Option<String> {
+        self.read_data(kind, clipboard::mime::TEXT)
+            .and_then(|bytes| String::from_utf8(bytes.to_vec()).ok())
+    }
+
+    fn write(&mut self, kind: clipboard::Kind, contents: String) {
+        self.write_data(kind, vec![clipboard::Data::text(contents)]);
+    }
+
+    fn read_data(
+        &self,
+        _kind: clipboard::Kind,
+        mime: &str,
+    ) -> Option<clipboard::Bytes> {
+        let is_text = clipboard::mime::is_text(mime);
+
+        self.contents
+            .iter()
+            .find(|data| {
+                data.mime == mime
+                    || is_text && clipboard::mime::is_text(&data.mime)
+            })
+            .map(|data| data.bytes.clone())
+    }
+
+    fn write_data(
+        &mut self,
+        _kind: clipboard::Kind,
+        contents: Vec<clipboard::Data>,
+    ) {
+        self.contents = contents;
+    }
+
+    fn formats(&self, _kind: clipboard::Kind) -> Vec<String> {
+        self.contents.iter().map(|data| data.mime.clone()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_widget::{button, column, text, text_input};
+
+    type Column<'a> = iced_widget::Column<'a, Message, iced_widget::Theme, ()>;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Increment,
+        Input(String),
+    }
+
+    #[test]
+    fn click_by_text() {
+        let counter: Column<'_> =
+            column![text("0"), button("+").on_press(Message::Increment)];
+
+        let mut ui = simulator(counter);
+
+        assert!(ui.click("+").is_ok());
+        assert!(matches!(ui.click("-"), Err(Error::NotFound(_))));
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            [Message::Increment]
+        );
+    }
+
+    #[test]
+    fn typewrite_into_text_input() {
+        let form: Column<'_> = column![text_input("Name", "")
+            .id(text_input::Id::new("name"))
+            .on_input(Message::Input)];
+
+        let mut ui = simulator(form);
+
+        assert!(ui.click(selector::id(text_input::Id::new("name"))).is_ok());
+        assert_eq!(ui.typewrite("hi"), event::Status::Captured);
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            [
+                Message::Input("h".to_owned()),
+                Message::Input("hi".to_owned())
+            ]
+        );
+    }
+
+    #[test]
+    fn clipboard_round_trip() {
+        let form: Column<'_> = column![text_input("Name", "iced")
+            .id(text_input::Id::new("name"))
+            .on_input(Message::Input)];
+
+        let mut ui = simulator(form);
+
+        assert!(ui.click(selector::id(text_input::Id::new("name"))).is_ok());
+
+        let _ = ui.tap_key_with(
+            keyboard::Key::Character("a".into()),
+            keyboard::Modifiers::COMMAND,
+        );
+        let _ = ui.tap_key_with(
+            keyboard::Key::Character("c".into()),
+            keyboard::Modifiers::COMMAND,
+        );
+
+        assert_eq!(ui.clipboard(), Some("iced"));
+
+        ui.set_clipboard("rust");
+
+        let _ = ui.tap_key_with(
+            keyboard::Key::Character("v".into()),
+            keyboard::Modifiers::COMMAND,
+        );
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            [Message::Input("rust".to_owned())]
+        );
+    }
+
+    #[test]
+    fn secure_text_input_is_not_exposed() {
+        let form: Column<'_> = column![text_input("Password", "hunter2")
+            .on_input(Message::Input)
+            .secure(true)];
+
+        let mut ui = simulator(form);
+
+        assert!(ui.find("hunter2").is_err());
+        assert!(ui.find("•••••••").is_ok());
+    }
+}
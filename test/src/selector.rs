@@ -0,0 +1,134 @@
+//! Select widgets of a user interface.
+use crate::core::widget::operation::{Operation, Scrollable};
+use crate::core::widget::Id;
+use crate::core::{Rectangle, Vector};
+
+use std::borrow::Cow;
+
+/// A description of the widget to find in a user interface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selector {
+    /// Find the widget with the given [`Id`].
+    Id(Id),
+
+    /// Find the widget containing exactly the given text.
+    Text(Cow<'static, str>),
+}
+
+/// Creates a [`Selector`] that finds the widget with the given [`Id`].
+pub fn id(id: impl Into<Id>) -> Selector {
+    Selector::Id(id.into())
+}
+
+/// Creates a [`Selector`] that finds the widget containing exactly the
+/// given text.
+pub fn text(text: impl Into<Cow<'static, str>>) -> Selector {
+    Selector::Text(text.into())
+}
+
+impl From<Id> for Selector {
+    fn from(id: Id) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<&'static str> for Selector {
+    fn from(text: &'static str) -> Self {
+        Self::Text(Cow::Borrowed(text))
+    }
+}
+
+impl From<String> for Selector {
+    fn from(text: String) -> Self {
+        Self::Text(Cow::Owned(text))
+    }
+}
+
+/// A widget found by a [`Selector`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Target {
+    /// The visible bounds of the widget, in window coordinates.
+    pub bounds: Rectangle,
+}
+
+/// An [`Operation`] that looks for the first widget matching a [`Selector`].
+pub(crate) struct Find {
+    selector: Selector,
+    translation: Vector,
+    pending_translation: Option<Vector>,
+    target: Option<Target>,
+}
+
+impl Find {
+    pub fn new(selector: Selector) -> Self {
+        Self {
+            selector,
+            translation: Vector::ZERO,
+            pending_translation: None,
+            target: None,
+        }
+    }
+
+    pub fn target(self) -> Option<Target> {
+        self.target
+    }
+
+    fn found(&mut self, bounds: Rectangle) {
+        self.target = Some(Target {
+            bounds: bounds - self.translation,
+        });
+    }
+}
+
+impl<T> Operation<T> for Find {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        let translation = self.pending_translation.take();
+
+        if self.target.is_some() {
+            return;
+        }
+
+        match (&self.selector, id) {
+            (Selector::Id(target), Some(id)) if target == id => {
+                self.found(bounds);
+            }
+            _ => {
+                let offset = translation.unwrap_or(Vector::ZERO);
+
+                self.translation = self.translation + offset;
+                operate_on_children(self);
+                self.translation = self.translation - offset;
+            }
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        translation: Vector,
+    ) {
+        self.pending_translation = Some(translation);
+    }
+
+    fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+        if self.target.is_some() {
+            return;
+        }
+
+        let is_match = match &self.selector {
+            Selector::Id(target) => Some(target) == id,
+            Selector::Text(target) => target == text,
+        };
+
+        if is_match {
+            self.found(bounds);
+        }
+    }
+}
@@ -0,0 +1,388 @@
+//! Take snapshots of a user interface and compare them against PNG images.
+use crate::core::renderer;
+use crate::core::{Color, Size, Theme};
+use crate::runtime::window::Screenshot;
+use crate::{Error, Simulator};
+
+use iced_tiny_skia::graphics::Viewport;
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The environment variable that makes [`Snapshot::matches_png`] record
+/// the reference images instead of comparing against them.
+pub const RECORD_VARIABLE: &str = "ICED_TEST_RECORD";
+
+/// The renderer used to take a [`Snapshot`].
+pub type Renderer = iced_tiny_skia::Renderer;
+
+/// The rendered pixels of a user interface.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    screenshot: Screenshot,
+}
+
+impl Snapshot {
+    /// Creates a new [`Snapshot`] from the given [`Screenshot`].
+    pub fn new(screenshot: Screenshot) -> Self {
+        Self { screenshot }
+    }
+
+    /// Returns the [`Screenshot`] of the [`Snapshot`].
+    pub fn screenshot(&self) -> &Screenshot {
+        &self.screenshot
+    }
+
+    /// Returns the size of the [`Snapshot`] in physical pixels.
+    pub fn size(&self) -> Size<u32> {
+        self.screenshot.size
+    }
+
+    /// Returns the `RGBA` bytes of the [`Snapshot`].
+    pub fn rgba(&self) -> &[u8] {
+        &self.screenshot.bytes
+    }
+
+    /// Loads a [`Snapshot`] from the PNG image at the given path.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let file = io::BufReader::new(fs::File::open(path)?);
+
+        let mut decoder = png::Decoder::new(file);
+        decoder.set_transformations(
+            png::Transformations::normalize_to_color8()
+                | png::Transformations::ALPHA,
+        );
+
+        let mut reader = decoder.read_info()?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer)?;
+        buffer.truncate(info.buffer_size());
+
+        let rgba = match info.color_type {
+            png::ColorType::Rgba => buffer,
+            png::ColorType::GrayscaleAlpha => buffer
+                .chunks_exact(2)
+                .flat_map(|pixel| [pixel[0], pixel[0], pixel[0], pixel[1]])
+                .collect(),
+            color_type => {
+                return Err(Error::UnsupportedPngFormat(color_type));
+            }
+        };
+
+        Ok(Self::new(Screenshot::new(
+            rgba,
+            Size::new(info.width, info.height),
+            1.0,
+        )))
+    }
+
+    /// Saves the [`Snapshot`] as a PNG image at the given path.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let file = io::BufWriter::new(fs::File::create(path)?);
+        let size = self.size();
+
+        let mut encoder = png::Encoder::new(file, size.width, size.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(self.rgba())?;
+        writer.finish()?;
+
+        Ok(())
+    }
+
+    /// Compares the [`Snapshot`] against an `expected` one.
+    ///
+    /// Two pixels are considered equal if none of their channels differ by
+    /// more than the given `tolerance`.
+    ///
+    /// If the snapshots differ, a new [`Snapshot`] highlighting the
+    /// mismatched pixels in red is returned.
+    pub fn diff(&self, expected: &Snapshot, tolerance: u8) -> Option<Snapshot> {
+        if self.size() != expected.size() {
+            let size = self.size();
+
+            return Some(Self::new(Screenshot::new(
+                [255, 0, 0, 255].repeat((size.width * size.height) as usize),
+                size,
+                self.screenshot.scale_factor,
+            )));
+        }
+
+        let mut has_mismatches = false;
+
+        let diff = self
+            .rgba()
+            .chunks_exact(4)
+            .zip(expected.rgba().chunks_exact(4))
+            .flat_map(|(actual, expected)| {
+                let is_match = actual
+                    .iter()
+                    .zip(expected)
+                    .all(|(a, b)| a.abs_diff(*b) <= tolerance);
+
+                if is_match {
+                    let luma = (u16::from(expected[0])
+                        + u16::from(expected[1])
+                        + u16::from(expected[2]))
+                        / 3;
+
+                    let faded = (luma / 4 + 192) as u8;
+
+                    [faded, faded, faded, 255]
+                } else {
+                    has_mismatches = true;
+
+                    [255, 0, 0, 255]
+                }
+            })
+            .collect::<Vec<_>>();
+
+        has_mismatches.then(|| {
+            Self::new(Screenshot::new(
+                diff,
+                self.size(),
+                self.screenshot.scale_factor,
+            ))
+        })
+    }
+
+    /// Returns whether the [`Snapshot`] matches the PNG image at the given
+    /// path with the given `tolerance`.
+    ///
+    /// If the image does not exist, [`Error::SnapshotNotFound`] is returned.
+    ///
+    /// If the [`RECORD_VARIABLE`] environment variable is set, the
+    /// [`Snapshot`] is saved at the given path instead and considered a
+    /// match. This can be used to create or update the reference images.
+    ///
+    /// If the [`Snapshot`] does not match, the image produced by
+    /// [`Snapshot::diff`] is saved next to the expected one with a
+    /// `.diff.png` extension.
+    pub fn matches_png(
+        &self,
+        path: impl AsRef<Path>,
+        tolerance: u8,
+    ) -> Result<bool, Error> {
+        let path = path.as_ref();
+
+        if env::var_os(RECORD_VARIABLE).is_some() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            self.save(path)?;
+
+            return Ok(true);
+        }
+
+        if !path.exists() {
+            return Err(Error::SnapshotNotFound(path.to_path_buf()));
+        }
+
+        let expected = Self::load(path)?;
+        let diff_path = diff_path(path);
+
+        match self.diff(&expected, tolerance) {
+            Some(diff) => {
+                diff.save(diff_path)?;
+
+                Ok(false)
+            }
+            None => {
+                if diff_path.exists() {
+                    fs::remove_file(diff_path)?;
+                }
+
+                Ok(true)
+            }
+        }
+    }
+}
+
+fn diff_path(path: &Path) -> PathBuf {
+    path.with_extension("diff.png")
+}
+
+impl<'a, Message, Theme> Simulator<'a, Message, Theme, Renderer> {
+    /// Renders the current user interface of the [`Simulator`] with the
+    /// given [`Theme`], text style, background color, and scale factor.
+    pub fn snapshot_with(
+        &mut self,
+        theme: &Theme,
+        style: &renderer::Style,
+        background_color: Color,
+        scale_factor: f64,
+    ) -> Snapshot {
+        let _ = self.raw.draw(&mut self.renderer, theme, style, self.cursor);
+
+        let physical_size = Size::new(
+            (self.size.width as f64 * scale_factor).round() as u32,
+            (self.size.height as f64 * scale_factor).round() as u32,
+        );
+
+        let viewport =
+            Viewport::with_physical_size(physical_size, scale_factor);
+
+        let rgba = self.renderer.screenshot(
+            &viewport,
+            background_color,
+            &[] as &[&str],
+        );
+
+        Snapshot::new(Screenshot::new(rgba, physical_size, scale_factor))
+    }
+}
+
+impl<'a, Message> Simulator<'a, Message, Theme, Renderer> {
+    /// Renders the current user interface of the [`Simulator`] with the
+    /// default style of the given built-in [`Theme`] and a scale factor of
+    /// `1.0`.
+    pub fn snapshot(&mut self, theme: &Theme) -> Snapshot {
+        let palette = theme.extended_palette();
+
+        self.snapshot_with(
+            theme,
+            &renderer::Style {
+                text_color: palette.background.base.text,
+            },
+            palette.background.base.color,
+            1.0,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(width: u32, height: u32, pixel: [u8; 4]) -> Snapshot {
+        Snapshot::new(Screenshot::new(
+            pixel.repeat((width * height) as usize),
+            Size::new(width, height),
+            1.0,
+        ))
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir()
+            .join(format!("iced_test-{}", std::process::id()))
+            .join(name)
+    }
+
+    #[test]
+    fn diff_of_equal_snapshots_is_none() {
+        let snapshot = snapshot(2, 2, [10, 20, 30, 255]);
+
+        assert!(snapshot.diff(&snapshot.clone(), 0).is_none());
+    }
+
+    #[test]
+    fn diff_respects_tolerance() {
+        let actual = snapshot(2, 2, [10, 20, 30, 255]);
+        let expected = snapshot(2, 2, [12, 20, 29, 255]);
+
+        assert!(actual.diff(&expected, 2).is_none());
+
+        let diff = actual.diff(&expected, 1).expect("snapshots should differ");
+
+        assert_eq!(diff.size(), actual.size());
+        assert!(diff
+            .rgba()
+            .chunks_exact(4)
+            .all(|pixel| pixel == [255, 0, 0, 255]));
+    }
+
+    #[test]
+    fn diff_highlights_mismatched_pixels() {
+        let expected = snapshot(2, 1, [0, 0, 0, 255]);
+
+        let mut bytes = expected.rgba().to_vec();
+        bytes[4..8].copy_from_slice(&[255, 255, 255, 255]);
+
+        let actual =
+            Snapshot::new(Screenshot::new(bytes, expected.size(), 1.0));
+
+        let diff = actual.diff(&expected, 0).expect("snapshots should differ");
+
+        assert_ne!(&diff.rgba()[0..4], [255, 0, 0, 255]);
+        assert_eq!(&diff.rgba()[4..8], [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn diff_of_different_sizes_is_all_mismatched() {
+        let actual = snapshot(2, 2, [0, 0, 0, 255]);
+        let expected = snapshot(3, 1, [0, 0, 0, 255]);
+
+        let diff = actual.diff(&expected, 255).expect("sizes should differ");
+
+        assert_eq!(diff.size(), actual.size());
+        assert!(diff
+            .rgba()
+            .chunks_exact(4)
+            .all(|pixel| pixel == [255, 0, 0, 255]));
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let path = temp_path("round_trip.png");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+        let snapshot = Snapshot::new(Screenshot::new(
+            (0..4 * 3 * 2).map(|i| (i * 10) as u8).collect::<Vec<_>>(),
+            Size::new(3, 2),
+            1.0,
+        ));
+
+        snapshot.save(&path).unwrap();
+        let loaded = Snapshot::load(&path).unwrap();
+
+        assert_eq!(loaded.size(), snapshot.size());
+        assert_eq!(loaded.rgba(), snapshot.rgba());
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn matches_png_fails_without_reference() {
+        if env::var_os(RECORD_VARIABLE).is_some() {
+            return;
+        }
+
+        let path = temp_path("missing.png");
+        let snapshot = snapshot(1, 1, [0, 0, 0, 255]);
+
+        assert!(matches!(
+            snapshot.matches_png(&path, 0),
+            Err(Error::SnapshotNotFound(_))
+        ));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn matches_png_saves_diff_on_mismatch() {
+        if env::var_os(RECORD_VARIABLE).is_some() {
+            return;
+        }
+
+        let path = temp_path("mismatch.png");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+        snapshot(1, 1, [0, 0, 0, 255]).save(&path).unwrap();
+
+        let actual = snapshot(1, 1, [255, 255, 255, 255]);
+
+        assert!(!actual.matches_png(&path, 0).unwrap());
+        assert!(diff_path(&path).exists());
+
+        assert!(snapshot(1, 1, [0, 0, 0, 255])
+            .matches_png(&path, 0)
+            .unwrap());
+        assert!(!diff_path(&path).exists());
+
+        fs::remove_file(path).unwrap();
+    }
+}
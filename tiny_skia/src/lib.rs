@@ -58,6 +58,57 @@ impl Renderer {
         }
     }
 
+    /// Draws the current contents of the [`Renderer`] into an offscreen
+    /// buffer, without the need of any window or surface.
+    ///
+    /// The resulting bytes are ordered as `RGBA` in the `sRGB` color space.
+    pub fn screenshot<T: AsRef<str>>(
+        &mut self,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8> {
+        let size = viewport.physical_size();
+
+        let mut offscreen_buffer: Vec<u32> =
+            vec![0; size.width as usize * size.height as usize];
+
+        let mut clip_mask = tiny_skia::Mask::new(size.width, size.height)
+            .expect("Create clip mask");
+
+        self.draw(
+            &mut tiny_skia::PixmapMut::from_bytes(
+                bytemuck::cast_slice_mut(&mut offscreen_buffer),
+                size.width,
+                size.height,
+            )
+            .expect("Create offscreen pixel map"),
+            &mut clip_mask,
+            viewport,
+            &[Rectangle::with_size(viewport.logical_size())],
+            background_color,
+            overlay,
+        );
+
+        offscreen_buffer.iter().fold(
+            Vec::with_capacity(offscreen_buffer.len() * 4),
+            |mut acc, pixel| {
+                const A_MASK: u32 = 0xFF_00_00_00;
+                const R_MASK: u32 = 0x00_FF_00_00;
+                const G_MASK: u32 = 0x00_00_FF_00;
+                const B_MASK: u32 = 0x00_00_00_FF;
+
+                let a = ((A_MASK & pixel) >> 24) as u8;
+                let r = ((R_MASK & pixel) >> 16) as u8;
+                let g = ((G_MASK & pixel) >> 8) as u8;
+                let b = (B_MASK & pixel) as u8;
+
+                acc.extend([r, g, b, a]);
+                acc
+            },
+        )
+    }
+
     pub fn layers(&mut self) -> &[Layer] {
         self.layers.flush();
         self.layers.as_slice()
@@ -226,6 +277,14 @@ impl Renderer {
     }
 }
 
+impl Default for Renderer {
+    fn default() -> Self {
+        let settings = Settings::default();
+
+        Self::new(settings.default_font, settings.default_text_size)
+    }
+}
+
 impl core::Renderer for Renderer {
     fn start_layer(&mut self, bounds: Rectangle) {
         self.layers.push_clip(bounds);
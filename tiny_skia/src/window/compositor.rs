@@ -1,4 +1,4 @@
-use crate::core::{Color, Rectangle, Size};
+use crate::core::{Color, Rectangle};
 use crate::graphics::compositor::{self, Information};
 use crate::graphics::damage;
 use crate::graphics::error::{self, Error};
@@ -212,48 +212,10 @@ pub fn present<T: AsRef<str>>(
 
 pub fn screenshot<T: AsRef<str>>(
     renderer: &mut Renderer,
-    surface: &mut Surface,
+    _surface: &mut Surface,
     viewport: &Viewport,
     background_color: Color,
     overlay: &[T],
 ) -> Vec<u8> {
-    let size = viewport.physical_size();
-
-    let mut offscreen_buffer: Vec<u32> =
-        vec![0; size.width as usize * size.height as usize];
-
-    renderer.draw(
-        &mut tiny_skia::PixmapMut::from_bytes(
-            bytemuck::cast_slice_mut(&mut offscreen_buffer),
-            size.width,
-            size.height,
-        )
-        .expect("Create offscreen pixel map"),
-        &mut surface.clip_mask,
-        viewport,
-        &[Rectangle::with_size(Size::new(
-            size.width as f32,
-            size.height as f32,
-        ))],
-        background_color,
-        overlay,
-    );
-
-    offscreen_buffer.iter().fold(
-        Vec::with_capacity(offscreen_buffer.len() * 4),
-        |mut acc, pixel| {
-            const A_MASK: u32 = 0xFF_00_00_00;
-            const R_MASK: u32 = 0x00_FF_00_00;
-            const G_MASK: u32 = 0x00_00_FF_00;
-            const B_MASK: u32 = 0x00_00_00_FF;
-
-            let a = ((A_MASK & pixel) >> 24) as u8;
-            let r = ((R_MASK & pixel) >> 16) as u8;
-            let g = ((G_MASK & pixel) >> 8) as u8;
-            let b = (B_MASK & pixel) as u8;
-
-            acc.extend([r, g, b, a]);
-            acc
-        },
-    )
+    renderer.screenshot(viewport, background_color, overlay)
 }
@@ -6,6 +6,7 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::theme::palette;
 use crate::core::touch;
+use crate::core::widget::operation::accessibility;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::Operation;
 use crate::core::{
@@ -144,9 +145,10 @@ where
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 struct State {
     is_pressed: bool,
+    requests: accessibility::Requests,
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -207,6 +209,17 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.accessibility(
+            None,
+            layout.bounds(),
+            &accessibility::Node::new(accessibility::Role::Button)
+                .disabled(self.on_press.is_none())
+                .action(accessibility::Action::Press),
+            Some(&mut state.requests),
+        );
+
         operation.container(None, layout.bounds(), &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
@@ -228,6 +241,16 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        for request in state.requests.take() {
+            if let (accessibility::Request::Press, Some(on_press)) =
+                (request, self.on_press.clone())
+            {
+                shell.publish(on_press);
+            }
+        }
+
         if let event::Status::Captured = self.content.as_widget_mut().on_event(
             &mut tree.children[0],
             event.clone(),
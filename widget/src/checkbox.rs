@@ -8,6 +8,7 @@ use crate::core::text;
 use crate::core::theme::palette;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::accessibility;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Pixels,
@@ -191,6 +192,12 @@ where
     }
 }
 
+#[derive(Debug, Default)]
+struct State<P: text::Paragraph> {
+    label: widget::text::State<P>,
+    requests: accessibility::Requests,
+}
+
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Checkbox<'a, Message, Theme, Renderer>
 where
@@ -198,11 +205,11 @@ where
     Theme: Catalog,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn size(&self) -> Size<Length> {
@@ -223,12 +230,11 @@ where
             self.spacing,
             |_| layout::Node::new(Size::new(self.size, self.size)),
             |limits| {
-                let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                 widget::text::layout(
-                    state,
+                    &mut state.label,
                     renderer,
                     limits,
                     self.width,
@@ -245,9 +251,30 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.accessibility(
+            None,
+            layout.bounds(),
+            &accessibility::Node::new(accessibility::Role::CheckBox)
+                .label(self.label.clone())
+                .toggled(self.is_checked)
+                .disabled(self.on_toggle.is_none())
+                .action(accessibility::Action::Press),
+            Some(&mut state.requests),
+        );
+    }
+
     fn on_event(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -256,6 +283,16 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        for request in state.requests.take() {
+            if let (accessibility::Request::Press, Some(on_toggle)) =
+                (request, &self.on_toggle)
+            {
+                shell.publish((on_toggle)(!self.is_checked));
+            }
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -363,7 +400,10 @@ where
                 renderer,
                 defaults,
                 label_layout,
-                tree.state.downcast_ref(),
+                &tree
+                    .state
+                    .downcast_ref::<State<Renderer::Paragraph>>()
+                    .label,
                 crate::text::Style {
                     color: style.text_color,
                 },
@@ -8,6 +8,7 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text;
 use crate::core::time::Instant;
+use crate::core::widget::operation::accessibility;
 use crate::core::widget::{self, Widget};
 use crate::core::{
     Clipboard, Element, Length, Padding, Rectangle, Shell, Size, Theme, Vector,
@@ -373,6 +374,29 @@ where
         vec![widget::Tree::new(&self.text_input as &dyn Widget<_, _, _>)]
     }
 
+    fn operate(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        let text_input_state = tree.children[0]
+            .state
+            .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+        operation.accessibility(
+            None,
+            layout.bounds(),
+            &accessibility::Node::new(accessibility::Role::ComboBox)
+                .value(self.state.value())
+                .focused(text_input_state.is_focused())
+                .action(accessibility::Action::Focus)
+                .action(accessibility::Action::SetValue),
+            Some(text_input_state.requests()),
+        );
+    }
+
     fn on_event(
         &mut self,
         tree: &mut widget::Tree,
@@ -413,6 +437,8 @@ where
             viewport,
         );
 
+        shell.request_input_method(local_shell.input_method());
+
         // Then finally react to them here
         for message in local_messages {
             let TextInputEvent::TextChanged(new_value) = message;
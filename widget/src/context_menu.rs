@@ -0,0 +1,322 @@
+//! Open a menu of actions at the mouse cursor when right-clicking a widget.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::text;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Padding, Pixels, Rectangle, Shell,
+    Size, Vector, Widget,
+};
+use crate::overlay::menu::cascade::{self, Cascade};
+use crate::overlay::menu::{self, Catalog};
+
+pub use crate::overlay::menu::Item;
+
+/// A widget that opens a menu of [`Item`]s at the mouse cursor when its
+/// content is right-clicked.
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced_widget::context_menu::Item;
+/// use iced_widget::{context_menu, text};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Copy,
+///     Paste,
+///     ToggleWrap,
+/// }
+///
+/// fn view<'a>(is_wrapped: bool) -> Element<'a, Message> {
+///     context_menu(
+///         text("Right-click me!"),
+///         [
+///             Item::new("Copy").shortcut("Ctrl+C").on_select(Message::Copy),
+///             Item::new("Paste").shortcut("Ctrl+V").on_select(Message::Paste),
+///             Item::separator(),
+///             Item::new("Wrap lines")
+///                 .checked(is_wrapped)
+///                 .on_select(Message::ToggleWrap),
+///         ],
+///     )
+///     .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ContextMenu<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    items: Vec<Item<Message>>,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`ContextMenu`] with the given content and the items
+    /// of its menu.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            items: items.into_iter().collect(),
+            padding: Padding::from([5, 10]),
+            text_size: None,
+            font: None,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+
+    /// Sets the [`Padding`] of the items of the [`ContextMenu`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the items of the [`ContextMenu`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the items of the [`ContextMenu`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the menu of the [`ContextMenu`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> menu::Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the menu of the [`ContextMenu`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    cascade: cascade::State,
+    offset: Vector,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ContextMenu<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.cascade.is_open() {
+            if let Event::Mouse(mouse::Event::ButtonPressed(button)) = event {
+                // Event wasn't processed by the overlay, so the cursor was
+                // clicked outside of it
+                state.cascade.close();
+
+                if button != mouse::Button::Right
+                    || !cursor.is_over(layout.bounds())
+                {
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) =
+            event
+        {
+            if let Some(position) = cursor.position_over(layout.bounds()) {
+                let state = tree.state.downcast_mut::<State>();
+
+                state.cascade.open(None);
+                state.offset = position - layout.position();
+
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &crate::core::renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.cascade.is_open() {
+            return self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                translation,
+            );
+        }
+
+        Some(
+            Cascade::new(&mut state.cascade, &self.items, &self.class)
+                .padding(self.padding)
+                .text_size(self.text_size)
+                .font(self.font)
+                .overlay(layout.position() + state.offset + translation, 0.0),
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer>
+    From<ContextMenu<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(context_menu: ContextMenu<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(context_menu)
+    }
+}
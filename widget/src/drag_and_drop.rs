@@ -0,0 +1,1223 @@
+//! Drag widgets around and drop them into other widgets.
+//!
+//! A [`Draggable`] carries a payload that can be dropped into any
+//! [`DropZone`] accepting payloads of the same type, as long as both are
+//! placed inside the same [`DragArea`].
+//!
+//! ```no_run
+//! # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+//! use iced_widget::{column, drag_area, draggable, drop_zone, row, text};
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum Lane {
+//!     Todo,
+//!     Done,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     CardMoved { card: usize, lane: Lane },
+//! }
+//!
+//! fn view<'a>(todo: &'a [String], done: &'a [String]) -> Element<'a, Message> {
+//!     let lane = |cards: &'a [String], lane: Lane| {
+//!         drop_zone(column(cards.iter().enumerate().map(|(card, title)| {
+//!             draggable(card, text(title)).into()
+//!         })))
+//!         .on_drop(move |card| Message::CardMoved { card, lane })
+//!     };
+//!
+//!     drag_area(row![lane(todo, Lane::Todo), lane(done, Lane::Done)]).into()
+//! }
+//! ```
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{self, Operation};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Layout, Length, Point,
+    Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// The distance the cursor needs to travel after pressing a [`Draggable`]
+/// for a drag to start.
+const DRAG_THRESHOLD: f32 = 5.0;
+
+/// The drag in progress of a [`DragArea`], shared with every [`Draggable`]
+/// and [`DropZone`] inside of it.
+#[derive(Clone, Default)]
+struct Context(Rc<RefCell<Option<Drag>>>);
+
+struct Drag {
+    payload: Box<dyn Any>,
+    is_released: bool,
+    is_dropped: bool,
+}
+
+impl Context {
+    /// Updates the drag in progress, if any, before any widget processes
+    /// the given event.
+    ///
+    /// The first release finishes the drag, even if the [`Draggable`] that
+    /// started it is no longer part of the user interface. The drag is still
+    /// kept until the next event, so every [`DropZone`] gets a chance to
+    /// receive it no matter the order in which widgets process events.
+    ///
+    /// A press always discards any drag left behind, since its release may
+    /// have happened outside of the [`DragArea`].
+    fn clean_up(&self, event: &Event) {
+        let mut drag = self.0.borrow_mut();
+
+        if is_press(event) {
+            *drag = None;
+        } else if is_release(event) {
+            if let Some(drag) = drag.as_mut() {
+                drag.is_released = true;
+            }
+        } else if drag.as_ref().is_some_and(|drag| drag.is_released) {
+            *drag = None;
+        }
+    }
+
+    fn start<Payload: 'static>(&self, payload: Payload) {
+        *self.0.borrow_mut() = Some(Drag {
+            payload: Box::new(payload),
+            is_released: false,
+            is_dropped: false,
+        });
+    }
+
+    /// Returns true if a payload that the given closure accepts is being
+    /// dragged.
+    fn is_dragging<Payload: 'static>(
+        &self,
+        accepts: impl Fn(&Payload) -> bool,
+    ) -> bool {
+        self.0.borrow().as_ref().is_some_and(|drag| {
+            !drag.is_released
+                && !drag.is_dropped
+                && drag.payload.downcast_ref::<Payload>().is_some_and(&accepts)
+        })
+    }
+
+    /// Takes the released payload, if it has not been dropped yet and the
+    /// given closure accepts it.
+    fn drop<Payload: Clone + 'static>(
+        &self,
+        accepts: impl Fn(&Payload) -> bool,
+    ) -> Option<Payload> {
+        let mut drag = self.0.borrow_mut();
+        let drag = drag.as_mut().filter(|drag| !drag.is_dropped)?;
+
+        let payload = drag
+            .payload
+            .downcast_ref::<Payload>()
+            .filter(|payload| accepts(payload))?
+            .clone();
+
+        drag.is_dropped = true;
+
+        Some(payload)
+    }
+}
+
+/// An [`Operation`] that shares the [`Context`] of a [`DragArea`] with
+/// the widgets inside of it.
+struct Share(Context);
+
+impl<T> Operation<T> for Share {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, _id: Option<&widget::Id>) {
+        if let Some(context) = state.downcast_mut::<Option<Context>>() {
+            *context = Some(self.0.clone());
+        }
+    }
+}
+
+fn is_press(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+    )
+}
+
+fn is_release(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+    )
+}
+
+/// An area where the payload of a [`Draggable`] can be dropped into a
+/// [`DropZone`].
+///
+/// It keeps track of the drag in progress, which is only visible to the
+/// widgets inside of it. Normally, it wraps the whole contents of a window.
+#[allow(missing_debug_implementations)]
+pub struct DragArea<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> DragArea<'a, Message, Theme, Renderer> {
+    /// Creates a new [`DragArea`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DragArea<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Context>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Context::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let node = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            limits,
+        );
+
+        // Any new widget is part of a layout, so this is the right time to
+        // share the drag with them
+        let context = tree.state.downcast_ref::<Context>().clone();
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            Layout::new(&node),
+            renderer,
+            &mut Share(context),
+        );
+
+        node
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        tree.state.downcast_ref::<Context>().clean_up(&event);
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DragArea<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(area: DragArea<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(area)
+    }
+}
+
+/// A widget that can be dragged around, carrying a payload.
+///
+/// While dragged, a preview of its content follows the mouse cursor. Its
+/// payload can only be dropped into a [`DropZone`] inside the same
+/// [`DragArea`].
+#[allow(missing_debug_implementations)]
+pub struct Draggable<
+    'a,
+    Payload,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    payload: Payload,
+    content: Element<'a, Message, Theme, Renderer>,
+    on_drag: Option<Message>,
+}
+
+impl<'a, Payload, Message, Theme, Renderer>
+    Draggable<'a, Payload, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+{
+    /// Creates a new [`Draggable`] carrying the given payload and displaying
+    /// the given content.
+    pub fn new(
+        payload: Payload,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            payload,
+            content: content.into(),
+            on_drag: None,
+        }
+    }
+
+    /// Sets the message that will be produced when a drag of the
+    /// [`Draggable`] starts.
+    pub fn on_drag(mut self, message: Message) -> Self {
+        self.on_drag = Some(message);
+        self
+    }
+}
+
+#[derive(Default)]
+struct State {
+    action: Action,
+    context: Option<Context>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+enum Action {
+    #[default]
+    Idle,
+    Pressed {
+        origin: Point,
+    },
+    Dragging {
+        grab: Vector,
+    },
+}
+
+impl<'a, Payload, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Draggable<'a, Payload, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Message: Clone,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.custom(&mut state.context, None);
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !matches!(state.action, Action::Dragging { .. }) {
+            let status = self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event.clone(),
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(origin) = cursor.position_over(bounds) {
+                    state.action = Action::Pressed { origin };
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Action::Pressed { origin } = state.action {
+                    if origin.distance(position) > DRAG_THRESHOLD {
+                        state.action = Action::Dragging {
+                            grab: origin - bounds.position(),
+                        };
+
+                        if let Some(context) = &state.context {
+                            context.start(self.payload.clone());
+                        }
+
+                        if let Some(on_drag) = &self.on_drag {
+                            shell.publish(on_drag.clone());
+                        }
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                match std::mem::take(&mut state.action) {
+                    Action::Dragging { .. } | Action::Pressed { .. } => {
+                        return event::Status::Captured;
+                    }
+                    Action::Idle => {}
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if let Action::Dragging { .. } =
+            tree.state.downcast_ref::<State>().action
+        {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::default()
+            && cursor.is_over(layout.bounds())
+        {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Action::Dragging { grab } =
+            tree.state.downcast_ref::<State>().action
+        else {
+            return self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                translation,
+            );
+        };
+
+        Some(overlay::Element::new(Box::new(Preview {
+            content: &self.content,
+            tree: &mut tree.children[0],
+            size: layout.bounds().size(),
+            grab,
+        })))
+    }
+}
+
+impl<'a, Payload, Message, Theme, Renderer>
+    From<Draggable<'a, Payload, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(
+        draggable: Draggable<'a, Payload, Message, Theme, Renderer>,
+    ) -> Self {
+        Element::new(draggable)
+    }
+}
+
+/// The content of a [`Draggable`] following the mouse cursor.
+struct Preview<'a, 'b, Message, Theme, Renderer> {
+    content: &'b Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    size: Size,
+    grab: Vector,
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Preview<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, _bounds: Size) -> layout::Node {
+        let content = self.content.as_widget().layout(
+            self.tree,
+            renderer,
+            &layout::Limits::new(Size::ZERO, self.size),
+        );
+
+        layout::Node::with_children(self.size, vec![content])
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let Some(position) = cursor.position() else {
+            return;
+        };
+
+        let Some(content) = layout.children().next() else {
+            return;
+        };
+
+        // The preview is positioned when drawn, so it never lags behind
+        let translation = position - self.grab - layout.position();
+
+        renderer.with_translation(translation, |renderer| {
+            self.content.as_widget().draw(
+                self.tree,
+                renderer,
+                theme,
+                style,
+                content,
+                mouse::Cursor::Unavailable,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+        });
+    }
+
+    fn is_over(
+        &self,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        _cursor_position: Point,
+    ) -> bool {
+        // The widgets below must keep receiving the cursor, since they may
+        // be drop zones
+        false
+    }
+}
+
+/// A widget that accepts the payloads of a [`Draggable`] dropped into it.
+///
+/// While a drag that can be dropped into it is in progress, a [`DropZone`]
+/// is highlighted. Only the payloads of a [`Draggable`] inside the same
+/// [`DragArea`] can be dropped into it.
+#[allow(missing_debug_implementations)]
+pub struct DropZone<
+    'a,
+    Payload,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_drop: Option<Box<dyn Fn(Payload) -> Message + 'a>>,
+    accepts: Option<Box<dyn Fn(&Payload) -> bool + 'a>>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Payload, Message, Theme, Renderer>
+    DropZone<'a, Payload, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Theme: Catalog,
+{
+    /// Creates a new [`DropZone`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_drop: None,
+            accepts: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when a payload is dropped
+    /// into the [`DropZone`].
+    ///
+    /// Unless `on_drop` is called, the [`DropZone`] will accept nothing.
+    pub fn on_drop(
+        mut self,
+        on_drop: impl Fn(Payload) -> Message + 'a,
+    ) -> Self {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Sets the closure that decides whether a payload can be dropped into
+    /// the [`DropZone`].
+    ///
+    /// By default, any payload of the right type is accepted.
+    pub fn accepts(mut self, accepts: impl Fn(&Payload) -> bool + 'a) -> Self {
+        self.accepts = Some(Box::new(accepts));
+        self
+    }
+
+    /// Sets the style of the [`DropZone`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`DropZone`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn can_drop(&self, payload: &Payload) -> bool {
+        self.on_drop.is_some()
+            && self.accepts.as_ref().is_none_or(|accepts| accepts(payload))
+    }
+
+    fn accepts_drag(&self, tree: &Tree) -> bool {
+        tree.state
+            .downcast_ref::<Option<Context>>()
+            .as_ref()
+            .is_some_and(|context| {
+                context.is_dragging(|payload: &Payload| self.can_drop(payload))
+            })
+    }
+}
+
+impl<'a, Payload, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DropZone<'a, Payload, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Theme: Catalog,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Option<Context>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(None::<Context>)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.custom(tree.state.downcast_mut::<Option<Context>>(), None);
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let is_release = is_release(&event);
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if !is_release || !cursor.is_over(layout.bounds()) {
+            return status;
+        }
+
+        let Some(on_drop) = &self.on_drop else {
+            return status;
+        };
+
+        // The drag has been released already, but it is kept until the
+        // next event for every drop zone to see it
+        let payload = tree
+            .state
+            .downcast_ref::<Option<Context>>()
+            .as_ref()
+            .and_then(|context| {
+                context.drop(|payload: &Payload| self.can_drop(payload))
+            });
+
+        if let Some(payload) = payload {
+            shell.publish(on_drop(payload));
+
+            return event::Status::Captured;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let status = if !self.accepts_drag(tree) {
+            Status::Idle
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = theme.style(&self.class, status);
+
+        if style.background.is_some() || style.border.width > 0.0 {
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.border,
+                        ..renderer::Quad::default()
+                    },
+                    style
+                        .background
+                        .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                );
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Payload, Message, Theme, Renderer>
+    From<DropZone<'a, Payload, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(
+        drop_zone: DropZone<'a, Payload, Message, Theme, Renderer>,
+    ) -> Self {
+        Element::new(drop_zone)
+    }
+}
+
+/// The possible status of a [`DropZone`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Nothing that can be dropped into the [`DropZone`] is being dragged.
+    Idle,
+    /// A payload that can be dropped into the [`DropZone`] is being dragged.
+    Active,
+    /// A payload that can be dropped into the [`DropZone`] is being dragged
+    /// over it.
+    Hovered,
+}
+
+/// The appearance of a [`DropZone`].
+///
+/// It is drawn on top of the content of the [`DropZone`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Style {
+    /// The [`Background`] of the [`DropZone`].
+    pub background: Option<Background>,
+    /// The [`Border`] of the [`DropZone`].
+    pub border: Border,
+}
+
+/// The theme catalog of a [`DropZone`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`DropZone`].
+///
+/// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`DropZone`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    match status {
+        Status::Idle => Style::default(),
+        Status::Active => Style {
+            background: None,
+            border: Border {
+                color: palette.primary.weak.color,
+                width: 1.0,
+                radius: 2.0.into(),
+            },
+        },
+        Status::Hovered => Style {
+            background: Some(
+                palette.primary.weak.color.scale_alpha(0.2).into(),
+            ),
+            border: Border {
+                color: palette.primary.strong.color,
+                width: 2.0,
+                radius: 2.0.into(),
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{row, Space};
+
+    use iced_test::Simulator;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Dragged,
+        Dropped(usize),
+    }
+
+    type Ui = Simulator<'static, Message, Theme, ()>;
+
+    const CARD: Point = Point::new(50.0, 25.0);
+    const LANE: Point = Point::new(150.0, 25.0);
+    const OUTSIDE: Point = Point::new(500.0, 300.0);
+
+    fn lanes(has_card: bool) -> Element<'static, Message, Theme, ()> {
+        let todo: Element<'static, Message, Theme, ()> = if has_card {
+            Draggable::new(1_usize, Space::new(100, 50))
+                .on_drag(Message::Dragged)
+                .into()
+        } else {
+            Space::new(100, 50).into()
+        };
+
+        row![
+            DropZone::new(todo).on_drop(Message::Dropped),
+            DropZone::new(Space::new(100, 50)).on_drop(Message::Dropped),
+        ]
+        .into()
+    }
+
+    fn area(has_card: bool) -> Element<'static, Message, Theme, ()> {
+        DragArea::new(lanes(has_card)).into()
+    }
+
+    fn press(ui: &mut Ui, position: Point) {
+        ui.point_at(position);
+        let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        ))]);
+    }
+
+    fn release(ui: &mut Ui, position: Point) {
+        ui.point_at(position);
+        let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        ))]);
+    }
+
+    /// Starts dragging the card and rebuilds the lanes, like an application
+    /// reacting to the drag would.
+    fn start_drag(has_card: bool) -> Ui {
+        let mut ui = Simulator::new(area(true));
+
+        press(&mut ui, CARD);
+        ui.point_at(LANE);
+
+        assert_eq!(ui.messages(), [Message::Dragged]);
+
+        Simulator::with_cache(
+            iced_test::DEFAULT_SIZE,
+            ui.into_cache(),
+            area(has_card),
+        )
+    }
+
+    #[test]
+    fn dropping_into_a_zone() {
+        let mut ui = start_drag(true);
+
+        release(&mut ui, LANE);
+        assert_eq!(ui.messages(), [Message::Dropped(1)]);
+
+        press(&mut ui, LANE);
+        release(&mut ui, LANE);
+        assert_eq!(ui.messages(), [Message::Dropped(1)]);
+    }
+
+    #[test]
+    fn dropping_after_the_draggable_is_gone() {
+        let mut ui = start_drag(false);
+
+        release(&mut ui, LANE);
+        assert_eq!(ui.messages(), [Message::Dropped(1)]);
+
+        press(&mut ui, LANE);
+        release(&mut ui, LANE);
+        assert_eq!(ui.messages(), [Message::Dropped(1)]);
+    }
+
+    #[test]
+    fn releasing_outside_of_any_zone() {
+        let mut ui = start_drag(false);
+
+        release(&mut ui, OUTSIDE);
+        assert_eq!(ui.messages(), []);
+
+        press(&mut ui, LANE);
+        release(&mut ui, LANE);
+        assert_eq!(ui.messages(), []);
+    }
+
+    #[test]
+    fn discarding_a_drag_whose_release_was_missed() {
+        let mut ui = start_drag(true);
+
+        // The release happened outside of the area
+        press(&mut ui, LANE);
+        release(&mut ui, LANE);
+
+        assert_eq!(ui.messages(), []);
+    }
+
+    #[test]
+    fn drags_are_not_shared_between_areas() {
+        let _dragging = start_drag(true);
+
+        let mut other: Ui = Simulator::new(area(false));
+        release(&mut other, LANE);
+
+        assert_eq!(other.messages(), []);
+    }
+
+    #[test]
+    fn dropping_needs_a_drag_area() {
+        let mut ui: Ui = Simulator::new(lanes(true));
+
+        press(&mut ui, CARD);
+        ui.point_at(LANE);
+        release(&mut ui, LANE);
+
+        assert_eq!(ui.messages(), [Message::Dragged]);
+    }
+}
@@ -3,10 +3,15 @@ use crate::button::{self, Button};
 use crate::checkbox::{self, Checkbox};
 use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
+use crate::context_menu::ContextMenu;
 use crate::core;
 use crate::core::widget::operation;
 use crate::core::{Element, Length, Pixels, Widget};
+use crate::drag_and_drop::{self, DragArea, Draggable, DropZone};
 use crate::keyed;
+use crate::list::List;
+use crate::menu_bar::{self, MenuBar};
+use crate::number_input::{self, NumberInput};
 use crate::overlay;
 use crate::pick_list::{self, PickList};
 use crate::progress_bar::{self, ProgressBar};
@@ -15,6 +20,8 @@ use crate::rule::{self, Rule};
 use crate::runtime::Command;
 use crate::scrollable::{self, Scrollable};
 use crate::slider::{self, Slider};
+use crate::table::{self, Table};
+use crate::tabs::{self, TabBar, Tabs};
 use crate::text::{self, Text};
 use crate::text_editor::{self, TextEditor};
 use crate::text_input::{self, TextInput};
@@ -111,6 +118,19 @@ macro_rules! text {
     };
 }
 
+/// Creates some [`Rich`] text with the given spans.
+///
+/// [`Rich`]: text::Rich
+#[macro_export]
+macro_rules! rich_text {
+    () => (
+        $crate::text::Rich::new()
+    );
+    ($($x:expr),+ $(,)?) => (
+        $crate::text::Rich::with_spans([$($crate::text::Span::from($x)),+])
+    );
+}
+
 /// Creates a new [`Container`] with the provided content.
 ///
 /// [`Container`]: crate::Container
@@ -657,6 +677,30 @@ where
     Text::new(text)
 }
 
+/// Creates some [`Rich`] text with the given [`Span`]s.
+///
+/// [`Rich`]: text::Rich
+/// [`Span`]: text::Span
+pub fn rich_text<'a, Link, Theme, Renderer>(
+    spans: impl IntoIterator<Item = text::Span<'a, Link, Renderer::Font>>,
+) -> text::Rich<'a, Link, Theme, Renderer>
+where
+    Link: Clone + 'static,
+    Theme: text::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    text::Rich::with_spans(spans)
+}
+
+/// Creates a new [`Span`] of text with the provided content.
+///
+/// [`Span`]: text::Span
+pub fn span<'a, Link, Font>(
+    text: impl text::IntoFragment<'a>,
+) -> text::Span<'a, Link, Font> {
+    text::Span::new(text.into_fragment())
+}
+
 /// Creates a new [`Text`] widget that displays the provided value.
 ///
 /// [`Text`]: core::widget::Text
@@ -732,6 +776,20 @@ where
     TextInput::new(placeholder, value)
 }
 
+/// Creates a new [`NumberInput`].
+///
+/// [`NumberInput`]: crate::NumberInput
+pub fn number_input<'a, T, Message, Theme, Renderer>(
+    value: T,
+) -> NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: number_input::Number,
+    Theme: number_input::Catalog + 'a,
+    Renderer: core::text::Renderer,
+{
+    NumberInput::new(value)
+}
+
 /// Creates a new [`TextEditor`].
 ///
 /// [`TextEditor`]: crate::TextEditor
@@ -797,6 +855,93 @@ where
     PickList::new(options, selected, on_selected)
 }
 
+/// Creates a new [`TabBar`] with the message to produce when a tab is
+/// selected.
+///
+/// [`TabBar`]: crate::TabBar
+pub fn tab_bar<'a, TabId, Message, Theme, Renderer>(
+    on_select: impl Fn(TabId) -> Message + 'a,
+) -> TabBar<'a, TabId, Message, Theme, Renderer>
+where
+    TabId: Clone + PartialEq,
+    Theme: tabs::Catalog,
+    Renderer: core::text::Renderer,
+{
+    TabBar::new(on_select)
+}
+
+/// Creates a new [`Tabs`] with the message to produce when a tab is
+/// selected.
+///
+/// [`Tabs`]: crate::Tabs
+pub fn tabs<'a, TabId, Message, Theme, Renderer>(
+    on_select: impl Fn(TabId) -> Message + 'a,
+) -> Tabs<'a, TabId, Message, Theme, Renderer>
+where
+    TabId: Clone + PartialEq,
+    Theme: tabs::Catalog,
+    Renderer: core::text::Renderer,
+{
+    Tabs::new(on_select)
+}
+
+/// Creates a new [`List`] with the given amount of items and a closure that
+/// builds the item at a given index.
+///
+/// [`List`]: crate::List
+pub fn list<'a, Message, Theme, Renderer>(
+    count: usize,
+    view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> List<'a, usize, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    List::new(count, view)
+}
+
+/// Creates a new [`Table`] with the given columns and rows.
+///
+/// [`Table`]: crate::Table
+pub fn table<'a, T, Message, Theme, Renderer>(
+    columns: impl IntoIterator<
+        Item = table::Column<'a, T, Message, Theme, Renderer>,
+    >,
+    rows: &'a [T],
+) -> Table<'a, T, Message, Theme, Renderer>
+where
+    Theme: table::Catalog,
+    Renderer: core::text::Renderer,
+{
+    Table::new(columns, rows)
+}
+
+/// Creates a new [`ContextMenu`] with the given content and the items of
+/// its menu.
+///
+/// [`ContextMenu`]: crate::ContextMenu
+pub fn context_menu<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    items: impl IntoIterator<Item = overlay::menu::Item<Message>>,
+) -> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Theme: overlay::menu::Catalog,
+    Renderer: core::text::Renderer,
+{
+    ContextMenu::new(content, items)
+}
+
+/// Creates a new, empty [`MenuBar`].
+///
+/// [`MenuBar`]: crate::MenuBar
+pub fn menu_bar<'a, Message, Theme, Renderer>(
+) -> MenuBar<'a, Message, Theme, Renderer>
+where
+    Theme: menu_bar::Catalog,
+    Renderer: core::text::Renderer,
+{
+    MenuBar::new()
+}
+
 /// Creates a new [`ComboBox`].
 ///
 /// [`ComboBox`]: crate::ComboBox
@@ -954,6 +1099,47 @@ where
     MouseArea::new(widget)
 }
 
+/// Creates a new [`DragArea`] with the given content.
+///
+/// [`DragArea`]: crate::DragArea
+pub fn drag_area<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DragArea<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    DragArea::new(content)
+}
+
+/// Creates a new [`Draggable`] carrying the given payload and displaying
+/// the given content.
+///
+/// [`Draggable`]: crate::Draggable
+pub fn draggable<'a, Payload, Message, Theme, Renderer>(
+    payload: Payload,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Draggable<'a, Payload, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Renderer: core::Renderer,
+{
+    Draggable::new(payload, content)
+}
+
+/// Creates a new [`DropZone`] with the given content.
+///
+/// [`DropZone`]: crate::DropZone
+pub fn drop_zone<'a, Payload, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DropZone<'a, Payload, Message, Theme, Renderer>
+where
+    Payload: Clone + 'static,
+    Theme: drag_and_drop::Catalog,
+    Renderer: core::Renderer,
+{
+    DropZone::new(content)
+}
+
 /// A widget that applies any `Theme` to its contents.
 pub fn themer<'a, Message, OldTheme, NewTheme, Renderer>(
     new_theme: NewTheme,
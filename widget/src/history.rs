@@ -0,0 +1,161 @@
+//! Keep track of edits to undo and redo them.
+
+/// A stack of undoable steps.
+///
+/// Consecutive steps of the same [`Group`] are merged together, so they are
+/// undone at once.
+#[derive(Debug, Clone)]
+pub(crate) struct History<T> {
+    undo: Vec<(T, Group)>,
+    redo: Vec<(T, Group)>,
+}
+
+/// The kind of edit that produced a step of a [`History`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Group {
+    /// Characters were typed.
+    Typing { is_whitespace: bool },
+    /// Characters were deleted one at a time.
+    Deleting,
+    /// Any other edit; like pasting or cutting.
+    Standalone,
+}
+
+impl Group {
+    fn continues(self, previous: Self) -> bool {
+        match (previous, self) {
+            (
+                Self::Typing { is_whitespace },
+                Self::Typing {
+                    is_whitespace: next_is_whitespace,
+                },
+            ) => !is_whitespace || next_is_whitespace,
+            (Self::Deleting, Self::Deleting) => true,
+            _ => false,
+        }
+    }
+}
+
+/// A step of a [`History`] that may absorb the step following it.
+pub(crate) trait Step: Sized {
+    /// Merges the next step into this one.
+    ///
+    /// Returns the next step back if both cannot be merged.
+    fn merge(&mut self, next: Self) -> Result<(), Self>;
+}
+
+impl<T: Step> History<T> {
+    /// Creates an empty [`History`].
+    pub fn new() -> Self {
+        Self {
+            undo: Vec::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    /// Records a new step in the [`History`], discarding any undone steps.
+    pub fn record(&mut self, step: T, group: Group) {
+        self.redo.clear();
+
+        let step = match self.undo.last_mut() {
+            Some((last, last_group)) if group.continues(*last_group) => {
+                match last.merge(step) {
+                    Ok(()) => {
+                        *last_group = group;
+                        return;
+                    }
+                    Err(step) => step,
+                }
+            }
+            _ => step,
+        };
+
+        self.undo.push((step, group));
+    }
+
+    /// Moves the last recorded step to the redo stack and returns it.
+    pub fn undo(&mut self) -> Option<&T> {
+        let step = self.undo.pop()?;
+        self.redo.push(step);
+
+        self.redo.last().map(|(step, _)| step)
+    }
+
+    /// Moves the last undone step back to the undo stack and returns it.
+    pub fn redo(&mut self) -> Option<&T> {
+        let (step, _) = self.redo.pop()?;
+
+        // Redone steps are never merged with new ones
+        self.undo.push((step, Group::Standalone));
+
+        self.undo.last().map(|(step, _)| step)
+    }
+
+    /// Returns whether there is any step to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns whether there is any step to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Discards all the steps of the [`History`].
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+impl<T: Step> Default for History<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Text(String);
+
+    impl Step for Text {
+        fn merge(&mut self, next: Self) -> Result<(), Self> {
+            self.0.push_str(&next.0);
+            Ok(())
+        }
+    }
+
+    fn typing(c: char) -> (Text, Group) {
+        (
+            Text(c.to_string()),
+            Group::Typing {
+                is_whitespace: c.is_whitespace(),
+            },
+        )
+    }
+
+    #[test]
+    fn typing_is_grouped_by_words() {
+        let mut history = History::new();
+
+        for c in "hello world".chars() {
+            let (step, group) = typing(c);
+            history.record(step, group);
+        }
+
+        assert_eq!(history.undo(), Some(&Text("world".to_owned())));
+        assert_eq!(history.undo(), Some(&Text("hello ".to_owned())));
+        assert_eq!(history.undo(), None);
+
+        assert_eq!(history.redo(), Some(&Text("hello ".to_owned())));
+        assert!(history.can_redo());
+
+        history.record(Text("!".to_owned()), Group::Standalone);
+
+        assert!(!history.can_redo());
+        assert_eq!(history.undo(), Some(&Text("!".to_owned())));
+    }
+}
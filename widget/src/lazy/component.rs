@@ -325,6 +325,8 @@ where
             shell.request_redraw(redraw_request);
         }
 
+        shell.request_input_method(local_shell.input_method());
+
         if !local_messages.is_empty() {
             let mut heads = self.state.take().unwrap().into_heads();
 
@@ -406,6 +408,27 @@ where
                 self.operation.scrollable(state, id, bounds, translation);
             }
 
+            fn text(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                text: &str,
+            ) {
+                self.operation.text(id, bounds, text);
+            }
+
+            fn accessibility(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                node: &widget::operation::accessibility::Node,
+                requests: Option<
+                    &mut widget::operation::accessibility::Requests,
+                >,
+            ) {
+                self.operation.accessibility(id, bounds, node, requests);
+            }
+
             fn custom(
                 &mut self,
                 state: &mut dyn std::any::Any,
@@ -652,6 +675,8 @@ where
             shell.request_redraw(redraw_request);
         }
 
+        shell.request_input_method(local_shell.input_method());
+
         if !local_messages.is_empty() {
             let mut inner =
                 self.overlay.take().unwrap().0.take().unwrap().into_heads();
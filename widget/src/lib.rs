@@ -9,7 +9,9 @@ pub use iced_runtime as runtime;
 pub use iced_runtime::core;
 
 mod column;
+mod history;
 mod mouse_area;
+mod preedit;
 mod row;
 mod space;
 mod stack;
@@ -19,7 +21,12 @@ pub mod button;
 pub mod checkbox;
 pub mod combo_box;
 pub mod container;
+pub mod context_menu;
+pub mod drag_and_drop;
 pub mod keyed;
+pub mod list;
+pub mod menu_bar;
+pub mod number_input;
 pub mod overlay;
 pub mod pane_grid;
 pub mod pick_list;
@@ -28,6 +35,8 @@ pub mod radio;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
+pub mod table;
+pub mod tabs;
 pub mod text;
 pub mod text_editor;
 pub mod text_input;
@@ -59,8 +68,22 @@ pub use combo_box::ComboBox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use drag_and_drop::DragArea;
+#[doc(no_inline)]
+pub use drag_and_drop::Draggable;
+#[doc(no_inline)]
+pub use drag_and_drop::DropZone;
+#[doc(no_inline)]
+pub use list::List;
+#[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -81,6 +104,12 @@ pub use space::Space;
 #[doc(no_inline)]
 pub use stack::Stack;
 #[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
+pub use tabs::TabBar;
+#[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_editor::TextEditor;
@@ -126,6 +155,9 @@ pub use canvas::Canvas;
 #[cfg(feature = "qr_code")]
 pub mod qr_code;
 
+#[cfg(feature = "markdown")]
+pub mod markdown;
+
 #[cfg(feature = "qr_code")]
 #[doc(no_inline)]
 pub use qr_code::QRCode;
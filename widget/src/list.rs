@@ -0,0 +1,587 @@
+//! Display huge amounts of items, building only the visible ones.
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+use std::cell::RefCell;
+use std::ops::Range;
+
+/// A vertical list that only builds, lays out, and draws the items visible
+/// in its viewport.
+///
+/// A [`List`] is meant to be placed inside a [`Scrollable`], which can be
+/// controlled as usual with [`scrollable::scroll_to`] and
+/// [`scrollable::snap_to`].
+///
+/// Items can have different heights. The height of an item is measured and
+/// cached by its key the first time it becomes visible; until then, it is
+/// estimated from the items measured so far.
+///
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced_widget::{list, scrollable, text};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {}
+///
+/// fn view(lines: &[String]) -> Element<'_, Message> {
+///     scrollable(list(lines.len(), |index| text(&lines[index]).into())).into()
+/// }
+/// ```
+///
+/// [`Scrollable`]: crate::Scrollable
+/// [`scrollable::scroll_to`]: crate::scrollable::scroll_to
+/// [`scrollable::snap_to`]: crate::scrollable::snap_to
+#[allow(missing_debug_implementations)]
+pub struct List<
+    'a,
+    Key,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Key: Copy + PartialEq,
+{
+    count: usize,
+    key: Box<dyn Fn(usize) -> Key + 'a>,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    width: Length,
+    spacing: f32,
+    estimated_height: f32,
+    items: RefCell<Vec<Element<'a, Message, Theme, Renderer>>>,
+}
+
+impl<'a, Message, Theme, Renderer> List<'a, usize, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates a new [`List`] with the given amount of items and a closure
+    /// that builds the item at a given index.
+    ///
+    /// The state of each item is kept by its index. Use [`List::with_keys`]
+    /// if items may be inserted or removed.
+    pub fn new(
+        count: usize,
+        view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self::with_keys(count, |index| index, view)
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> List<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq,
+    Renderer: crate::core::Renderer,
+{
+    /// The default estimated height of an item of a [`List`].
+    pub const DEFAULT_ESTIMATED_HEIGHT: f32 = 30.0;
+
+    /// The amount of pixels of items built outside of the viewport.
+    const OVERSCAN: f32 = 200.0;
+
+    /// The height of the viewport assumed before it is known.
+    const INITIAL_VIEWPORT_HEIGHT: f32 = 1000.0;
+
+    /// Creates a new [`List`] with the given amount of items, a closure that
+    /// produces the key of the item at a given index, and a closure that
+    /// builds the item at a given index.
+    ///
+    /// The state of each item is kept by its key.
+    pub fn with_keys(
+        count: usize,
+        key: impl Fn(usize) -> Key + 'a,
+        view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            count,
+            key: Box::new(key),
+            view: Box::new(view),
+            width: Length::Fill,
+            spacing: 0.0,
+            estimated_height: Self::DEFAULT_ESTIMATED_HEIGHT,
+            items: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Sets the width of the [`List`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the vertical spacing between the items of the [`List`].
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the height of an item of the [`List`] that has never been
+    /// visible, as long as no other item has been measured.
+    pub fn estimated_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.estimated_height = height.into().0;
+        self
+    }
+}
+
+struct State<Key> {
+    keys: Vec<Key>,
+    range: Range<usize>,
+    heights: Vec<Option<f32>>,
+    height_keys: Vec<Key>,
+    window: Option<(f32, f32)>,
+}
+
+impl<Key> State<Key>
+where
+    Key: Copy + PartialEq,
+{
+    /// Moves the measured heights to the current position of their keys.
+    fn remap_heights(&mut self, count: usize, key: impl Fn(usize) -> Key) {
+        if self.height_keys.len() == count
+            && self
+                .height_keys
+                .iter()
+                .enumerate()
+                .all(|(index, previous)| key(index) == *previous)
+        {
+            return;
+        }
+
+        let mut previous: Vec<_> = self
+            .height_keys
+            .drain(..)
+            .zip(self.heights.drain(..))
+            .collect();
+
+        // Search from the last match, so items that keep their order
+        // are found right away
+        let mut start = 0;
+
+        for index in 0..count {
+            let key = key(index);
+
+            let height = (start..previous.len())
+                .chain(0..start)
+                .find(|&i| previous[i].0 == key)
+                .and_then(|i| {
+                    start = i + 1;
+                    previous[i].1.take()
+                });
+
+            self.height_keys.push(key);
+            self.heights.push(height);
+        }
+    }
+
+    /// Returns the top position of every item, plus the bottom of the last.
+    fn offsets(&self, spacing: f32, estimated_height: f32) -> Vec<f32> {
+        let (total, measured) = self
+            .heights
+            .iter()
+            .flatten()
+            .fold((0.0, 0), |(total, count), height| {
+                (total + height, count + 1)
+            });
+
+        let estimate = if measured > 0 {
+            total / measured as f32
+        } else {
+            estimated_height
+        };
+
+        let mut offsets = Vec::with_capacity(self.heights.len() + 1);
+        let mut y = 0.0;
+
+        for height in &self.heights {
+            offsets.push(y);
+            y += height.unwrap_or(estimate) + spacing;
+        }
+
+        offsets.push((y - spacing).max(0.0));
+        offsets
+    }
+}
+
+/// Returns the items that intersect the given vertical window.
+fn visible(offsets: &[f32], (top, bottom): (f32, f32)) -> Range<usize> {
+    let count = offsets.len().saturating_sub(1);
+
+    let start = offsets[1..]
+        .partition_point(|bottom| *bottom <= top)
+        .min(count);
+    let end = offsets[..count]
+        .partition_point(|top| *top < bottom)
+        .max(start);
+
+    start..end
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for List<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Key> {
+            keys: Vec::new(),
+            range: 0..0,
+            heights: Vec::new(),
+            height_keys: Vec::new(),
+            window: None,
+        })
+    }
+
+    fn diff(&self, _tree: &mut Tree) {
+        // The items are only built once the visible ones are known,
+        // so they are diffed during layout instead.
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State<Key>>();
+        state.remap_heights(self.count, &self.key);
+
+        let limits = limits.width(self.width);
+        let max_width = limits.max().width;
+
+        let window = state.window.unwrap_or((
+            0.0,
+            limits.max().height.min(Self::INITIAL_VIEWPORT_HEIGHT),
+        ));
+
+        let offsets = state.offsets(self.spacing, self.estimated_height);
+        let range = visible(
+            &offsets,
+            (window.0 - Self::OVERSCAN, window.1 + Self::OVERSCAN),
+        );
+
+        let mut items = self.items.borrow_mut();
+        *items = range.clone().map(|index| (self.view)(index)).collect();
+
+        let keys: Vec<Key> =
+            range.clone().map(|index| (self.key)(index)).collect();
+
+        // Reuse the state of the items that were already built
+        let mut previous = std::mem::take(children);
+
+        *children = keys
+            .iter()
+            .zip(items.iter())
+            .map(|(key, item)| {
+                match state.keys.iter().position(|previous| previous == key) {
+                    Some(index) => {
+                        let mut tree = std::mem::replace(
+                            &mut previous[index],
+                            Tree::empty(),
+                        );
+
+                        tree.diff(item.as_widget());
+                        tree
+                    }
+                    None => Tree::new(item.as_widget()),
+                }
+            })
+            .collect();
+
+        state.keys = keys;
+        state.range = range.clone();
+
+        let item_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(max_width, f32::INFINITY),
+        );
+
+        let nodes: Vec<layout::Node> = range
+            .clone()
+            .zip(items.iter())
+            .zip(children.iter_mut())
+            .map(|((index, item), tree)| {
+                let node =
+                    item.as_widget().layout(tree, renderer, &item_limits);
+
+                state.heights[index] = Some(node.size().height);
+
+                node
+            })
+            .collect();
+
+        // Place the items with the measured heights
+        let offsets = state.offsets(self.spacing, self.estimated_height);
+
+        let nodes = range
+            .zip(nodes)
+            .map(|(index, node)| node.move_to(Point::new(0.0, offsets[index])))
+            .collect();
+
+        let width = match self.width {
+            Length::Shrink => 0.0,
+            _ => max_width,
+        };
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(width, offsets[self.count]),
+        );
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let items = self.items.borrow();
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            items
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((item, tree), layout)| {
+                    item.as_widget().operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self
+            .items
+            .get_mut()
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((item, tree), layout)| {
+                item.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        // Build the items that have become visible
+        let bounds = layout.bounds();
+        let window = (
+            viewport.y - bounds.y,
+            viewport.y + viewport.height - bounds.y,
+        );
+
+        let state = tree.state.downcast_mut::<State<Key>>();
+
+        if state.window != Some(window) {
+            let offsets = state.offsets(self.spacing, self.estimated_height);
+            let needed = visible(&offsets, window);
+
+            state.window = Some(window);
+
+            if !needed.is_empty()
+                && (needed.start < state.range.start
+                    || needed.end > state.range.end)
+            {
+                shell.invalidate_layout();
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.items
+            .borrow()
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((item, tree), layout)| {
+                item.as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((item, tree), layout) in self
+            .items
+            .borrow()
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            if layout.bounds().intersects(viewport) {
+                item.as_widget().draw(
+                    tree, renderer, theme, style, layout, cursor, viewport,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            self.items.get_mut(),
+            tree,
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    From<List<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Copy + PartialEq + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(list: List<'a, Key, Message, Theme, Renderer>) -> Self {
+        Element::new(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(heights: impl IntoIterator<Item = Option<f32>>) -> State<usize> {
+        let heights: Vec<_> = heights.into_iter().collect();
+
+        State {
+            keys: (0..heights.len()).collect(),
+            range: 0..0,
+            height_keys: (0..heights.len()).collect(),
+            heights,
+            window: None,
+        }
+    }
+
+    #[test]
+    fn offsets_use_the_estimate_until_measured() {
+        let state = state([None, None, None]);
+
+        assert_eq!(state.offsets(0.0, 10.0), [0.0, 10.0, 20.0, 30.0]);
+        assert_eq!(state.offsets(5.0, 10.0), [0.0, 15.0, 30.0, 40.0]);
+    }
+
+    #[test]
+    fn offsets_estimate_the_average_of_measured_heights() {
+        let state = state([Some(10.0), None, Some(30.0), None]);
+
+        assert_eq!(state.offsets(0.0, 100.0), [0.0, 10.0, 30.0, 60.0, 80.0]);
+        assert_eq!(state.offsets(2.0, 100.0), [0.0, 12.0, 34.0, 66.0, 86.0]);
+    }
+
+    #[test]
+    fn offsets_of_an_empty_list() {
+        assert_eq!(state([]).offsets(5.0, 10.0), [0.0]);
+    }
+
+    #[test]
+    fn heights_follow_their_keys() {
+        let mut state = state([Some(10.0), Some(20.0), Some(30.0)]);
+
+        state.remap_heights(3, |index| index);
+        assert_eq!(state.heights, [Some(10.0), Some(20.0), Some(30.0)]);
+
+        state.remap_heights(4, |index| [9, 0, 1, 2][index]);
+        assert_eq!(state.heights, [None, Some(10.0), Some(20.0), Some(30.0)]);
+
+        state.remap_heights(2, |index| [0, 2][index]);
+        assert_eq!(state.heights, [Some(10.0), Some(30.0)]);
+
+        state.remap_heights(3, |index| [2, 0, 2][index]);
+        assert_eq!(state.heights, [Some(30.0), Some(10.0), None]);
+    }
+
+    #[test]
+    fn visible_items_intersect_the_window() {
+        let offsets = [0.0, 10.0, 20.0, 30.0, 40.0];
+
+        assert_eq!(visible(&offsets, (0.0, 40.0)), 0..4);
+        assert_eq!(visible(&offsets, (5.0, 15.0)), 0..2);
+        assert_eq!(visible(&offsets, (10.0, 20.0)), 1..2);
+        assert_eq!(visible(&offsets, (12.0, 12.0)), 1..2);
+        assert_eq!(visible(&offsets, (-20.0, 5.0)), 0..1);
+        assert_eq!(visible(&offsets, (35.0, 100.0)), 3..4);
+    }
+
+    #[test]
+    fn visible_items_outside_of_the_list() {
+        let offsets = [0.0, 10.0, 20.0];
+
+        assert_eq!(visible(&offsets, (-20.0, -10.0)), 0..0);
+        assert_eq!(visible(&offsets, (20.0, 30.0)), 2..2);
+        assert_eq!(visible(&offsets, (50.0, 60.0)), 2..2);
+        assert_eq!(visible(&[0.0], (0.0, 100.0)), 0..0);
+    }
+}
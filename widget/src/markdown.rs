@@ -0,0 +1,794 @@
+//! Parse and display Markdown.
+//!
+//! You can enable the `highlighter` feature for syntax highlighting
+//! in code blocks.
+//!
+//! Only the core CommonMark syntax is supported, together with tables
+//! and strikethrough.
+use crate::container::{self, Container};
+use crate::core::alignment;
+use crate::core::font::{self, Font};
+use crate::core::text::Span;
+use crate::core::theme::{self, Theme};
+use crate::core::{Border, Element, Length, Padding, Pixels};
+use crate::rule;
+use crate::scrollable::{self, Scrollable};
+use crate::text;
+use crate::{column, horizontal_rule, rich_text, row};
+
+use std::borrow::Cow;
+
+pub use pulldown_cmark::HeadingLevel;
+pub use url::Url;
+
+/// A Markdown item.
+#[derive(Debug, Clone)]
+pub enum Item {
+    /// A heading.
+    Heading(HeadingLevel, Text),
+    /// A paragraph.
+    Paragraph(Text),
+    /// A code block.
+    ///
+    /// You can enable the `highlighter` feature for syntax highlighting.
+    CodeBlock(Text),
+    /// A list.
+    List {
+        /// The first number of the list, if it is ordered.
+        start: Option<u64>,
+        /// The items of the list.
+        items: Vec<Vec<Item>>,
+    },
+    /// A block quote.
+    Quote(Vec<Item>),
+    /// A table.
+    Table {
+        /// The alignment of each column of the table.
+        alignments: Vec<alignment::Horizontal>,
+        /// The rows of the table; starting with its head.
+        rows: Vec<Vec<Text>>,
+    },
+    /// An image.
+    Image {
+        /// The location of the image.
+        url: String,
+        /// The title of the image.
+        title: String,
+        /// The alternative text of the image.
+        alt: Text,
+    },
+    /// A thematic break.
+    Rule,
+}
+
+/// A bunch of Markdown text.
+#[derive(Debug, Clone, Default)]
+pub struct Text {
+    spans: Vec<Span<'static, Url>>,
+}
+
+impl Text {
+    /// Returns the [`Span`]s of the [`Text`].
+    pub fn spans(&self) -> impl Iterator<Item = Span<'_, Url>> {
+        self.spans.iter().map(|span| Span {
+            text: Cow::Borrowed(&span.text),
+            font: span.font,
+            color: span.color,
+            highlight: span.highlight,
+            underline: span.underline,
+            strikethrough: span.strikethrough,
+            link: span.link.clone(),
+        })
+    }
+
+    /// Returns true if the [`Text`] has no content.
+    pub fn is_empty(&self) -> bool {
+        self.spans.iter().all(|span| span.text.is_empty())
+    }
+}
+
+/// Parses the given Markdown source into a list of [`Item`]s.
+///
+/// The colors of links and code are taken from the given [`Palette`].
+///
+/// [`Palette`]: theme::Palette
+pub fn parse(markdown: &str, palette: theme::Palette) -> Vec<Item> {
+    let parser = pulldown_cmark::Parser::new_ext(
+        markdown,
+        pulldown_cmark::Options::ENABLE_TABLES
+            | pulldown_cmark::Options::ENABLE_STRIKETHROUGH,
+    );
+
+    let mut parser_state = Parser {
+        palette,
+        scopes: Vec::new(),
+        items: Vec::new(),
+        spans: Vec::new(),
+        strong: 0,
+        emphasis: 0,
+        strikethrough: 0,
+        link: None,
+        code_block: None,
+    };
+
+    for event in parser {
+        parser_state.process(event);
+    }
+
+    parser_state.items
+}
+
+struct Parser {
+    palette: theme::Palette,
+    scopes: Vec<Scope>,
+    items: Vec<Item>,
+    spans: Vec<Span<'static, Url>>,
+    strong: usize,
+    emphasis: usize,
+    strikethrough: usize,
+    link: Option<Url>,
+    code_block: Option<(String, String)>,
+}
+
+enum Scope {
+    List {
+        start: Option<u64>,
+        items: Vec<Vec<Item>>,
+    },
+    ListItem(Vec<Item>),
+    Quote(Vec<Item>),
+    Table {
+        alignments: Vec<alignment::Horizontal>,
+        rows: Vec<Vec<Text>>,
+    },
+    Image {
+        url: String,
+        title: String,
+    },
+}
+
+impl Parser {
+    fn process(&mut self, event: pulldown_cmark::Event<'_>) {
+        use pulldown_cmark::{Event, Tag, TagEnd};
+
+        match event {
+            Event::Start(tag) => match tag {
+                Tag::BlockQuote(_) => {
+                    self.flush();
+                    self.scopes.push(Scope::Quote(Vec::new()));
+                }
+                Tag::CodeBlock(kind) => {
+                    let language = match kind {
+                        pulldown_cmark::CodeBlockKind::Fenced(language) => {
+                            language
+                                .split_whitespace()
+                                .next()
+                                .unwrap_or_default()
+                                .to_owned()
+                        }
+                        pulldown_cmark::CodeBlockKind::Indented => {
+                            String::new()
+                        }
+                    };
+
+                    self.flush();
+                    self.code_block = Some((language, String::new()));
+                }
+                Tag::List(start) => {
+                    self.flush();
+                    self.scopes.push(Scope::List {
+                        start,
+                        items: Vec::new(),
+                    });
+                }
+                Tag::Item => {
+                    self.scopes.push(Scope::ListItem(Vec::new()));
+                }
+                Tag::Table(alignments) => {
+                    self.flush();
+                    self.scopes.push(Scope::Table {
+                        alignments: alignments
+                            .into_iter()
+                            .map(|alignment| match alignment {
+                                pulldown_cmark::Alignment::None
+                                | pulldown_cmark::Alignment::Left => {
+                                    alignment::Horizontal::Left
+                                }
+                                pulldown_cmark::Alignment::Center => {
+                                    alignment::Horizontal::Center
+                                }
+                                pulldown_cmark::Alignment::Right => {
+                                    alignment::Horizontal::Right
+                                }
+                            })
+                            .collect(),
+                        rows: Vec::new(),
+                    });
+                }
+                Tag::TableHead | Tag::TableRow => {
+                    if let Some(Scope::Table { rows, .. }) =
+                        self.scopes.last_mut()
+                    {
+                        rows.push(Vec::new());
+                    }
+                }
+                Tag::Emphasis => {
+                    self.emphasis += 1;
+                }
+                Tag::Strong => {
+                    self.strong += 1;
+                }
+                Tag::Strikethrough => {
+                    self.strikethrough += 1;
+                }
+                Tag::Link { dest_url, .. } => {
+                    self.link = Url::parse(&dest_url).ok();
+                }
+                Tag::Image {
+                    dest_url, title, ..
+                } => {
+                    self.flush();
+                    self.scopes.push(Scope::Image {
+                        url: dest_url.into_string(),
+                        title: title.into_string(),
+                    });
+                }
+                Tag::Paragraph | Tag::Heading { .. } => {
+                    self.flush();
+                }
+                Tag::TableCell
+                | Tag::HtmlBlock
+                | Tag::FootnoteDefinition(_)
+                | Tag::MetadataBlock(_) => {}
+            },
+            Event::End(tag) => match tag {
+                TagEnd::Paragraph => {
+                    self.flush();
+                }
+                TagEnd::Heading(level) => {
+                    let text = self.take_text();
+
+                    self.push(Item::Heading(level, text));
+                }
+                TagEnd::BlockQuote => {
+                    self.flush();
+
+                    if let Some(Scope::Quote(items)) = self.scopes.pop() {
+                        self.push(Item::Quote(items));
+                    }
+                }
+                TagEnd::CodeBlock => {
+                    if let Some((language, code)) = self.code_block.take() {
+                        let text = self.highlight(&language, &code);
+
+                        self.push(Item::CodeBlock(text));
+                    }
+                }
+                TagEnd::Item => {
+                    // Tight lists have no paragraphs
+                    self.flush();
+
+                    if let Some(Scope::ListItem(item)) = self.scopes.pop() {
+                        if let Some(Scope::List { items, .. }) =
+                            self.scopes.last_mut()
+                        {
+                            items.push(item);
+                        }
+                    }
+                }
+                TagEnd::List(_) => {
+                    if let Some(Scope::List { start, items }) =
+                        self.scopes.pop()
+                    {
+                        self.push(Item::List { start, items });
+                    }
+                }
+                TagEnd::TableCell => {
+                    let text = self.take_text();
+
+                    if let Some(Scope::Table { rows, .. }) =
+                        self.scopes.last_mut()
+                    {
+                        if let Some(row) = rows.last_mut() {
+                            row.push(text);
+                        }
+                    }
+                }
+                TagEnd::Table => {
+                    if let Some(Scope::Table { alignments, rows }) =
+                        self.scopes.pop()
+                    {
+                        self.push(Item::Table { alignments, rows });
+                    }
+                }
+                TagEnd::Emphasis => {
+                    self.emphasis = self.emphasis.saturating_sub(1);
+                }
+                TagEnd::Strong => {
+                    self.strong = self.strong.saturating_sub(1);
+                }
+                TagEnd::Strikethrough => {
+                    self.strikethrough = self.strikethrough.saturating_sub(1);
+                }
+                TagEnd::Link => {
+                    self.link = None;
+                }
+                TagEnd::Image => {
+                    let alt = self.take_text();
+
+                    if let Some(Scope::Image { url, title }) = self.scopes.pop()
+                    {
+                        self.push(Item::Image { url, title, alt });
+                    }
+                }
+                TagEnd::TableHead
+                | TagEnd::TableRow
+                | TagEnd::HtmlBlock
+                | TagEnd::FootnoteDefinition
+                | TagEnd::MetadataBlock(_) => {}
+            },
+            Event::Text(text) => {
+                if let Some((_, code)) = &mut self.code_block {
+                    code.push_str(&text);
+                } else {
+                    self.push_span(Span::new(text.into_string()));
+                }
+            }
+            Event::Code(code) => {
+                self.push_span(
+                    Span::new(code.into_string())
+                        .font(Font::MONOSPACE)
+                        .background(self.palette.text.scale_alpha(0.1)),
+                );
+            }
+            Event::SoftBreak => {
+                self.push_span(Span::new(" "));
+            }
+            Event::HardBreak => {
+                self.push_span(Span::new("\n"));
+            }
+            Event::Rule => {
+                self.flush();
+                self.push(Item::Rule);
+            }
+            Event::Html(_)
+            | Event::InlineHtml(_)
+            | Event::InlineMath(_)
+            | Event::DisplayMath(_)
+            | Event::FootnoteReference(_)
+            | Event::TaskListMarker(_) => {}
+        }
+    }
+
+    /// Adds a [`Span`] to the current paragraph, styled with the active
+    /// emphasis and link.
+    fn push_span(&mut self, span: Span<'static, Url>) {
+        let font = span.font.unwrap_or(Font::DEFAULT);
+
+        let font = Font {
+            weight: if self.strong > 0 {
+                font::Weight::Bold
+            } else {
+                font::Weight::Normal
+            },
+            style: if self.emphasis > 0 {
+                font::Style::Italic
+            } else {
+                font::Style::Normal
+            },
+            ..font
+        };
+
+        let span = span
+            .font_maybe((font != Font::DEFAULT).then_some(font))
+            .strikethrough(self.strikethrough > 0);
+
+        let span = match self.link.clone() {
+            Some(link) => {
+                span.color(self.palette.primary).underline(true).link(link)
+            }
+            None => span,
+        };
+
+        self.spans.push(span);
+    }
+
+    fn take_text(&mut self) -> Text {
+        Text {
+            spans: std::mem::take(&mut self.spans),
+        }
+    }
+
+    /// Turns the pending spans into a paragraph, if there are any.
+    fn flush(&mut self) {
+        let text = self.take_text();
+
+        if !text.is_empty() {
+            self.push(Item::Paragraph(text));
+        }
+    }
+
+    fn push(&mut self, item: Item) {
+        let items =
+            self.scopes.iter_mut().rev().find_map(|scope| match scope {
+                Scope::ListItem(items) | Scope::Quote(items) => Some(items),
+                _ => None,
+            });
+
+        items.unwrap_or(&mut self.items).push(item);
+    }
+
+    #[cfg(feature = "highlighter")]
+    fn highlight(&self, language: &str, code: &str) -> Text {
+        use crate::core::text::Highlighter as _;
+        use iced_highlighter::Highlighter;
+
+        let mut highlighter = Highlighter::new(&iced_highlighter::Settings {
+            theme: iced_highlighter::Theme::from_palette(
+                "markdown",
+                self.palette,
+            ),
+            extension: language.to_owned(),
+            syntaxes: iced_highlighter::Syntaxes::default(),
+        });
+
+        let mut spans = Vec::new();
+
+        for (i, line) in code.trim_end_matches('\n').lines().enumerate() {
+            if i > 0 {
+                spans.push(Span::new("\n"));
+            }
+
+            let mut end = 0;
+
+            for (range, highlight) in highlighter.highlight_line(line) {
+                if range.start > end {
+                    spans.push(Span::new(line[end..range.start].to_owned()));
+                }
+
+                let format = highlight.to_format();
+
+                spans.push(
+                    Span::new(line[range.clone()].to_owned())
+                        .color_maybe(format.color)
+                        .font_maybe(format.font),
+                );
+
+                end = range.end;
+            }
+
+            if end < line.len() {
+                spans.push(Span::new(line[end..].to_owned()));
+            }
+        }
+
+        Text { spans }
+    }
+
+    #[cfg(not(feature = "highlighter"))]
+    fn highlight(&self, _language: &str, code: &str) -> Text {
+        Text {
+            spans: vec![Span::new(code.trim_end_matches('\n').to_owned())],
+        }
+    }
+}
+
+/// Configuration controlling the way Markdown is rendered.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Settings {
+    /// The base text size.
+    pub text_size: Pixels,
+    /// The text size of level 1 headings.
+    pub h1_size: Pixels,
+    /// The text size of level 2 headings.
+    pub h2_size: Pixels,
+    /// The text size of level 3 headings.
+    pub h3_size: Pixels,
+    /// The text size of level 4 headings.
+    pub h4_size: Pixels,
+    /// The text size of level 5 headings.
+    pub h5_size: Pixels,
+    /// The text size of level 6 headings.
+    pub h6_size: Pixels,
+    /// The text size used in code blocks.
+    pub code_size: Pixels,
+    /// The spacing between items.
+    pub spacing: Pixels,
+}
+
+impl Settings {
+    /// Creates new [`Settings`] with the given base text size, scaling the
+    /// rest of the sizes accordingly.
+    pub fn with_text_size(text_size: impl Into<Pixels>) -> Self {
+        let text_size = text_size.into();
+
+        Self {
+            text_size,
+            h1_size: text_size * 2.0,
+            h2_size: text_size * 1.75,
+            h3_size: text_size * 1.5,
+            h4_size: text_size * 1.25,
+            h5_size: text_size,
+            h6_size: text_size,
+            code_size: text_size * 0.75,
+            spacing: text_size * 0.875,
+        }
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self::with_text_size(16)
+    }
+}
+
+/// Displays a list of Markdown [`Item`]s.
+///
+/// Clicking a link produces its [`Url`] as a message.
+pub fn view<'a, Theme, Renderer>(
+    items: impl IntoIterator<Item = &'a Item>,
+    settings: Settings,
+) -> Element<'a, Url, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    <Theme as container::Catalog>::Class<'a>:
+        From<container::StyleFn<'a, Theme>>,
+    Renderer: self::Renderer + 'a,
+{
+    column(items.into_iter().map(|item| self::item(item, settings)))
+        .spacing(settings.spacing)
+        .into()
+}
+
+fn item<'a, Theme, Renderer>(
+    item: &'a Item,
+    settings: Settings,
+) -> Element<'a, Url, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    <Theme as container::Catalog>::Class<'a>:
+        From<container::StyleFn<'a, Theme>>,
+    Renderer: self::Renderer + 'a,
+{
+    let Settings {
+        text_size,
+        code_size,
+        spacing,
+        ..
+    } = settings;
+
+    match item {
+        Item::Heading(level, heading) => {
+            let size = match level {
+                HeadingLevel::H1 => settings.h1_size,
+                HeadingLevel::H2 => settings.h2_size,
+                HeadingLevel::H3 => settings.h3_size,
+                HeadingLevel::H4 => settings.h4_size,
+                HeadingLevel::H5 => settings.h5_size,
+                HeadingLevel::H6 => settings.h6_size,
+            };
+
+            rich_text(heading.spans())
+                .size(size)
+                .font(Font {
+                    weight: font::Weight::Bold,
+                    ..Font::DEFAULT
+                })
+                .into()
+        }
+        Item::Paragraph(paragraph) => {
+            rich_text(paragraph.spans()).size(text_size).into()
+        }
+        Item::CodeBlock(code) => Container::new(Scrollable::with_direction(
+            Container::new(
+                rich_text(code.spans())
+                    .font(Font::MONOSPACE)
+                    .size(code_size),
+            )
+            .padding(spacing.0 / 2.0),
+            scrollable::Direction::Horizontal(
+                scrollable::Properties::new().width(2).scroller_width(2),
+            ),
+        ))
+        .width(Length::Fill)
+        .padding(Padding::new(spacing.0 / 2.0))
+        .style(Theme::code_block)
+        .into(),
+        Item::List { start, items } => {
+            column(items.iter().enumerate().map(|(i, items)| {
+                let marker = match start {
+                    Some(start) => format!("{}.", *start + i as u64),
+                    None => String::from("•"),
+                };
+
+                row![
+                    text::Text::new(marker).size(text_size),
+                    view(items, settings)
+                ]
+                .spacing(spacing.0 / 2.0)
+                .into()
+            }))
+            .spacing(spacing.0 / 2.0)
+            .into()
+        }
+        Item::Quote(items) => Container::new(view(items, settings))
+            .width(Length::Fill)
+            .padding(Padding::new(spacing.0 / 2.0))
+            .style(Theme::quote)
+            .into(),
+        Item::Table { alignments, rows } => {
+            let rows = rows.iter().enumerate().map(|(i, cells)| {
+                let cells = cells.iter().enumerate().map(|(j, cell)| {
+                    let font = Font {
+                        weight: if i == 0 {
+                            font::Weight::Bold
+                        } else {
+                            font::Weight::Normal
+                        },
+                        ..Font::DEFAULT
+                    };
+
+                    Container::new(
+                        rich_text(cell.spans()).size(text_size).font(font),
+                    )
+                    .width(Length::Fill)
+                    .align_x(
+                        alignments
+                            .get(j)
+                            .copied()
+                            .unwrap_or(alignment::Horizontal::Left),
+                    )
+                    .into()
+                });
+
+                let row = row(cells).spacing(spacing);
+
+                if i == 0 {
+                    column![row, horizontal_rule(1)]
+                        .spacing(spacing.0 / 2.0)
+                        .into()
+                } else {
+                    row.into()
+                }
+            });
+
+            column(rows).spacing(spacing.0 / 2.0).into()
+        }
+        Item::Image { url, alt, .. } => image(url, alt, settings),
+        Item::Rule => horizontal_rule(1).into(),
+    }
+}
+
+#[cfg(feature = "image")]
+fn image<'a, Theme, Renderer>(
+    url: &'a str,
+    _alt: &'a Text,
+    _settings: Settings,
+) -> Element<'a, Url, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: self::Renderer + 'a,
+{
+    crate::image(url).into()
+}
+
+#[cfg(not(feature = "image"))]
+fn image<'a, Theme, Renderer>(
+    _url: &'a str,
+    alt: &'a Text,
+    settings: Settings,
+) -> Element<'a, Url, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: self::Renderer + 'a,
+{
+    rich_text(alt.spans())
+        .size(settings.text_size)
+        .font(Font {
+            style: font::Style::Italic,
+            ..Font::DEFAULT
+        })
+        .into()
+}
+
+/// The theme catalog of Markdown items.
+pub trait Catalog:
+    container::Catalog + scrollable::Catalog + text::Catalog + rule::Catalog
+{
+    /// The [`container::Style`] of code blocks.
+    fn code_block(&self) -> container::Style;
+
+    /// The [`container::Style`] of block quotes.
+    fn quote(&self) -> container::Style;
+}
+
+impl Catalog for Theme {
+    fn code_block(&self) -> container::Style {
+        let palette = self.extended_palette();
+
+        container::Style {
+            background: Some(palette.background.weak.color.into()),
+            text_color: Some(palette.background.weak.text),
+            border: Border::rounded(4),
+            ..container::Style::default()
+        }
+    }
+
+    fn quote(&self) -> container::Style {
+        let palette = self.extended_palette();
+
+        container::Style {
+            border: Border {
+                color: palette.background.strong.color,
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..container::Style::default()
+        }
+    }
+}
+
+/// A renderer capable of displaying Markdown.
+#[cfg(feature = "image")]
+pub trait Renderer:
+    crate::core::text::Renderer<Font = Font>
+    + crate::core::image::Renderer<Handle = crate::core::image::Handle>
+{
+}
+
+#[cfg(feature = "image")]
+impl<T> Renderer for T where
+    T: crate::core::text::Renderer<Font = Font>
+        + crate::core::image::Renderer<Handle = crate::core::image::Handle>
+{
+}
+
+/// A renderer capable of displaying Markdown.
+#[cfg(not(feature = "image"))]
+pub trait Renderer: crate::core::text::Renderer<Font = Font> {}
+
+#[cfg(not(feature = "image"))]
+impl<T> Renderer for T where T: crate::core::text::Renderer<Font = Font> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(text: &Text) -> String {
+        text.spans().map(|span| span.text.into_owned()).collect()
+    }
+
+    #[test]
+    fn it_parses_nested_items() {
+        let items = parse(
+            "# Title\n\n- One [link](https://iced.rs)\n- Two\n  > Quoted\n",
+            theme::Palette::LIGHT,
+        );
+
+        let [Item::Heading(HeadingLevel::H1, title), Item::List { start: None, items }] =
+            items.as_slice()
+        else {
+            panic!("unexpected items: {items:?}");
+        };
+
+        assert_eq!(text(title), "Title");
+
+        let [Item::Paragraph(one)] = items[0].as_slice() else {
+            panic!("unexpected list item: {:?}", items[0]);
+        };
+
+        assert_eq!(text(one), "One link");
+        assert_eq!(
+            one.spans().filter_map(|span| span.link).collect::<Vec<_>>(),
+            [Url::parse("https://iced.rs").unwrap()]
+        );
+
+        let [Item::Paragraph(two), Item::Quote(quote)] = items[1].as_slice()
+        else {
+            panic!("unexpected list item: {:?}", items[1]);
+        };
+
+        assert_eq!(text(two), "Two");
+        assert!(matches!(quote.as_slice(), [Item::Paragraph(_)]));
+    }
+}
@@ -0,0 +1,570 @@
+//! Display a bar of menus with nested submenus.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+use crate::overlay::menu;
+use crate::overlay::menu::cascade::{self, Cascade};
+
+pub use crate::overlay::menu::Item;
+
+/// A horizontal bar of menus, each one opening a list of [`Item`]s when
+/// its title is clicked.
+///
+/// Once a menu is open, the other menus open by hovering their titles or
+/// with the arrow keys. Nested submenus are supported with
+/// [`Item::submenu`].
+///
+/// # Example
+/// ```no_run
+/// # type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
+/// use iced_widget::menu_bar;
+/// use iced_widget::menu_bar::Item;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     New,
+///     Open,
+///     OpenRecent(usize),
+///     Quit,
+///     ToggleSidebar,
+/// }
+///
+/// fn view<'a>(recent: &[String], has_sidebar: bool) -> Element<'a, Message> {
+///     menu_bar()
+///         .menu(
+///             "File",
+///             [
+///                 Item::new("New").shortcut("Ctrl+N").on_select(Message::New),
+///                 Item::new("Open…").shortcut("Ctrl+O").on_select(Message::Open),
+///                 Item::submenu(
+///                     "Open Recent",
+///                     recent.iter().enumerate().map(|(i, file)| {
+///                         Item::new(file).on_select(Message::OpenRecent(i))
+///                     }),
+///                 ),
+///                 Item::separator(),
+///                 Item::new("Quit").shortcut("Ctrl+Q").on_select(Message::Quit),
+///             ],
+///         )
+///         .menu(
+///             "View",
+///             [Item::new("Sidebar")
+///                 .checked(has_sidebar)
+///                 .on_select(Message::ToggleSidebar)],
+///         )
+///         .into()
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    menus: Vec<(String, Vec<Item<Message>>)>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    font: Option<Renderer::Font>,
+    class: <Theme as Catalog>::Class<'a>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> MenuBar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of the titles and items of a [`MenuBar`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 5.0,
+        bottom: 5.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// Creates a new, empty [`MenuBar`].
+    pub fn new() -> Self {
+        Self {
+            menus: Vec::new(),
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            font: None,
+            class: <Theme as Catalog>::default(),
+            menu_class: <Theme as Catalog>::default_menu(),
+        }
+    }
+
+    /// Adds a menu with the given title and items to the [`MenuBar`].
+    pub fn menu(
+        mut self,
+        title: impl Into<String>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        self.menus.push((title.into(), items.into_iter().collect()));
+        self
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the titles and items of the [`MenuBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the titles of the [`MenuBar`].
+    pub fn text_line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the [`MenuBar`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the menus of the [`MenuBar`].
+    #[must_use]
+    pub fn menu_style(
+        mut self,
+        style: impl Fn(&Theme) -> menu::Style + 'a,
+    ) -> Self
+    where
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`MenuBar`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(
+        mut self,
+        class: impl Into<<Theme as Catalog>::Class<'a>>,
+    ) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style class of the menus of the [`MenuBar`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn menu_class(
+        mut self,
+        class: impl Into<<Theme as menu::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.menu_class = class.into();
+        self
+    }
+
+    fn text<T>(
+        &self,
+        content: T,
+        renderer: &Renderer,
+    ) -> Text<T, Renderer::Font> {
+        Text {
+            content,
+            bounds: Size::INFINITY,
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.text_line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+        }
+    }
+
+    /// Opens the menu at the given index, selecting its first item if
+    /// it was opened with the keyboard.
+    fn open(&self, state: &mut State, index: usize, from_keyboard: bool) {
+        state.active = index;
+        state.cascade.open(if from_keyboard {
+            cascade::first_enabled(&self.menus[index].1)
+        } else {
+            None
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Default
+    for MenuBar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    active: usize,
+    cascade: cascade::State,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MenuBar<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let height = f32::from(self.text_line_height.to_absolute(text_size))
+            + self.padding.vertical();
+
+        let mut x = 0.0;
+
+        let titles: Vec<_> = self
+            .menus
+            .iter()
+            .map(|(title, _)| {
+                let width = Renderer::Paragraph::with_text(
+                    self.text(title.as_str(), renderer),
+                )
+                .min_width()
+                .ceil()
+                    + self.padding.horizontal();
+
+                let node = layout::Node::new(Size::new(width, height))
+                    .move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let size = limits.height(height).resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(x, height),
+        );
+
+        layout::Node::with_children(size, titles)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let is_open =
+            state.cascade.is_open() && state.active < self.menus.len();
+
+        let hovered_title = cursor.position().and_then(|position| {
+            layout
+                .children()
+                .position(|title| title.bounds().contains(position))
+        });
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                match hovered_title {
+                    Some(index) if !is_open || index != state.active => {
+                        self.open(state, index, false);
+                    }
+                    _ if is_open => {
+                        // Event wasn't processed by the overlay, so the
+                        // cursor was clicked either outside of it or on the
+                        // title of the open menu
+                        state.cascade.close();
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) if is_open => {
+                if let Some(index) =
+                    hovered_title.filter(|&index| index != state.active)
+                {
+                    self.open(state, index, false);
+                    shell.invalidate_layout();
+                }
+
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) if is_open => {
+                let count = self.menus.len();
+
+                let index = match named {
+                    key::Named::ArrowLeft => (state.active + count - 1) % count,
+                    key::Named::ArrowRight => (state.active + 1) % count,
+                    key::Named::Escape => {
+                        state.cascade.close();
+                        shell.invalidate_layout();
+
+                        return event::Status::Captured;
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                self.open(state, index, true);
+                shell.invalidate_layout();
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout
+            .children()
+            .any(|title| cursor.is_over(title.bounds()))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = Catalog::style(theme, &self.class);
+        let bounds = layout.bounds();
+
+        if style.background.is_some() || style.border.width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        for (index, ((title, _), layout)) in
+            self.menus.iter().zip(layout.children()).enumerate()
+        {
+            let bounds = layout.bounds();
+
+            let is_selected = if state.cascade.is_open() {
+                state.active == index
+            } else {
+                cursor.is_over(bounds)
+            };
+
+            if is_selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border::rounded(style.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.selected_background,
+                );
+            }
+
+            renderer.fill_text(
+                self.text(title.clone(), renderer),
+                Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                if is_selected {
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                },
+                *viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.cascade.is_open() {
+            return None;
+        }
+
+        let (_, items) = self.menus.get(state.active)?;
+        let title = layout.children().nth(state.active)?.bounds();
+
+        Some(
+            Cascade::new(&mut state.cascade, items, &self.menu_class)
+                .padding(self.padding)
+                .text_size(self.text_size)
+                .font(self.font)
+                .overlay(title.position() + translation, title.height),
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MenuBar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(menu_bar: MenuBar<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(menu_bar)
+    }
+}
+
+/// The appearance of a [`MenuBar`].
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The [`Background`] of the [`MenuBar`].
+    pub background: Option<Background>,
+    /// The [`Border`] of the [`MenuBar`].
+    pub border: Border,
+    /// The text [`Color`] of the titles of the [`MenuBar`].
+    pub text_color: Color,
+    /// The text [`Color`] of a hovered or open title of the [`MenuBar`].
+    pub selected_text_color: Color,
+    /// The [`Background`] of a hovered or open title of the [`MenuBar`].
+    pub selected_background: Background,
+}
+
+/// The theme catalog of a [`MenuBar`].
+pub trait Catalog: menu::Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The default class for the menus of the [`MenuBar`].
+    fn default_menu<'a>() -> <Self as menu::Catalog>::Class<'a> {
+        <Self as menu::Catalog>::default()
+    }
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`MenuBar`].
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> StyleFn<'a, Self> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &StyleFn<'_, Self>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`MenuBar`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: Some(palette.background.weak.color.into()),
+        border: Border::default(),
+        text_color: palette.background.weak.text,
+        selected_text_color: palette.primary.strong.text,
+        selected_background: palette.primary.strong.color.into(),
+    }
+}
@@ -0,0 +1,1066 @@
+//! Display fields that can only be filled with numbers.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::{accessibility, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Widget,
+};
+use crate::text_input::{self, TextInput, Value};
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A field that can only be filled with numbers.
+///
+/// The value of a [`NumberInput`] can be typed, or stepped with its `-` and
+/// `+` buttons and, while focused, with the arrow keys and the mouse wheel.
+/// A message is only produced when the typed text is a valid number within
+/// bounds; otherwise, the [`NumberInput`] is drawn with a [`Status::Invalid`]
+/// status.
+///
+/// # Example
+/// ```no_run
+/// # pub type NumberInput<'a, T, Message> = iced_widget::NumberInput<'a, T, Message>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     QuantityChanged(u32),
+/// }
+///
+/// let quantity = 3;
+///
+/// let input = NumberInput::new(quantity)
+///     .min(1)
+///     .max(10)
+///     .on_change(Message::QuantityChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    id: Option<widget::Id>,
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    text_input: TextInput<'a, TextInputEvent, InputStyle, Renderer>,
+    on_change: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    width: Length,
+    padding: Padding,
+    size: Option<Pixels>,
+    line_height: text::LineHeight,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Number,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`NumberInput`] with the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            id: None,
+            value,
+            min: None,
+            max: None,
+            step: T::one(),
+            text_input: TextInput::new("", &value.to_string()),
+            on_change: None,
+            width: Length::Fill,
+            padding: text_input::DEFAULT_PADDING,
+            size: None,
+            line_height: text::LineHeight::default(),
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message that should be produced when the value of the
+    /// [`NumberInput`] changes.
+    ///
+    /// If this method is not called, the [`NumberInput`] will be disabled.
+    pub fn on_change(mut self, on_change: impl Fn(T) -> Message + 'a) -> Self {
+        self.text_input = self.text_input.on_input(TextInputEvent::TextChanged);
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the minimum value of the [`NumberInput`].
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value of the [`NumberInput`].
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the amount the value of the [`NumberInput`] changes with every
+    /// step.
+    ///
+    /// By default, it is `1`.
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the [`text_input::Id`] of the [`NumberInput`].
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.id = Some(id.clone().into());
+        self.text_input = self.text_input.id(id);
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`NumberInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self.text_input = self.text_input.padding(self.padding);
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+
+        self.size = Some(size);
+        self.text_input = self.text_input.size(size);
+        self
+    }
+
+    /// Sets the [`text::LineHeight`] of the [`NumberInput`].
+    pub fn line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.line_height = line_height.into();
+        self.text_input = self.text_input.line_height(self.line_height);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`NumberInput`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = Some(font);
+        self.text_input = self.text_input.font(font);
+        self
+    }
+
+    /// Sets the style of the [`NumberInput`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`NumberInput`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn parse(&self, text: &str) -> Option<T> {
+        let value = T::from_str(text.trim()).ok()?;
+
+        let is_out_of_bounds = self.min.is_some_and(|min| value < min)
+            || self.max.is_some_and(|max| value > max);
+
+        (!is_out_of_bounds).then_some(value)
+    }
+
+    fn increment(&self) -> Option<T> {
+        let max = self.max.unwrap_or_else(T::max_value);
+
+        if self.value >= max {
+            return None;
+        }
+
+        // Avoid overflowing the numeric type itself
+        if self.value > T::max_value() - self.step {
+            return Some(max);
+        }
+
+        let value = self.value + self.step;
+
+        Some(if value > max { max } else { value })
+    }
+
+    fn decrement(&self) -> Option<T> {
+        let min = self.min.unwrap_or_else(T::min_value);
+
+        if self.value <= min {
+            return None;
+        }
+
+        // Avoid overflowing the numeric type itself
+        if self.value < T::min_value() + self.step {
+            return Some(min);
+        }
+
+        let value = self.value - self.step;
+
+        Some(if value < min { min } else { value })
+    }
+
+    fn button_width(&self, renderer: &Renderer) -> f32 {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        self.line_height.to_absolute(size).0 + self.padding.vertical()
+    }
+}
+
+/// A number that can be edited with a [`NumberInput`].
+///
+/// This trait is implemented for all the primitive integer and floating point
+/// types.
+pub trait Number:
+    Copy
+    + PartialOrd
+    + Display
+    + FromStr
+    + num_traits::Num
+    + num_traits::Bounded
+    + num_traits::ToPrimitive
+    + 'static
+{
+}
+
+impl<T> Number for T where
+    T: Copy
+        + PartialOrd
+        + Display
+        + FromStr
+        + num_traits::Num
+        + num_traits::Bounded
+        + num_traits::ToPrimitive
+        + 'static
+{
+}
+
+#[derive(Debug, Clone)]
+enum TextInputEvent {
+    TextChanged(String),
+}
+
+#[derive(Debug, Default)]
+struct State {
+    editing: Option<Editing>,
+    is_pressed: Option<Button>,
+    requests: accessibility::Requests,
+}
+
+/// The text typed by the user, which may not be a valid number yet.
+#[derive(Debug)]
+struct Editing {
+    text: String,
+    is_valid: bool,
+    /// The last value the text was synchronized with.
+    value: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Button {
+    Decrement,
+    Increment,
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for NumberInput<'a, T, Message, Theme, Renderer>
+where
+    T: Number,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(
+            &self.text_input as &dyn Widget<_, InputStyle, Renderer>,
+        )]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Discard the typed text if the value changed elsewhere
+        if state
+            .editing
+            .as_ref()
+            .is_some_and(|editing| editing.value != self.value.to_string())
+        {
+            state.editing = None;
+        }
+
+        if self.on_change.is_none() {
+            state.editing = None;
+            state.is_pressed = None;
+        }
+
+        Widget::<TextInputEvent, InputStyle, Renderer>::diff(
+            &self.text_input,
+            &mut tree.children[0],
+        );
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_ref::<State>();
+        let value = state
+            .editing
+            .as_ref()
+            .map(|editing| Value::new(&editing.text));
+
+        let button_width = self.button_width(renderer);
+
+        let limits = limits
+            .width(self.width)
+            .shrink(Size::new(button_width * 2.0, 0.0));
+
+        let input = self.text_input.layout(
+            &mut tree.children[0],
+            renderer,
+            &limits,
+            value.as_ref(),
+        );
+
+        let input_size = input.size();
+        let button_size = Size::new(button_width, input_size.height);
+
+        let decrement = layout::Node::new(button_size)
+            .move_to(Point::new(input_size.width, 0.0));
+
+        let increment = layout::Node::new(button_size)
+            .move_to(Point::new(input_size.width + button_width, 0.0));
+
+        layout::Node::with_children(
+            Size::new(input_size.width + button_width * 2.0, input_size.height),
+            vec![input, decrement, increment],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let text = state.editing.as_ref().map_or_else(
+            || self.value.to_string(),
+            |editing| editing.text.clone(),
+        );
+
+        let input_state = tree.children[0]
+            .state
+            .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+        operation.focusable(input_state, self.id.as_ref());
+        operation.text_input(input_state, self.id.as_ref());
+        operation.text(self.id.as_ref(), layout.bounds(), &text);
+
+        let (min, max) = (
+            self.min.unwrap_or_else(T::min_value),
+            self.max.unwrap_or_else(T::max_value),
+        );
+
+        operation.accessibility(
+            self.id.as_ref(),
+            layout.bounds(),
+            &accessibility::Node::new(accessibility::Role::SpinButton)
+                .value(accessibility::Value::Number {
+                    value: self.value.to_f64().unwrap_or_default(),
+                    min: min.to_f64().unwrap_or(f64::MIN),
+                    max: max.to_f64().unwrap_or(f64::MAX),
+                    step: self.step.to_f64(),
+                })
+                .focused(input_state.is_focused())
+                .disabled(self.on_change.is_none())
+                .action(accessibility::Action::Focus)
+                .action(accessibility::Action::SetValue)
+                .action(accessibility::Action::Increment)
+                .action(accessibility::Action::Decrement),
+            Some(&mut state.requests),
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let Some(on_change) = &self.on_change else {
+            return event::Status::Ignored;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        for request in state.requests.take() {
+            let value = match request {
+                accessibility::Request::Focus => {
+                    tree.children[0]
+                        .state
+                        .downcast_mut::<text_input::State<Renderer::Paragraph>>(
+                        )
+                        .focus();
+
+                    continue;
+                }
+                accessibility::Request::SetText(text) => self.parse(&text),
+                accessibility::Request::SetNumber(number) => {
+                    self.parse(&number.to_string())
+                }
+                accessibility::Request::Increment => self.increment(),
+                accessibility::Request::Decrement => self.decrement(),
+                accessibility::Request::Press => None,
+            };
+
+            if let Some(value) = value {
+                shell.publish(on_change(value));
+
+                self.value = value;
+                state.editing = None;
+                shell.invalidate_layout();
+            }
+        }
+
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+        let decrement_layout = children.next().unwrap();
+        let increment_layout = children.next().unwrap();
+
+        let is_focused = |tree: &Tree| {
+            tree.children[0]
+                .state
+                .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+                .is_focused()
+        };
+
+        let was_focused = is_focused(tree);
+
+        let step = match &event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                if cursor.is_over(decrement_layout.bounds()) {
+                    state.is_pressed = Some(Button::Decrement);
+
+                    Some(self.decrement())
+                } else if cursor.is_over(increment_layout.bounds()) {
+                    state.is_pressed = Some(Button::Increment);
+
+                    Some(self.increment())
+                } else {
+                    None
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                let state = tree.state.downcast_mut::<State>();
+                state.is_pressed = None;
+
+                None
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if was_focused && cursor.is_over(layout.bounds()) =>
+            {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. } => *y,
+                };
+
+                if y > 0.0 {
+                    Some(self.increment())
+                } else if y < 0.0 {
+                    Some(self.decrement())
+                } else {
+                    None
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named_key),
+                ..
+            }) if was_focused => match named_key {
+                key::Named::ArrowUp => Some(self.increment()),
+                key::Named::ArrowDown => Some(self.decrement()),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        if let Some(step) = step {
+            let state = tree.state.downcast_mut::<State>();
+            state.editing = None;
+
+            if let Some(value) = step {
+                shell.publish(on_change(value));
+            }
+
+            shell.invalidate_layout();
+
+            return event::Status::Captured;
+        }
+
+        let state = tree.state.downcast_ref::<State>();
+
+        if let Some(editing) = &state.editing {
+            self.text_input.set_value(Value::new(&editing.text));
+        }
+
+        let mut local_messages = Vec::new();
+        let mut local_shell = Shell::new(&mut local_messages);
+
+        let status = self.text_input.on_event(
+            &mut tree.children[0],
+            event,
+            input_layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        shell.request_input_method(local_shell.input_method());
+
+        let is_now_focused = is_focused(tree);
+        let state = tree.state.downcast_mut::<State>();
+
+        for message in local_messages {
+            let TextInputEvent::TextChanged(text) = message;
+            let value = self.parse(&text);
+
+            if let Some(value) = value {
+                shell.publish(on_change(value));
+            }
+
+            state.editing = Some(Editing {
+                is_valid: value.is_some(),
+                value: value.unwrap_or(self.value).to_string(),
+                text,
+            });
+
+            shell.invalidate_layout();
+        }
+
+        // Show the actual value once the user is done typing
+        if was_focused && !is_now_focused && state.editing.is_some() {
+            state.editing = None;
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+
+        if self.on_change.is_some()
+            && children.any(|button| cursor.is_over(button.bounds()))
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.text_input.mouse_interaction(
+            &tree.children[0],
+            input_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+        let decrement_layout = children.next().unwrap();
+        let increment_layout = children.next().unwrap();
+
+        let is_disabled = self.on_change.is_none();
+        let is_invalid = state
+            .editing
+            .as_ref()
+            .is_some_and(|editing| !editing.is_valid);
+
+        let status = if is_disabled {
+            Status::Disabled
+        } else if is_invalid {
+            Status::Invalid { is_focused }
+        } else if is_focused {
+            Status::Focused
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = theme.style(&self.class, status);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let value = state
+            .editing
+            .as_ref()
+            .map(|editing| Value::new(&editing.text));
+
+        self.text_input.draw(
+            &tree.children[0],
+            renderer,
+            &InputStyle(text_input::Style {
+                background: Background::Color(Color::TRANSPARENT),
+                border: Border::default(),
+                icon: style.value,
+                placeholder: style.placeholder,
+                value: style.value,
+                selection: style.selection,
+            }),
+            input_layout,
+            cursor,
+            value.as_ref(),
+            viewport,
+        );
+
+        let buttons = [
+            (
+                Button::Decrement,
+                decrement_layout,
+                DECREMENT_ICON,
+                self.decrement().is_some(),
+            ),
+            (
+                Button::Increment,
+                increment_layout,
+                INCREMENT_ICON,
+                self.increment().is_some(),
+            ),
+        ];
+
+        for (button, layout, icon, can_step) in buttons {
+            let bounds = layout.bounds();
+            let is_enabled = !is_disabled && can_step;
+
+            if is_enabled
+                && (cursor.is_over(bounds) || state.is_pressed == Some(button))
+            {
+                let inset = style.border.width;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + inset,
+                            y: bounds.y + inset,
+                            width: bounds.width - inset * 2.0,
+                            height: bounds.height - inset * 2.0,
+                        },
+                        border: Border::rounded(style.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.button_background,
+                );
+            }
+
+            renderer.fill_text(
+                Text {
+                    content: String::from(icon),
+                    bounds: bounds.size(),
+                    size: self.size.unwrap_or_else(|| renderer.default_size()),
+                    line_height: self.line_height,
+                    font: self.font.unwrap_or_else(|| renderer.default_font()),
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                },
+                bounds.center(),
+                if is_enabled {
+                    style.button_icon
+                } else {
+                    style.placeholder
+                },
+                bounds,
+            );
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<NumberInput<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Number,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        number_input: NumberInput<'a, T, Message, Theme, Renderer>,
+    ) -> Self {
+        Self::new(number_input)
+    }
+}
+
+/// The icon of the button that decrements the value of a [`NumberInput`].
+const DECREMENT_ICON: char = '−';
+
+/// The icon of the button that increments the value of a [`NumberInput`].
+const INCREMENT_ICON: char = '+';
+
+/// The [`text_input::Catalog`] used to draw the text of a [`NumberInput`]
+/// with its current [`Style`].
+struct InputStyle(text_input::Style);
+
+impl text_input::Catalog for InputStyle {
+    type Class<'a> = ();
+
+    fn default<'a>() -> Self::Class<'a> {}
+
+    fn style(
+        &self,
+        _class: &Self::Class<'_>,
+        _status: text_input::Status,
+    ) -> text_input::Style {
+        self.0
+    }
+}
+
+/// The possible status of a [`NumberInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`NumberInput`] can be interacted with.
+    Active,
+    /// The [`NumberInput`] is being hovered.
+    Hovered,
+    /// The [`NumberInput`] is focused.
+    Focused,
+    /// The [`NumberInput`] cannot be interacted with.
+    Disabled,
+    /// The text of the [`NumberInput`] is not a valid number within bounds.
+    Invalid {
+        /// Whether the [`NumberInput`] is focused.
+        is_focused: bool,
+    },
+}
+
+/// The appearance of a number input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the number input.
+    pub background: Background,
+    /// The [`Border`] of the number input.
+    pub border: Border,
+    /// The [`Color`] of the placeholder of the number input.
+    pub placeholder: Color,
+    /// The [`Color`] of the value of the number input.
+    pub value: Color,
+    /// The [`Color`] of the selection of the number input.
+    pub selection: Color,
+    /// The [`Background`] of a hovered step button of the number input.
+    pub button_background: Background,
+    /// The [`Color`] of the icons of the step buttons of the number input.
+    pub button_icon: Color,
+}
+
+/// The theme catalog of a [`NumberInput`].
+pub trait Catalog: Sized {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`NumberInput`].
+///
+/// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`NumberInput`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        background: Background::Color(palette.background.base.color),
+        border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        placeholder: palette.background.strong.color,
+        value: palette.background.base.text,
+        selection: palette.primary.weak.color,
+        button_background: Background::Color(palette.background.weak.color),
+        button_icon: palette.background.base.text,
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered => Style {
+            border: Border {
+                color: palette.background.base.text,
+                ..active.border
+            },
+            ..active
+        },
+        Status::Focused => Style {
+            border: Border {
+                color: palette.primary.strong.color,
+                ..active.border
+            },
+            ..active
+        },
+        Status::Disabled => Style {
+            background: Background::Color(palette.background.weak.color),
+            value: active.placeholder,
+            button_icon: active.placeholder,
+            ..active
+        },
+        Status::Invalid { is_focused } => Style {
+            border: Border {
+                color: if is_focused {
+                    palette.danger.strong.color
+                } else {
+                    palette.danger.base.color
+                },
+                ..active.border
+            },
+            selection: palette.danger.weak.color,
+            ..active
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_test::{selector, Simulator};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        Changed(i32),
+    }
+
+    fn number_input<T: Number>(
+        value: T,
+    ) -> NumberInput<'static, T, (), Theme, ()> {
+        NumberInput::new(value)
+    }
+
+    fn simulator(
+        input: NumberInput<'static, i32, Message, Theme, ()>,
+    ) -> Simulator<'static, Message, Theme, ()> {
+        Simulator::new(
+            input
+                .id(text_input::Id::new("number"))
+                .on_change(Message::Changed),
+        )
+    }
+
+    fn focus(ui: &mut Simulator<'static, Message, Theme, ()>) {
+        assert!(ui
+            .click(selector::id(text_input::Id::new("number")))
+            .is_ok());
+        let _ = ui.tap_key(key::Named::End);
+    }
+
+    fn scroll(
+        ui: &mut Simulator<'static, Message, Theme, ()>,
+        y: f32,
+    ) -> event::Status {
+        ui.simulate([Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Lines { x: 0.0, y },
+        })])[0]
+    }
+
+    #[test]
+    fn parsing_respects_bounds() {
+        let input = number_input(0).min(-5).max(10);
+
+        assert_eq!(input.parse("7"), Some(7));
+        assert_eq!(input.parse(" -5 "), Some(-5));
+        assert_eq!(input.parse("11"), None);
+        assert_eq!(input.parse("-6"), None);
+        assert_eq!(input.parse("7.5"), None);
+        assert_eq!(input.parse("seven"), None);
+        assert_eq!(input.parse(""), None);
+
+        assert_eq!(number_input(0.0).parse("0.25"), Some(0.25));
+    }
+
+    #[test]
+    fn stepping_is_clamped_to_bounds() {
+        assert_eq!(number_input(8).max(10).step(5).increment(), Some(10));
+        assert_eq!(number_input(10).max(10).increment(), None);
+        assert_eq!(number_input(-3).min(-5).step(5).decrement(), Some(-5));
+        assert_eq!(number_input(-5).min(-5).decrement(), None);
+        assert_eq!(number_input(3).step(2).increment(), Some(5));
+        assert_eq!(number_input(3).step(2).decrement(), Some(1));
+    }
+
+    #[test]
+    fn stepping_does_not_overflow() {
+        assert_eq!(number_input(250_u8).step(10).increment(), Some(u8::MAX));
+        assert_eq!(number_input(u8::MAX).increment(), None);
+        assert_eq!(number_input(0_u8).decrement(), None);
+        assert_eq!(number_input(-125_i8).step(10).decrement(), Some(i8::MIN));
+        assert_eq!(number_input(i32::MAX - 1).increment(), Some(i32::MAX));
+    }
+
+    #[test]
+    fn typing_publishes_valid_numbers_only() {
+        let mut ui = simulator(NumberInput::new(1).max(100));
+        focus(&mut ui);
+
+        let _ = ui.typewrite("2");
+        let _ = ui.typewrite("0");
+        let _ = ui.typewrite("x");
+
+        assert_eq!(ui.messages(), [Message::Changed(12)]);
+    }
+
+    #[test]
+    fn arrow_keys_step_when_focused() {
+        let mut ui = simulator(NumberInput::new(5).step(2));
+
+        let _ = ui.tap_key(key::Named::ArrowUp);
+        assert!(ui.messages().is_empty());
+
+        focus(&mut ui);
+
+        let _ = ui.tap_key(key::Named::ArrowUp);
+        let _ = ui.tap_key(key::Named::ArrowDown);
+
+        assert_eq!(ui.messages(), [Message::Changed(7), Message::Changed(3)]);
+    }
+
+    #[test]
+    fn wheel_steps_only_when_focused() {
+        let mut ui = simulator(NumberInput::new(5));
+
+        let center = ui
+            .find(selector::id(text_input::Id::new("number")))
+            .unwrap()
+            .bounds
+            .center();
+
+        ui.point_at(center);
+
+        assert_eq!(scroll(&mut ui, 1.0), event::Status::Ignored);
+        assert!(ui.messages().is_empty());
+
+        focus(&mut ui);
+
+        assert_eq!(scroll(&mut ui, 1.0), event::Status::Captured);
+        assert_eq!(scroll(&mut ui, -1.0), event::Status::Captured);
+
+        assert_eq!(ui.messages(), [Message::Changed(6), Message::Changed(4)]);
+    }
+}
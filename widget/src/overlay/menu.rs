@@ -15,6 +15,12 @@ use crate::core::{
 use crate::core::{Element, Shell, Widget};
 use crate::scrollable::{self, Scrollable};
 
+pub(crate) mod cascade;
+
+mod item;
+
+pub use item::Item;
+
 /// A list of selectable options.
 #[allow(missing_debug_implementations)]
 pub struct Menu<
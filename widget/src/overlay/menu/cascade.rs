@@ -0,0 +1,840 @@
+//! Display a menu of [`Item`]s with nested submenus.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::{
+    Border, Clipboard, Padding, Pixels, Point, Rectangle, Shell, Size,
+};
+use crate::overlay::menu::item::Kind;
+use crate::overlay::menu::{Catalog, Item};
+
+/// The height of a separator [`Item`].
+const SEPARATOR_HEIGHT: f32 = 9.0;
+
+/// The local state of a [`Cascade`].
+///
+/// It keeps track of the open levels of the [`Cascade`] and the item
+/// selected in each of them.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct State {
+    path: Vec<Option<usize>>,
+}
+
+impl State {
+    /// Opens the root menu of the [`Cascade`] with the given item selected.
+    pub fn open(&mut self, selected: Option<usize>) {
+        self.path = vec![selected];
+    }
+
+    /// Closes every menu of the [`Cascade`].
+    pub fn close(&mut self) {
+        self.path.clear();
+    }
+
+    /// Returns whether the [`Cascade`] is open.
+    pub fn is_open(&self) -> bool {
+        !self.path.is_empty()
+    }
+
+    /// Selects the item at the given index of the given level, closing any
+    /// deeper levels.
+    ///
+    /// Returns true if the open levels have changed.
+    fn select<Message>(
+        &mut self,
+        level: usize,
+        index: Option<usize>,
+        items: &[Item<Message>],
+    ) -> bool {
+        let previous = self.path.clone();
+
+        self.path.truncate(level + 1);
+        self.path[level] = index;
+
+        if index
+            .and_then(|index| items.get(index))
+            .is_some_and(|item| {
+                item.is_enabled() && item.submenu_items().is_some()
+            })
+        {
+            self.path.push(None);
+        }
+
+        // Changing the selection of the deepest level does not change the
+        // layout, unless a submenu opens or closes
+        self.path.len() != previous.len()
+            || self.path[..self.path.len() - 1]
+                != previous[..previous.len() - 1]
+    }
+}
+
+/// A menu of [`Item`]s whose submenus open next to it.
+pub(crate) struct Cascade<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    state: &'a mut State,
+    items: &'a [Item<Message>],
+    position: Point,
+    target_height: f32,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    font: Option<Renderer::Font>,
+    class: &'a <Theme as Catalog>::Class<'b>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Cascade<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+    'b: 'a,
+{
+    /// Creates a new [`Cascade`] with the given [`State`] and items.
+    pub fn new(
+        state: &'a mut State,
+        items: &'a [Item<Message>],
+        class: &'a <Theme as Catalog>::Class<'b>,
+    ) -> Self {
+        Self {
+            state,
+            items,
+            position: Point::ORIGIN,
+            target_height: 0.0,
+            padding: Padding::from([5, 10]),
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            font: None,
+            class,
+        }
+    }
+
+    /// Sets the [`Padding`] of the items of the [`Cascade`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Cascade`], if `Some`.
+    pub fn text_size(mut self, text_size: Option<Pixels>) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`Cascade`], if `Some`.
+    pub fn font(mut self, font: Option<Renderer::Font>) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Turns the [`Cascade`] into an overlay [`overlay::Element`] at the
+    /// given target position.
+    ///
+    /// The `target_height` will be used to display the root menu either on
+    /// top of the target or under it, depending on the available space.
+    pub fn overlay(
+        mut self,
+        position: Point,
+        target_height: f32,
+    ) -> overlay::Element<'a, Message, Theme, Renderer> {
+        self.position = position;
+        self.target_height = target_height;
+
+        overlay::Element::new(Box::new(self))
+    }
+
+    /// Returns the items of every open level.
+    fn levels(&self) -> Vec<&'a [Item<Message>]> {
+        let mut levels = Vec::with_capacity(self.state.path.len());
+        let mut items = self.items;
+
+        for selected in &self.state.path {
+            levels.push(items);
+
+            match selected
+                .and_then(|index| items.get(index))
+                .and_then(Item::submenu_items)
+            {
+                Some(submenu) => items = submenu,
+                None => break,
+            }
+        }
+
+        levels.truncate(self.state.path.len());
+        levels
+    }
+
+    fn text_size_and_row_height(&self, renderer: &Renderer) -> (Pixels, f32) {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let row_height =
+            f32::from(self.text_line_height.to_absolute(text_size))
+                + self.padding.vertical();
+
+        (text_size, row_height)
+    }
+
+    /// Returns the level and index of the item under the cursor, if any.
+    fn hovered(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<(usize, Option<usize>)> {
+        let position = cursor.position()?;
+
+        // Submenus are drawn on top of their parents
+        layout
+            .children()
+            .enumerate()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .find(|(_, level)| level.bounds().contains(position))
+            .map(|(level, layout)| {
+                (
+                    level,
+                    layout
+                        .children()
+                        .position(|row| row.bounds().contains(position)),
+                )
+            })
+    }
+
+    fn activate(
+        &mut self,
+        items: &[Item<Message>],
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        match items[index].kind() {
+            Kind::Action {
+                on_select: Some(on_select),
+                ..
+            } => {
+                shell.publish(on_select.clone());
+                self.state.close();
+            }
+            Kind::Submenu { items, .. } => {
+                self.state.path.push(first_enabled(items));
+                shell.invalidate_layout();
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Cascade<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+    'b: 'a,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let (text_size, row_height) = self.text_size_and_row_height(renderer);
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let measure = |content: &str| {
+            Renderer::Paragraph::with_text(Text {
+                content,
+                bounds: Size::INFINITY,
+                size: text_size,
+                line_height: self.text_line_height,
+                font,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: text::Shaping::Advanced,
+            })
+            .min_width()
+        };
+
+        let mut levels: Vec<layout::Node> = Vec::new();
+        let mut parent: Option<(Rectangle, f32)> = None;
+
+        for (level, items) in self.levels().into_iter().enumerate() {
+            let columns = Columns::of(items, text_size);
+
+            let (label_width, shortcut_width) = items.iter().fold(
+                (0.0f32, 0.0f32),
+                |(label_width, shortcut_width), item| match item.kind() {
+                    Kind::Action {
+                        label, shortcut, ..
+                    } => (
+                        label_width.max(measure(label)),
+                        shortcut
+                            .as_deref()
+                            .map_or(shortcut_width, |shortcut| {
+                                shortcut_width.max(measure(shortcut))
+                            }),
+                    ),
+                    Kind::Submenu { label, .. } => {
+                        (label_width.max(measure(label)), shortcut_width)
+                    }
+                    Kind::Separator => (label_width, shortcut_width),
+                },
+            );
+
+            let width = (self.padding.horizontal()
+                + columns.check
+                + label_width
+                + if shortcut_width > 0.0 {
+                    columns.gap + shortcut_width
+                } else {
+                    0.0
+                }
+                + columns.arrow)
+                .ceil()
+                .min(bounds.width);
+
+            let mut y = 0.0;
+
+            let rows: Vec<_> = items
+                .iter()
+                .map(|item| {
+                    let height = match item.kind() {
+                        Kind::Separator => SEPARATOR_HEIGHT,
+                        _ => row_height,
+                    };
+
+                    let row = layout::Node::new(Size::new(width, height))
+                        .move_to(Point::new(0.0, y));
+
+                    y += height;
+
+                    row
+                })
+                .collect();
+
+            let height = y;
+
+            let position = match parent {
+                None => {
+                    let below = self.position.y + self.target_height;
+                    let space_below = bounds.height - below;
+                    let space_above = self.position.y;
+
+                    let y = if height <= space_below
+                        || space_below >= space_above
+                    {
+                        below.min(bounds.height - height)
+                    } else {
+                        self.position.y - height
+                    };
+
+                    Point::new(self.position.x.min(bounds.width - width), y)
+                }
+                Some((parent, row_y)) => {
+                    let right = parent.x + parent.width;
+
+                    let x = if right + width <= bounds.width {
+                        right
+                    } else {
+                        parent.x - width
+                    };
+
+                    Point::new(x, row_y.min(bounds.height - height))
+                }
+            };
+
+            let position = Point::new(position.x.max(0.0), position.y.max(0.0));
+            let node =
+                layout::Node::with_children(Size::new(width, height), rows)
+                    .move_to(position);
+
+            parent = self.state.path[level].and_then(|selected| {
+                let row = node.children().get(selected)?;
+
+                Some((node.bounds(), position.y + row.bounds().y))
+            });
+
+            levels.push(node);
+        }
+
+        layout::Node::with_children(bounds, levels)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let levels = self.levels();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some((level, index)) = self.hovered(layout, cursor) {
+                    let items = levels[level];
+                    let index =
+                        index.filter(|&index| items[index].is_enabled());
+
+                    // Moving away from a submenu keeps it open
+                    if index.is_some() && self.state.select(level, index, items)
+                    {
+                        shell.invalidate_layout();
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some((level, index)) = self.hovered(layout, cursor) {
+                    let items = levels[level];
+
+                    if let Some(index) =
+                        index.filter(|&index| items[index].is_enabled())
+                    {
+                        if self.state.select(level, Some(index), items) {
+                            shell.invalidate_layout();
+                        }
+
+                        if items[index].submenu_items().is_none() {
+                            self.activate(items, index, shell);
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(_))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+                if self.hovered(layout, cursor).is_some() =>
+            {
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) => {
+                let Some(&items) =
+                    levels.last().filter(|items| !items.is_empty())
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let level = levels.len() - 1;
+                let selected = self.state.path[level];
+
+                match named {
+                    key::Named::ArrowDown | key::Named::ArrowUp => {
+                        let next = if named == key::Named::ArrowDown {
+                            cycle(items, selected, items.len() - 1, 1)
+                        } else {
+                            cycle(items, selected, 0, items.len() - 1)
+                        };
+
+                        self.state.path[level] = next;
+                    }
+                    key::Named::ArrowRight => {
+                        match selected.and_then(|index| {
+                            items[index].is_enabled().then_some(index)
+                        }) {
+                            Some(index)
+                                if items[index].submenu_items().is_some() =>
+                            {
+                                self.activate(items, index, shell);
+                            }
+                            _ => return event::Status::Ignored,
+                        }
+                    }
+                    key::Named::ArrowLeft => {
+                        if level == 0 {
+                            return event::Status::Ignored;
+                        }
+
+                        let _ = self.state.path.pop();
+                        shell.invalidate_layout();
+                    }
+                    key::Named::Enter | key::Named::Space => {
+                        if let Some(index) =
+                            selected.filter(|&index| items[index].is_enabled())
+                        {
+                            self.activate(items, index, shell);
+                        }
+                    }
+                    key::Named::Escape => {
+                        if level == 0 {
+                            self.state.close();
+                        } else {
+                            let _ = self.state.path.pop();
+                        }
+
+                        shell.invalidate_layout();
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let levels = self.levels();
+
+        match self.hovered(layout, cursor) {
+            Some((level, Some(index))) if levels[level][index].is_enabled() => {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = Catalog::style(theme, self.class);
+        let (text_size, row_height) = self.text_size_and_row_height(renderer);
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let text = |content: String, font, alignment| Text {
+            content,
+            bounds: Size::new(f32::INFINITY, row_height),
+            size: text_size,
+            line_height: self.text_line_height,
+            font,
+            horizontal_alignment: alignment,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+        };
+
+        for ((items, selected), layout) in self
+            .levels()
+            .into_iter()
+            .zip(&self.state.path)
+            .zip(layout.children())
+        {
+            let bounds = layout.bounds();
+            let columns = Columns::of(items, text_size);
+
+            // Every level needs its own layer, so submenus are drawn on
+            // top of the text of their parents
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.border,
+                        ..renderer::Quad::default()
+                    },
+                    style.background,
+                );
+
+                for (index, (item, row)) in
+                    items.iter().zip(layout.children()).enumerate()
+                {
+                    let row = row.bounds();
+
+                    if let Kind::Separator = item.kind() {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: row.x + self.padding.left,
+                                    y: row.center_y().floor(),
+                                    width: row.width
+                                        - self.padding.horizontal(),
+                                    height: 1.0,
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            style.border.color,
+                        );
+
+                        continue;
+                    }
+
+                    let is_selected =
+                        *selected == Some(index) && item.is_enabled();
+
+                    if is_selected {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: row.x + style.border.width,
+                                    width: row.width - style.border.width * 2.0,
+                                    ..row
+                                },
+                                border: Border::rounded(style.border.radius),
+                                ..renderer::Quad::default()
+                            },
+                            style.selected_background,
+                        );
+                    }
+
+                    let color = if is_selected {
+                        style.selected_text_color
+                    } else if item.is_enabled() {
+                        style.text_color
+                    } else {
+                        style.text_color.scale_alpha(0.5)
+                    };
+
+                    let left = row.x + self.padding.left;
+                    let right = row.x + row.width - self.padding.right;
+
+                    let label = match item.kind() {
+                        Kind::Action {
+                            label,
+                            shortcut,
+                            checked,
+                            ..
+                        } => {
+                            if *checked == Some(true) {
+                                renderer.fill_text(
+                                    text(
+                                        Renderer::CHECKMARK_ICON.to_string(),
+                                        Renderer::ICON_FONT,
+                                        alignment::Horizontal::Left,
+                                    ),
+                                    Point::new(left, row.center_y()),
+                                    color,
+                                    bounds,
+                                );
+                            }
+
+                            if let Some(shortcut) = shortcut {
+                                renderer.fill_text(
+                                    text(
+                                        shortcut.clone(),
+                                        font,
+                                        alignment::Horizontal::Right,
+                                    ),
+                                    Point::new(
+                                        right - columns.arrow,
+                                        row.center_y(),
+                                    ),
+                                    color.scale_alpha(0.7),
+                                    bounds,
+                                );
+                            }
+
+                            label
+                        }
+                        Kind::Submenu { label, .. } => {
+                            renderer.fill_text(
+                                text(
+                                    String::from("▸"),
+                                    font,
+                                    alignment::Horizontal::Right,
+                                ),
+                                Point::new(right, row.center_y()),
+                                color,
+                                bounds,
+                            );
+
+                            label
+                        }
+                        Kind::Separator => continue,
+                    };
+
+                    renderer.fill_text(
+                        text(label.clone(), font, alignment::Horizontal::Left),
+                        Point::new(left + columns.check, row.center_y()),
+                        color,
+                        bounds,
+                    );
+                }
+            });
+        }
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout
+            .children()
+            .any(|level| level.bounds().contains(cursor_position))
+    }
+}
+
+/// The widths of the optional columns of a level of a [`Cascade`].
+struct Columns {
+    check: f32,
+    gap: f32,
+    arrow: f32,
+}
+
+impl Columns {
+    fn of<Message>(items: &[Item<Message>], text_size: Pixels) -> Self {
+        let text_size = text_size.0;
+
+        let has_checks = items.iter().any(|item| {
+            matches!(
+                item.kind(),
+                Kind::Action {
+                    checked: Some(_),
+                    ..
+                }
+            )
+        });
+
+        let has_submenus =
+            items.iter().any(|item| item.submenu_items().is_some());
+
+        Self {
+            check: if has_checks { text_size * 1.5 } else { 0.0 },
+            gap: text_size * 2.0,
+            arrow: if has_submenus { text_size * 1.5 } else { 0.0 },
+        }
+    }
+}
+
+/// Returns the index of the first enabled item, if any.
+pub(crate) fn first_enabled<Message>(items: &[Item<Message>]) -> Option<usize> {
+    items.iter().position(Item::is_enabled)
+}
+
+/// Returns the index of the next enabled item after the selected one,
+/// stepping by `step` and wrapping around.
+///
+/// If nothing is selected, the search starts after `start`.
+fn cycle<Message>(
+    items: &[Item<Message>],
+    selected: Option<usize>,
+    start: usize,
+    step: usize,
+) -> Option<usize> {
+    let current = selected.unwrap_or(start);
+
+    (1..=items.len())
+        .map(|offset| (current + offset * step) % items.len())
+        .find(|&index| items[index].is_enabled())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items() -> Vec<Item<&'static str>> {
+        vec![
+            Item::new("Open").on_select("open"),
+            Item::separator(),
+            Item::new("Save"),
+            Item::submenu("Recent", [Item::new("a.txt").on_select("a")]),
+            Item::submenu("Empty", []),
+            Item::new("Quit").on_select("quit"),
+        ]
+    }
+
+    fn down(
+        items: &[Item<&'static str>],
+        selected: Option<usize>,
+    ) -> Option<usize> {
+        cycle(items, selected, items.len() - 1, 1)
+    }
+
+    fn up(
+        items: &[Item<&'static str>],
+        selected: Option<usize>,
+    ) -> Option<usize> {
+        cycle(items, selected, 0, items.len() - 1)
+    }
+
+    #[test]
+    fn cycle_skips_separators_and_disabled_items() {
+        let items = items();
+
+        assert_eq!(down(&items, None), Some(0));
+        assert_eq!(down(&items, Some(0)), Some(3));
+        assert_eq!(down(&items, Some(3)), Some(5));
+        assert_eq!(down(&items, Some(5)), Some(0));
+
+        assert_eq!(up(&items, None), Some(5));
+        assert_eq!(up(&items, Some(5)), Some(3));
+        assert_eq!(up(&items, Some(3)), Some(0));
+        assert_eq!(up(&items, Some(0)), Some(5));
+    }
+
+    #[test]
+    fn cycle_without_enabled_items() {
+        let items = vec![Item::separator(), Item::new("Save")];
+
+        assert_eq!(down(&items, None), None);
+        assert_eq!(up(&items, Some(1)), None);
+        assert_eq!(cycle::<()>(&[], None, 0, 1), None);
+
+        assert_eq!(first_enabled(&items), None);
+        assert_eq!(first_enabled(&self::items()[1..]), Some(2));
+    }
+
+    #[test]
+    fn selecting_a_submenu_opens_it() {
+        let items = items();
+        let mut state = State::default();
+
+        state.open(None);
+        assert_eq!(state.path, [None]);
+
+        assert!(state.select(0, Some(3), &items));
+        assert_eq!(state.path, [Some(3), None]);
+
+        // Moving within the submenu does not change the open levels
+        let recent = items[3].submenu_items().unwrap();
+        assert!(!state.select(1, Some(0), recent));
+        assert_eq!(state.path, [Some(3), Some(0)]);
+
+        // Selecting another item of the root closes the submenu
+        assert!(state.select(0, Some(0), &items));
+        assert_eq!(state.path, [Some(0)]);
+
+        assert!(!state.select(0, Some(5), &items));
+        assert_eq!(state.path, [Some(5)]);
+    }
+
+    #[test]
+    fn selecting_a_disabled_submenu_does_not_open_it() {
+        let items = items();
+        let mut state = State::default();
+
+        state.open(Some(0));
+
+        assert!(!state.select(0, Some(4), &items));
+        assert_eq!(state.path, [Some(4)]);
+    }
+
+    #[test]
+    fn closing_a_cascade() {
+        let items = items();
+        let mut state = State::default();
+
+        assert!(!state.is_open());
+
+        state.open(None);
+        let _ = state.select(0, Some(3), &items);
+        assert!(state.is_open());
+
+        state.close();
+        assert!(!state.is_open());
+    }
+}
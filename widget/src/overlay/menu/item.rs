@@ -0,0 +1,127 @@
+/// An entry of a menu.
+///
+/// An [`Item`] can be an action, a separator, or a submenu that opens
+/// when hovered.
+#[derive(Debug, Clone)]
+pub struct Item<Message> {
+    kind: Kind<Message>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Kind<Message> {
+    Action {
+        label: String,
+        shortcut: Option<String>,
+        checked: Option<bool>,
+        on_select: Option<Message>,
+    },
+    Separator,
+    Submenu {
+        label: String,
+        items: Vec<Item<Message>>,
+    },
+}
+
+impl<Message> Item<Message> {
+    /// Creates a new action [`Item`] with the given label.
+    ///
+    /// The [`Item`] will be disabled until [`Item::on_select`] is called.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            kind: Kind::Action {
+                label: label.into(),
+                shortcut: None,
+                checked: None,
+                on_select: None,
+            },
+        }
+    }
+
+    /// Creates a new [`Item`] separating groups of items with a line.
+    pub fn separator() -> Self {
+        Self {
+            kind: Kind::Separator,
+        }
+    }
+
+    /// Creates a new [`Item`] that opens a nested menu with the given items.
+    pub fn submenu(
+        label: impl Into<String>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        Self {
+            kind: Kind::Submenu {
+                label: label.into(),
+                items: items.into_iter().collect(),
+            },
+        }
+    }
+
+    /// Sets the message that will be produced when the [`Item`] is selected.
+    ///
+    /// This has no effect on separators and submenus.
+    pub fn on_select(mut self, message: Message) -> Self {
+        if let Kind::Action { on_select, .. } = &mut self.kind {
+            *on_select = Some(message);
+        }
+
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Item`] is selected,
+    /// if `Some`.
+    ///
+    /// If `None`, the [`Item`] will be disabled.
+    pub fn on_select_maybe(mut self, message: Option<Message>) -> Self {
+        if let Kind::Action { on_select, .. } = &mut self.kind {
+            *on_select = message;
+        }
+
+        self
+    }
+
+    /// Sets the keyboard shortcut displayed next to the label of the
+    /// [`Item`]; like `"Ctrl+S"`.
+    ///
+    /// The shortcut is only displayed; listening to it is up to you.
+    pub fn shortcut(mut self, text: impl Into<String>) -> Self {
+        if let Kind::Action { shortcut, .. } = &mut self.kind {
+            *shortcut = Some(text.into());
+        }
+
+        self
+    }
+
+    /// Makes the [`Item`] checkable and sets whether it is checked.
+    ///
+    /// A checked [`Item`] displays a checkmark next to its label.
+    pub fn checked(mut self, is_checked: bool) -> Self {
+        if let Kind::Action { checked, .. } = &mut self.kind {
+            *checked = Some(is_checked);
+        }
+
+        self
+    }
+
+    pub(crate) fn kind(&self) -> &Kind<Message> {
+        &self.kind
+    }
+
+    /// Returns the items of the nested menu of the [`Item`], if it is a
+    /// submenu.
+    pub(crate) fn submenu_items(&self) -> Option<&[Item<Message>]> {
+        match &self.kind {
+            Kind::Submenu { items, .. } => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the [`Item`] can be selected.
+    pub(crate) fn is_enabled(&self) -> bool {
+        match &self.kind {
+            Kind::Action { on_select, .. } => on_select.is_some(),
+            Kind::Separator => false,
+            Kind::Submenu { items, .. } => !items.is_empty(),
+        }
+    }
+}
@@ -8,7 +8,9 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text::{self, Paragraph as _, Text};
 use crate::core::touch;
+use crate::core::widget::operation::accessibility;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
 use crate::core::{
     Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
     Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
@@ -301,6 +303,35 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let mut node = accessibility::Node::new(accessibility::Role::ComboBox)
+            .toggled(state.is_open)
+            .action(accessibility::Action::Press);
+
+        if let Some(placeholder) = &self.placeholder {
+            node = node.label(placeholder.clone());
+        }
+
+        if let Some(selected) = &self.selected {
+            node = node.value(selected.borrow().to_string());
+        }
+
+        operation.accessibility(
+            None,
+            layout.bounds(),
+            &node,
+            Some(&mut state.requests),
+        );
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -312,6 +343,35 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        for request in state.requests.take() {
+            if request != accessibility::Request::Press {
+                continue;
+            }
+
+            if state.is_open {
+                state.is_open = false;
+
+                if let Some(on_close) = &self.on_close {
+                    shell.publish(on_close.clone());
+                }
+            } else {
+                let selected = self.selected.as_ref().map(Borrow::borrow);
+
+                state.is_open = true;
+                state.hovered_option = self
+                    .options
+                    .borrow()
+                    .iter()
+                    .position(|option| Some(option) == selected);
+
+                if let Some(on_open) = &self.on_open {
+                    shell.publish(on_open.clone());
+                }
+            }
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -624,6 +684,7 @@ struct State<P: text::Paragraph> {
     hovered_option: Option<usize>,
     options: Vec<P>,
     placeholder: P,
+    requests: accessibility::Requests,
 }
 
 impl<P: text::Paragraph> State<P> {
@@ -636,6 +697,7 @@ impl<P: text::Paragraph> State<P> {
             hovered_option: Option::default(),
             options: Vec::new(),
             placeholder: P::default(),
+            requests: accessibility::Requests::default(),
         }
     }
 }
@@ -0,0 +1,172 @@
+//! Display the text being composed with an input method.
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Paragraph as _, Text};
+use crate::core::{Background, Color, Pixels, Point, Rectangle, Size};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The text being composed with an input method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Preedit {
+    content: String,
+    cursor: Option<(usize, usize)>,
+}
+
+impl Preedit {
+    /// Updates the current [`Preedit`] of a text widget with the given
+    /// input method event.
+    ///
+    /// Returns the text that should be inserted, if the composition was
+    /// committed.
+    pub fn update(
+        preedit: &mut Option<Self>,
+        event: keyboard::Ime,
+    ) -> Option<String> {
+        match event {
+            keyboard::Ime::Preedit { content, cursor } => {
+                *preedit =
+                    (!content.is_empty()).then_some(Self { content, cursor });
+
+                None
+            }
+            keyboard::Ime::Commit(text) => {
+                *preedit = None;
+
+                Some(text)
+            }
+            keyboard::Ime::Enabled | keyboard::Ime::Disabled => {
+                *preedit = None;
+
+                None
+            }
+        }
+    }
+
+    /// Returns the [`Text`] of the [`Preedit`] with the given font, size,
+    /// and line height.
+    pub fn text<Font>(
+        &self,
+        font: Font,
+        size: Pixels,
+        line_height: LineHeight,
+    ) -> Text<String, Font> {
+        Text {
+            content: self.content.clone(),
+            bounds: Size::new(
+                f32::INFINITY,
+                line_height.to_absolute(size).into(),
+            ),
+            size,
+            line_height,
+            font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+        }
+    }
+
+    /// Draws the [`Preedit`] as the given [`Text`] at the given position,
+    /// shifting it left if needed to fit the given bounds.
+    ///
+    /// The [`Preedit`] is drawn underlined in its own layer, on top of the
+    /// text behind it.
+    pub fn draw<Renderer: text::Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        text: Text<String, Renderer::Font>,
+        position: Point,
+        bounds: Rectangle,
+        color: Color,
+        background: Background,
+    ) {
+        let paragraph = Renderer::Paragraph::with_text(Text {
+            content: &text.content,
+            bounds: text.bounds,
+            size: text.size,
+            line_height: text.line_height,
+            font: text.font,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+            shaping: text.shaping,
+        });
+
+        let size = paragraph.min_bounds();
+
+        let area = Rectangle {
+            x: position
+                .x
+                .min(bounds.x + bounds.width - size.width)
+                .max(bounds.x),
+            y: position.y,
+            width: size.width,
+            height: size.height,
+        };
+
+        let offset = |index: usize| {
+            let graphemes = self
+                .content
+                .get(..index)
+                .unwrap_or(&self.content)
+                .graphemes(true)
+                .count();
+
+            paragraph
+                .grapheme_position(0, graphemes)
+                .map_or(size.width, |position| position.x)
+        };
+
+        renderer.with_layer(area, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: area,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+
+            renderer.fill_text(text, area.position(), color, area);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: area.y + area.height - 1.0,
+                        height: 1.0,
+                        ..area
+                    },
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+
+            if let Some((start, end)) = self.cursor {
+                let left = offset(start.min(end));
+                let right = offset(start.max(end));
+
+                let cursor = if start == end {
+                    Rectangle {
+                        x: (area.x + left).floor(),
+                        width: 1.0,
+                        ..area
+                    }
+                } else {
+                    Rectangle {
+                        x: area.x + left,
+                        y: area.y + area.height - 2.0,
+                        width: right - left,
+                        height: 2.0,
+                    }
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: cursor,
+                        ..renderer::Quad::default()
+                    },
+                    color,
+                );
+            }
+        });
+    }
+}
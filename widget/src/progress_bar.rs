@@ -2,7 +2,8 @@
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::widget::Tree;
+use crate::core::widget::operation::accessibility;
+use crate::core::widget::{Operation, Tree};
 use crate::core::{
     self, Background, Border, Element, Layout, Length, Rectangle, Size, Theme,
     Widget,
@@ -113,6 +114,29 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let (start, end) = self.range.clone().into_inner();
+
+        operation.accessibility(
+            None,
+            layout.bounds(),
+            &accessibility::Node::new(accessibility::Role::ProgressIndicator)
+                .value(accessibility::Value::Number {
+                    value: f64::from(self.value),
+                    min: f64::from(start),
+                    max: f64::from(end),
+                    step: None,
+                }),
+            None,
+        );
+    }
+
     fn draw(
         &self,
         _state: &Tree,
@@ -9,11 +9,11 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::touch;
 use crate::core::widget;
-use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::operation::{self, accessibility, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    self, Background, Border, Clipboard, Color, Element, Layout, Length,
-    Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+    self, Background, Border, Clipboard, Color, Element, InputMethod, Layout,
+    Length, Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
 };
 use crate::runtime::Command;
 
@@ -305,6 +305,13 @@ where
         let translation =
             state.translation(self.direction, bounds, content_bounds);
 
+        operation.accessibility(
+            self.id.as_ref().map(|id| &id.0),
+            bounds,
+            &accessibility::Node::new(accessibility::Role::ScrollView),
+            None,
+        );
+
         operation.scrollable(
             state,
             self.id.as_ref().map(|id| &id.0),
@@ -512,7 +519,9 @@ where
             let translation =
                 state.translation(self.direction, bounds, content_bounds);
 
-            self.content.as_widget_mut().on_event(
+            let had_input_method = shell.input_method().is_enabled();
+
+            let event_status = self.content.as_widget_mut().on_event(
                 &mut tree.children[0],
                 event.clone(),
                 content,
@@ -525,7 +534,19 @@ where
                     x: bounds.x + translation.x,
                     ..bounds
                 },
-            )
+            );
+
+            // The input method cursor of the content must be placed
+            // relative to the window
+            if !had_input_method {
+                if let InputMethod::Enabled { cursor } =
+                    shell.input_method_mut()
+                {
+                    *cursor = *cursor - translation;
+                }
+            }
+
+            event_status
         };
 
         if matches!(
@@ -7,7 +7,9 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::touch;
+use crate::core::widget::operation::accessibility;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
 use crate::core::{
     self, Border, Clipboard, Color, Element, Layout, Length, Pixels, Point,
     Rectangle, Shell, Size, Theme, Widget,
@@ -198,6 +200,33 @@ where
         layout::atomic(limits, self.width, self.height)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let (start, end) = self.range.clone().into_inner();
+
+        operation.accessibility(
+            None,
+            layout.bounds(),
+            &accessibility::Node::new(accessibility::Role::Slider)
+                .value(accessibility::Value::Number {
+                    value: self.value.into(),
+                    min: start.into(),
+                    max: end.into(),
+                    step: Some(self.step.into()),
+                })
+                .action(accessibility::Action::SetValue)
+                .action(accessibility::Action::Increment)
+                .action(accessibility::Action::Decrement),
+            Some(&mut state.requests),
+        );
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -213,6 +242,7 @@ where
 
         let is_dragging = state.is_dragging;
         let current_value = self.value;
+        let requests = state.requests.take();
 
         let locate = |cursor_position: Point| -> Option<T> {
             let bounds = layout.bounds();
@@ -279,7 +309,29 @@ where
             T::from_f64(new_value)
         };
 
-        let change = |new_value: T| {
+        let set = |value: f64| -> Option<T> {
+            let start = (*self.range.start()).into();
+            let end = (*self.range.end()).into();
+            let step = self.step.into();
+
+            let steps = ((value.clamp(start, end) - start) / step).round();
+
+            T::from_f64((steps * step + start).min(end))
+        };
+
+        let requested = requests.into_iter().fold(None, |value, request| {
+            let current = value.unwrap_or(current_value);
+
+            match request {
+                accessibility::Request::SetNumber(number) => set(number),
+                accessibility::Request::Increment => increment(current),
+                accessibility::Request::Decrement => decrement(current),
+                _ => None,
+            }
+            .or(value)
+        });
+
+        let mut change = |new_value: T| {
             if (self.value.into() - new_value.into()).abs() > f64::EPSILON {
                 shell.publish((self.on_change)(new_value));
 
@@ -287,6 +339,10 @@ where
             }
         };
 
+        if let Some(new_value) = requested {
+            change(new_value);
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -484,10 +540,11 @@ where
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 struct State {
     is_dragging: bool,
     keyboard_modifiers: keyboard::Modifiers,
+    requests: accessibility::Requests,
 }
 
 /// The possible status of a [`Slider`].
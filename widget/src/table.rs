@@ -0,0 +1,1586 @@
+//! Display large amounts of data in rows and columns.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::operation::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+
+use std::cell::RefCell;
+use std::ops::Range;
+
+/// A table of rows with a header and resizable, sortable columns.
+///
+/// A [`Table`] is virtualized: only the rows that are visible are built,
+/// laid out, and drawn. Therefore, all of its rows have the same height.
+///
+/// ```no_run
+/// # type Table<'a, T, Message> = iced_widget::Table<'a, T, Message>;
+/// # use iced_widget::{table, text};
+/// #
+/// struct Device {
+///     name: String,
+///     temperature: f32,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     RowSelected(usize),
+///     SortChanged(usize, table::Order),
+/// }
+///
+/// fn view(devices: &[Device]) -> Table<'_, Device, Message> {
+///     Table::new(
+///         [
+///             table::column("Name", |device: &Device| {
+///                 text(&device.name).into()
+///             }),
+///             table::column("Temperature", |device: &Device| {
+///                 text(format!("{:.1} °C", device.temperature)).into()
+///             })
+///             .sortable(true),
+///         ],
+///         devices,
+///     )
+///     .on_select(Message::RowSelected)
+///     .on_sort(Message::SortChanged)
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Table<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    columns: Vec<Column<'a, T, Message, Theme, Renderer>>,
+    rows: &'a [T],
+    width: Length,
+    height: Length,
+    row_height: f32,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    font: Option<Renderer::Font>,
+    selection: Vec<usize>,
+    sort: Option<(usize, Order)>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_sort: Option<Box<dyn Fn(usize, Order) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    class: Theme::Class<'a>,
+    cells: RefCell<Vec<Element<'a, Message, Theme, Renderer>>>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Table<'a, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default height of the rows of a [`Table`].
+    pub const DEFAULT_ROW_HEIGHT: f32 = 32.0;
+
+    /// The default [`Padding`] of the cells of a [`Table`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 4.0,
+        bottom: 4.0,
+        right: 8.0,
+        left: 8.0,
+    };
+
+    /// Creates a new [`Table`] with the given columns and rows.
+    pub fn new(
+        columns: impl IntoIterator<Item = Column<'a, T, Message, Theme, Renderer>>,
+        rows: &'a [T],
+    ) -> Self {
+        Self {
+            columns: columns.into_iter().collect(),
+            rows,
+            width: Length::Fill,
+            height: Length::Fill,
+            row_height: Self::DEFAULT_ROW_HEIGHT,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            font: None,
+            selection: Vec::new(),
+            sort: None,
+            on_select: None,
+            on_sort: None,
+            on_resize: None,
+            class: Theme::default(),
+            cells: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Sets the width of the [`Table`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height of every row of the [`Table`].
+    pub fn row_height(mut self, row_height: impl Into<Pixels>) -> Self {
+        self.row_height = row_height.into().0.max(1.0);
+        self
+    }
+
+    /// Sets the [`Padding`] of the cells of the [`Table`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the header of the [`Table`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the header of the [`Table`].
+    pub fn text_line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the header of the [`Table`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the indices of the selected rows of the [`Table`].
+    pub fn selection(
+        mut self,
+        selection: impl IntoIterator<Item = usize>,
+    ) -> Self {
+        self.selection = selection.into_iter().collect();
+        self
+    }
+
+    /// Sets the column the rows of the [`Table`] are currently sorted by,
+    /// together with its [`Order`].
+    pub fn sort(mut self, column: usize, order: Order) -> Self {
+        self.sort = Some((column, order));
+        self
+    }
+
+    /// Sets the message that will be produced when a row of the [`Table`]
+    /// is clicked.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the message that will be produced when the header of a sortable
+    /// [`Column`] is clicked.
+    ///
+    /// Clicking the header of the current sorting column reverses its
+    /// [`Order`]. The [`Table`] does not sort the rows by itself.
+    pub fn on_sort(
+        mut self,
+        on_sort: impl Fn(usize, Order) -> Message + 'a,
+    ) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Sets the message that will be produced when a [`Column`] has been
+    /// resized, with its new width.
+    ///
+    /// The [`Table`] remembers the new width of the [`Column`] by itself.
+    pub fn on_resize(
+        mut self,
+        on_resize: impl Fn(usize, f32) -> Message + 'a,
+    ) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Table`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn widths(&self, state: &State) -> Vec<f32> {
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                state
+                    .widths
+                    .get(index)
+                    .copied()
+                    .flatten()
+                    .unwrap_or(column.width)
+                    .max(column.min_width)
+            })
+            .collect()
+    }
+
+    fn header_height(&self, renderer: &Renderer) -> f32 {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        f32::from(self.text_line_height.to_absolute(size))
+            + self.padding.vertical()
+    }
+
+    fn metrics(
+        &self,
+        bounds: Rectangle,
+        widths: &[f32],
+        renderer: &Renderer,
+    ) -> Metrics {
+        let header_height = self.header_height(renderer).min(bounds.height);
+
+        Metrics {
+            header: Rectangle {
+                height: header_height,
+                ..bounds
+            },
+            body: Rectangle {
+                y: bounds.y + header_height,
+                height: bounds.height - header_height,
+                ..bounds
+            },
+            content: Size::new(
+                widths.iter().sum(),
+                self.rows.len() as f32 * self.row_height,
+            ),
+        }
+    }
+
+    fn visible_rows(&self, body: Size, offset: Vector) -> Range<usize> {
+        let start = (offset.y / self.row_height).floor() as usize;
+        let end = ((offset.y + body.height) / self.row_height).ceil() as usize;
+
+        start.min(self.rows.len())..end.min(self.rows.len())
+    }
+
+    /// Returns the column whose right edge is under the given position, if
+    /// it can be resized.
+    fn resize_handle(
+        &self,
+        widths: &[f32],
+        header: Rectangle,
+        offset: Vector,
+        position: Point,
+    ) -> Option<usize> {
+        if !header.contains(position) {
+            return None;
+        }
+
+        let mut edge = header.x - offset.x;
+
+        widths.iter().zip(&self.columns).enumerate().find_map(
+            |(index, (width, column))| {
+                edge += width;
+
+                (column.resizable
+                    && (position.x - edge).abs() <= RESIZE_HANDLE_WIDTH / 2.0)
+                    .then_some(index)
+            },
+        )
+    }
+
+    /// Returns the column whose header is under the given position, if any.
+    fn header_column(
+        &self,
+        widths: &[f32],
+        header: Rectangle,
+        offset: Vector,
+        position: Point,
+    ) -> Option<usize> {
+        if !header.contains(position) {
+            return None;
+        }
+
+        let x = position.x - header.x + offset.x;
+        let mut start = 0.0;
+
+        widths.iter().position(|width| {
+            start += width;
+
+            x < start
+        })
+    }
+
+    /// Returns the row under the given position, if any.
+    fn row_at(
+        &self,
+        body: Rectangle,
+        offset: Vector,
+        position: Point,
+    ) -> Option<usize> {
+        if !body.contains(position) {
+            return None;
+        }
+
+        let row = ((position.y - body.y + offset.y) / self.row_height) as usize;
+
+        (row < self.rows.len()).then_some(row)
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Table<'a, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        // The cells are only built once the visible rows are known,
+        // so they are diffed during layout instead.
+        let state = tree.state.downcast_mut::<State>();
+
+        state.widths.resize(self.columns.len(), None);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+        state.widths.resize(self.columns.len(), None);
+
+        let widths = self.widths(state);
+        let header_height = self.header_height(renderer);
+        let intrinsic = Size::new(
+            widths.iter().sum(),
+            header_height + self.rows.len() as f32 * self.row_height,
+        );
+
+        let size = limits.resolve(self.width, self.height, intrinsic);
+        let metrics =
+            self.metrics(Rectangle::with_size(size), &widths, renderer);
+
+        state.offset = clamp(state.offset, &metrics);
+
+        let rows = self.visible_rows(metrics.body.size(), state.offset);
+        let columns = self.columns.len();
+
+        let mut cells = self.cells.borrow_mut();
+        let data = self.rows;
+
+        *cells = rows
+            .clone()
+            .flat_map(|row| {
+                self.columns
+                    .iter()
+                    .map(move |column| (column.view)(&data[row]))
+            })
+            .collect();
+
+        // Reuse the state of the cells that were already visible
+        let mut previous = std::mem::take(&mut tree.children);
+        let previous_rows = std::mem::replace(&mut state.rows, rows.clone());
+        let previous_columns = std::mem::replace(&mut state.columns, columns);
+
+        tree.children = rows
+            .clone()
+            .flat_map(|row| (0..columns).map(move |column| (row, column)))
+            .zip(cells.iter())
+            .map(|((row, column), cell)| {
+                if previous_columns == columns && previous_rows.contains(&row) {
+                    let index = (row - previous_rows.start) * columns + column;
+                    let mut tree =
+                        std::mem::replace(&mut previous[index], Tree::empty());
+
+                    tree.diff(cell.as_widget());
+                    tree
+                } else {
+                    Tree::new(cell.as_widget())
+                }
+            })
+            .collect();
+
+        let padding = self.padding;
+        let cell_limits = |width: f32| {
+            layout::Limits::new(
+                Size::ZERO,
+                Size::new(
+                    (width - padding.horizontal()).max(0.0),
+                    (self.row_height - padding.vertical()).max(0.0),
+                ),
+            )
+        };
+
+        let nodes = rows
+            .flat_map(|row| {
+                widths
+                    .iter()
+                    .scan(0.0, |x, width| {
+                        let start = *x;
+                        *x += width;
+
+                        Some((start, *width))
+                    })
+                    .map(move |(x, width)| (row, x, width))
+            })
+            .zip(cells.iter())
+            .zip(tree.children.iter_mut())
+            .map(|(((row, x, width), cell), tree)| {
+                let node = cell.as_widget().layout(
+                    tree,
+                    renderer,
+                    &cell_limits(width),
+                );
+
+                let available = self.row_height - padding.vertical();
+                let y = metrics.body.y
+                    + row as f32 * self.row_height
+                    + padding.top
+                    + ((available - node.size().height) / 2.0).max(0.0);
+
+                node.move_to(Point::new(
+                    x + padding.left - state.offset.x,
+                    y - state.offset.y,
+                ))
+            })
+            .collect();
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let cells = self.cells.borrow();
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            cells
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((cell, tree), layout)| {
+                    cell.as_widget().operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let widths = self.widths(state);
+        let metrics = self.metrics(bounds, &widths, renderer);
+
+        if state.resizing.is_none() && state.scrolling.is_none() {
+            let cell_cursor = if cursor.is_over(metrics.body)
+                && !is_over_scrollbar(&metrics, state.offset, cursor)
+            {
+                cursor
+            } else {
+                mouse::Cursor::Unavailable
+            };
+
+            let status = self
+                .cells
+                .get_mut()
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .map(|((cell, tree), layout)| {
+                    cell.as_widget_mut().on_event(
+                        tree,
+                        event.clone(),
+                        layout,
+                        cell_cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                        &metrics.body,
+                    )
+                })
+                .fold(event::Status::Ignored, event::Status::merge);
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !cursor.is_over(bounds) {
+                    return event::Status::Ignored;
+                }
+
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        Vector::new(x, y) * 60.0
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
+                };
+
+                let offset = clamp(state.offset - delta, &metrics);
+
+                if offset != state.offset {
+                    state.offset = offset;
+                    shell.invalidate_layout();
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                for axis in [Axis::Vertical, Axis::Horizontal] {
+                    let Some(scrollbar) =
+                        scrollbar(&metrics, state.offset, axis)
+                    else {
+                        continue;
+                    };
+
+                    if scrollbar.thumb.contains(position) {
+                        state.scrolling = Some(Scrolling {
+                            axis,
+                            grab: axis.main(position.x, position.y)
+                                - axis
+                                    .main(scrollbar.thumb.x, scrollbar.thumb.y),
+                        });
+
+                        return event::Status::Captured;
+                    }
+
+                    if scrollbar.track.contains(position) {
+                        let grab = axis.main(
+                            scrollbar.thumb.width,
+                            scrollbar.thumb.height,
+                        ) / 2.0;
+
+                        state.offset = scroll_to(
+                            &metrics,
+                            state.offset,
+                            axis,
+                            position,
+                            grab,
+                        );
+                        state.scrolling = Some(Scrolling { axis, grab });
+                        shell.invalidate_layout();
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                if let Some(column) = self.resize_handle(
+                    &widths,
+                    metrics.header,
+                    state.offset,
+                    position,
+                ) {
+                    state.resizing = Some(Resizing {
+                        column,
+                        origin: position.x,
+                        width: widths[column],
+                    });
+
+                    return event::Status::Captured;
+                }
+
+                if let Some(column) = self.header_column(
+                    &widths,
+                    metrics.header,
+                    state.offset,
+                    position,
+                ) {
+                    if self.on_sort.is_some() && self.columns[column].sortable {
+                        state.pressed_header = Some(column);
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                if let Some(row) =
+                    self.row_at(metrics.body, state.offset, position)
+                {
+                    if let Some(on_select) = &self.on_select {
+                        shell.publish(on_select(row));
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(resizing) = state.resizing {
+                    let width = (resizing.width + position.x - resizing.origin)
+                        .max(self.columns[resizing.column].min_width);
+
+                    state.widths[resizing.column] = Some(width);
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                }
+
+                if let Some(scrolling) = state.scrolling {
+                    state.offset = scroll_to(
+                        &metrics,
+                        state.offset,
+                        scrolling.axis,
+                        position,
+                        scrolling.grab,
+                    );
+                    shell.invalidate_layout();
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if let Some(resizing) = state.resizing.take() {
+                    if let Some(on_resize) = &self.on_resize {
+                        shell.publish(on_resize(
+                            resizing.column,
+                            widths[resizing.column],
+                        ));
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                if state.scrolling.take().is_some() {
+                    return event::Status::Captured;
+                }
+
+                if let Some(pressed) = state.pressed_header.take() {
+                    let Some(on_sort) = &self.on_sort else {
+                        return event::Status::Ignored;
+                    };
+
+                    let is_released_over =
+                        cursor.position().and_then(|position| {
+                            self.header_column(
+                                &widths,
+                                metrics.header,
+                                state.offset,
+                                position,
+                            )
+                        }) == Some(pressed);
+
+                    if is_released_over {
+                        let order = match self.sort {
+                            Some((column, order)) if column == pressed => {
+                                order.reverse()
+                            }
+                            _ => Order::Ascending,
+                        };
+
+                        shell.publish(on_sort(pressed, order));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.resizing.is_some() {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        if state.scrolling.is_some() {
+            return mouse::Interaction::Idle;
+        }
+
+        let bounds = layout.bounds();
+        let widths = self.widths(state);
+        let metrics = self.metrics(bounds, &widths, renderer);
+
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        if is_over_scrollbar(&metrics, state.offset, cursor) {
+            return mouse::Interaction::Idle;
+        }
+
+        if self
+            .resize_handle(&widths, metrics.header, state.offset, position)
+            .is_some()
+        {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        if let Some(column) =
+            self.header_column(&widths, metrics.header, state.offset, position)
+        {
+            return if self.on_sort.is_some() && self.columns[column].sortable {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            };
+        }
+
+        let interaction = self
+            .cells
+            .borrow()
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((cell, tree), layout)| {
+                cell.as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction == mouse::Interaction::default()
+            && self.on_select.is_some()
+            && self.row_at(metrics.body, state.offset, position).is_some()
+        {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let widths = self.widths(state);
+        let metrics = self.metrics(bounds, &widths, renderer);
+        let style = theme.style(&self.class);
+
+        let Some(clip_bounds) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        if style.background.is_some() || style.border.width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        let offset = state.offset;
+        let content_width = metrics.content.width.max(metrics.body.width);
+        let hovered_row = if self.on_select.is_some()
+            && state.resizing.is_none()
+            && state.scrolling.is_none()
+            && !is_over_scrollbar(&metrics, offset, cursor)
+        {
+            cursor.position().and_then(|position| {
+                self.row_at(metrics.body, offset, position)
+            })
+        } else {
+            None
+        };
+
+        if let Some(body) = metrics.body.intersection(&clip_bounds) {
+            renderer.with_layer(body, |renderer| {
+                let cells = self.cells.borrow();
+                let columns = self.columns.len().max(1);
+
+                for (row, ((cells, trees), layouts)) in state.rows.clone().zip(
+                    cells
+                        .chunks(columns)
+                        .zip(tree.children.chunks(columns))
+                        .zip(
+                            layout
+                                .children()
+                                .collect::<Vec<_>>()
+                                .chunks(columns),
+                        ),
+                ) {
+                    let row_bounds = Rectangle {
+                        x: metrics.body.x - offset.x,
+                        y: metrics.body.y + row as f32 * self.row_height
+                            - offset.y,
+                        width: content_width,
+                        height: self.row_height,
+                    };
+
+                    let is_selected = self.selection.contains(&row);
+
+                    let background = if is_selected {
+                        Some(style.selected_row_background)
+                    } else if hovered_row == Some(row) {
+                        style.hovered_row_background
+                    } else if row % 2 == 1 {
+                        style.alternate_row_background
+                    } else {
+                        None
+                    };
+
+                    if let Some(background) = background {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: row_bounds,
+                                ..renderer::Quad::default()
+                            },
+                            background,
+                        );
+                    }
+
+                    let text_color = if is_selected {
+                        style.selected_text_color
+                    } else {
+                        style.text_color
+                    };
+
+                    for ((cell, tree), layout) in
+                        cells.iter().zip(trees).zip(layouts)
+                    {
+                        cell.as_widget().draw(
+                            tree,
+                            renderer,
+                            theme,
+                            &renderer::Style { text_color },
+                            *layout,
+                            cursor,
+                            &body,
+                        );
+                    }
+                }
+
+                let mut x = metrics.body.x - offset.x;
+
+                for width in &widths {
+                    x += width;
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: x - DIVIDER_WIDTH,
+                                y: metrics.body.y,
+                                width: DIVIDER_WIDTH,
+                                height: metrics.body.height,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style.divider,
+                    );
+                }
+
+                for axis in [Axis::Vertical, Axis::Horizontal] {
+                    if let Some(scrollbar) = scrollbar(&metrics, offset, axis) {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: scrollbar.thumb,
+                                border: Border::rounded(SCROLLBAR_WIDTH / 2.0),
+                                ..renderer::Quad::default()
+                            },
+                            style.scrollbar,
+                        );
+                    }
+                }
+            });
+        }
+
+        let Some(header) = metrics.header.intersection(&clip_bounds) else {
+            return;
+        };
+
+        renderer.with_layer(header, |renderer| {
+            if let Some(background) = style.header_background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: metrics.header,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            let size =
+                self.text_size.unwrap_or_else(|| renderer.default_size());
+            let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+            let mut x = metrics.header.x - offset.x;
+
+            for (index, (column, width)) in
+                self.columns.iter().zip(&widths).enumerate()
+            {
+                let bounds = Rectangle {
+                    x,
+                    width: *width,
+                    ..metrics.header
+                };
+
+                x += width;
+
+                if !bounds.intersects(&header) {
+                    continue;
+                }
+
+                let content = Rectangle {
+                    x: bounds.x + self.padding.left,
+                    y: bounds.y + self.padding.top,
+                    width: (bounds.width - self.padding.horizontal()).max(0.0),
+                    height: (bounds.height - self.padding.vertical()).max(0.0),
+                };
+
+                let indicator = match self.sort {
+                    Some((column, Order::Ascending)) if column == index => {
+                        Some(ASCENDING_ICON)
+                    }
+                    Some((column, Order::Descending)) if column == index => {
+                        Some(DESCENDING_ICON)
+                    }
+                    _ => None,
+                };
+
+                renderer.fill_text(
+                    Text {
+                        content: column.header.clone(),
+                        bounds: content.size(),
+                        size,
+                        line_height: self.text_line_height,
+                        font,
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: text::Shaping::Advanced,
+                    },
+                    Point::new(content.x, content.center_y()),
+                    style.header_text_color,
+                    content,
+                );
+
+                if let Some(indicator) = indicator {
+                    renderer.fill_text(
+                        Text {
+                            content: String::from(indicator),
+                            bounds: content.size(),
+                            size,
+                            line_height: self.text_line_height,
+                            font,
+                            horizontal_alignment: alignment::Horizontal::Right,
+                            vertical_alignment: alignment::Vertical::Center,
+                            shaping: text::Shaping::Advanced,
+                        },
+                        Point::new(
+                            content.x + content.width,
+                            content.center_y(),
+                        ),
+                        style.header_text_color,
+                        bounds,
+                    );
+                }
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + bounds.width - DIVIDER_WIDTH,
+                            width: DIVIDER_WIDTH,
+                            ..bounds
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.divider,
+                );
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: metrics.header.y + metrics.header.height
+                            - DIVIDER_WIDTH,
+                        height: DIVIDER_WIDTH,
+                        ..metrics.header
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.divider,
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            self.cells.get_mut(),
+            tree,
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<Table<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(table: Table<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(table)
+    }
+}
+
+/// A column of a [`Table`].
+#[allow(missing_debug_implementations)]
+pub struct Column<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    header: String,
+    width: f32,
+    min_width: f32,
+    sortable: bool,
+    resizable: bool,
+    view: Box<dyn Fn(&'a T) -> Element<'a, Message, Theme, Renderer> + 'a>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Column<'a, T, Message, Theme, Renderer> {
+    /// The default width of a [`Column`].
+    pub const DEFAULT_WIDTH: f32 = 150.0;
+
+    /// Creates a new [`Column`] with the given header and a closure that
+    /// produces the cell of a row.
+    pub fn new(
+        header: impl Into<String>,
+        view: impl Fn(&'a T) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            width: Self::DEFAULT_WIDTH,
+            min_width: 30.0,
+            sortable: false,
+            resizable: true,
+            view: Box::new(view),
+        }
+    }
+
+    /// Sets the initial width of the [`Column`].
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into().0;
+        self
+    }
+
+    /// Sets the minimum width of the [`Column`] when resized.
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.min_width = min_width.into().0;
+        self
+    }
+
+    /// Sets whether the [`Table`] can be sorted by the [`Column`].
+    ///
+    /// By default, columns are not sortable.
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Sets whether the [`Column`] can be resized by dragging the right edge
+    /// of its header.
+    ///
+    /// By default, columns are resizable.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+}
+
+/// Creates a new [`Column`] with the given header and a closure that
+/// produces the cell of a row.
+pub fn column<'a, T, Message, Theme, Renderer>(
+    header: impl Into<String>,
+    view: impl Fn(&'a T) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> Column<'a, T, Message, Theme, Renderer> {
+    Column::new(header, view)
+}
+
+/// The order of the rows of a sorted [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Order {
+    /// From smallest to largest.
+    #[default]
+    Ascending,
+    /// From largest to smallest.
+    Descending,
+}
+
+impl Order {
+    /// Returns the opposite [`Order`].
+    pub fn reverse(self) -> Self {
+        match self {
+            Order::Ascending => Order::Descending,
+            Order::Descending => Order::Ascending,
+        }
+    }
+}
+
+const RESIZE_HANDLE_WIDTH: f32 = 8.0;
+const DIVIDER_WIDTH: f32 = 1.0;
+const SCROLLBAR_WIDTH: f32 = 6.0;
+const SCROLLBAR_MARGIN: f32 = 2.0;
+const MIN_THUMB_LENGTH: f32 = 20.0;
+const ASCENDING_ICON: char = '\u{25B2}';
+const DESCENDING_ICON: char = '\u{25BC}';
+
+#[derive(Debug, Default)]
+struct State {
+    offset: Vector,
+    widths: Vec<Option<f32>>,
+    rows: Range<usize>,
+    columns: usize,
+    resizing: Option<Resizing>,
+    scrolling: Option<Scrolling>,
+    pressed_header: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Resizing {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Scrolling {
+    axis: Axis,
+    grab: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    Vertical,
+    Horizontal,
+}
+
+impl Axis {
+    fn main(self, x: f32, y: f32) -> f32 {
+        match self {
+            Axis::Vertical => y,
+            Axis::Horizontal => x,
+        }
+    }
+}
+
+struct Metrics {
+    header: Rectangle,
+    body: Rectangle,
+    content: Size,
+}
+
+impl Metrics {
+    fn max_offset(&self) -> Vector {
+        Vector::new(
+            (self.content.width - self.body.width).max(0.0),
+            (self.content.height - self.body.height).max(0.0),
+        )
+    }
+}
+
+struct Scrollbar {
+    track: Rectangle,
+    thumb: Rectangle,
+}
+
+fn clamp(offset: Vector, metrics: &Metrics) -> Vector {
+    let max = metrics.max_offset();
+
+    Vector::new(offset.x.clamp(0.0, max.x), offset.y.clamp(0.0, max.y))
+}
+
+fn scrollbar(
+    metrics: &Metrics,
+    offset: Vector,
+    axis: Axis,
+) -> Option<Scrollbar> {
+    let body = metrics.body;
+    let max_offset = metrics.max_offset();
+    let max = axis.main(max_offset.x, max_offset.y);
+
+    if max <= 0.0 {
+        return None;
+    }
+
+    let track = match axis {
+        Axis::Vertical => Rectangle {
+            x: body.x + body.width - SCROLLBAR_WIDTH - SCROLLBAR_MARGIN,
+            width: SCROLLBAR_WIDTH,
+            ..body
+        },
+        Axis::Horizontal => Rectangle {
+            y: body.y + body.height - SCROLLBAR_WIDTH - SCROLLBAR_MARGIN,
+            height: SCROLLBAR_WIDTH,
+            ..body
+        },
+    };
+
+    let length = axis.main(track.width, track.height);
+    let content = axis.main(metrics.content.width, metrics.content.height);
+    let thumb_length = (length * length / content)
+        .max(MIN_THUMB_LENGTH)
+        .min(length);
+    let start = (length - thumb_length) * axis.main(offset.x, offset.y) / max;
+
+    let thumb = match axis {
+        Axis::Vertical => Rectangle {
+            y: track.y + start,
+            height: thumb_length,
+            ..track
+        },
+        Axis::Horizontal => Rectangle {
+            x: track.x + start,
+            width: thumb_length,
+            ..track
+        },
+    };
+
+    Some(Scrollbar { track, thumb })
+}
+
+fn is_over_scrollbar(
+    metrics: &Metrics,
+    offset: Vector,
+    cursor: mouse::Cursor,
+) -> bool {
+    [Axis::Vertical, Axis::Horizontal].into_iter().any(|axis| {
+        scrollbar(metrics, offset, axis)
+            .is_some_and(|scrollbar| cursor.is_over(scrollbar.track))
+    })
+}
+
+/// Returns the offset needed for the thumb of the scrollbar of the given
+/// [`Axis`] to start at the given position minus the grab distance.
+fn scroll_to(
+    metrics: &Metrics,
+    offset: Vector,
+    axis: Axis,
+    position: Point,
+    grab: f32,
+) -> Vector {
+    let Some(scrollbar) = scrollbar(metrics, offset, axis) else {
+        return offset;
+    };
+
+    let Scrollbar { track, thumb } = scrollbar;
+
+    let free = axis.main(track.width, track.height)
+        - axis.main(thumb.width, thumb.height);
+
+    if free <= 0.0 {
+        return offset;
+    }
+
+    let max_offset = metrics.max_offset();
+    let start =
+        axis.main(position.x, position.y) - grab - axis.main(track.x, track.y);
+    let value =
+        (start / free).clamp(0.0, 1.0) * axis.main(max_offset.x, max_offset.y);
+
+    match axis {
+        Axis::Vertical => Vector::new(offset.x, value),
+        Axis::Horizontal => Vector::new(value, offset.y),
+    }
+}
+
+/// The appearance of a [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the [`Table`].
+    pub background: Option<Background>,
+    /// The [`Border`] of the [`Table`].
+    pub border: Border,
+    /// The text [`Color`] of the cells of the [`Table`].
+    pub text_color: Color,
+    /// The [`Background`] of the header of the [`Table`].
+    pub header_background: Option<Background>,
+    /// The text [`Color`] of the header of the [`Table`].
+    pub header_text_color: Color,
+    /// The [`Background`] of every other row of the [`Table`].
+    pub alternate_row_background: Option<Background>,
+    /// The [`Background`] of a hovered row, if rows can be selected.
+    pub hovered_row_background: Option<Background>,
+    /// The [`Background`] of a selected row.
+    pub selected_row_background: Background,
+    /// The text [`Color`] of the cells of a selected row.
+    pub selected_text_color: Color,
+    /// The [`Color`] of the dividers between columns.
+    pub divider: Color,
+    /// The [`Color`] of the scrollbars.
+    pub scrollbar: Color,
+}
+
+/// The theme catalog of a [`Table`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Table`].
+///
+/// This is just a boxed closure: `Fn(&Theme) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Table`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: Some(palette.background.base.color.into()),
+        border: Border {
+            color: palette.background.strong.color,
+            width: 1.0,
+            radius: 0.0.into(),
+        },
+        text_color: palette.background.base.text,
+        header_background: Some(palette.background.weak.color.into()),
+        header_text_color: palette.background.weak.text,
+        alternate_row_background: Some(
+            palette.background.weak.color.scale_alpha(0.4).into(),
+        ),
+        hovered_row_background: Some(
+            palette.primary.weak.color.scale_alpha(0.3).into(),
+        ),
+        selected_row_background: palette.primary.strong.color.into(),
+        selected_text_color: palette.primary.strong.text,
+        divider: palette.background.strong.color,
+        scrollbar: palette.background.strong.color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text;
+
+    use iced_test::Simulator;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Sort(usize, Order),
+        Resize(usize, f32),
+    }
+
+    const ROWS: &[&str] = &["Ferris", "Corro", "Clippy"];
+
+    // A vertical position inside the header, which is ~29px tall by default
+    const HEADER: f32 = 10.0;
+
+    fn table(
+        sort: Option<(usize, Order)>,
+    ) -> Simulator<'static, Message, Theme, ()> {
+        let mut table = Table::new(
+            [
+                column("Name", |name: &&str| text(*name).into()).sortable(true),
+                column("Length", |name: &&str| text(name.len()).into())
+                    .min_width(50.0),
+            ],
+            ROWS,
+        )
+        .on_sort(Message::Sort)
+        .on_resize(Message::Resize);
+
+        if let Some((column, order)) = sort {
+            table = table.sort(column, order);
+        }
+
+        Simulator::new(table)
+    }
+
+    fn drag(
+        ui: &mut Simulator<'_, Message, Theme, ()>,
+        from: Point,
+        to: Point,
+    ) {
+        ui.point_at(from);
+        let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        ))]);
+
+        ui.point_at(to);
+        let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        ))]);
+    }
+
+    #[test]
+    fn clicking_a_sortable_header_sorts() {
+        let name = Point::new(75.0, HEADER);
+
+        let mut ui = table(None);
+        drag(&mut ui, name, name);
+        assert_eq!(ui.messages(), [Message::Sort(0, Order::Ascending)]);
+
+        let mut ui = table(Some((0, Order::Ascending)));
+        drag(&mut ui, name, name);
+        assert_eq!(ui.messages(), [Message::Sort(0, Order::Descending)]);
+
+        let mut ui = table(Some((1, Order::Descending)));
+        drag(&mut ui, name, name);
+        assert_eq!(ui.messages(), [Message::Sort(0, Order::Ascending)]);
+    }
+
+    #[test]
+    fn clicking_elsewhere_does_not_sort() {
+        let name = Point::new(75.0, HEADER);
+        let length = Point::new(225.0, HEADER);
+
+        let mut ui = table(None);
+        drag(&mut ui, length, length);
+        assert_eq!(ui.messages(), []);
+
+        drag(&mut ui, name, length);
+        assert_eq!(ui.messages(), []);
+
+        drag(&mut ui, name, Point::new(75.0, 100.0));
+        assert_eq!(ui.messages(), []);
+    }
+
+    #[test]
+    fn dragging_a_header_edge_resizes() {
+        let mut ui = table(None);
+
+        drag(
+            &mut ui,
+            Point::new(150.0, HEADER),
+            Point::new(200.0, HEADER),
+        );
+        assert_eq!(ui.messages(), [Message::Resize(0, 200.0)]);
+
+        // The new width is remembered, and so is the new edge
+        drag(
+            &mut ui,
+            Point::new(200.0, HEADER),
+            Point::new(180.0, HEADER),
+        );
+        assert_eq!(
+            ui.messages(),
+            [Message::Resize(0, 200.0), Message::Resize(0, 180.0)]
+        );
+
+        // The edge of the second column moved along
+        drag(&mut ui, Point::new(330.0, HEADER), Point::new(0.0, HEADER));
+        assert_eq!(
+            ui.messages(),
+            [
+                Message::Resize(0, 200.0),
+                Message::Resize(0, 180.0),
+                Message::Resize(1, 50.0)
+            ]
+        );
+    }
+}
@@ -0,0 +1,652 @@
+//! Display a set of views, one at a time, selectable through a tab bar.
+pub mod tab_bar;
+
+pub use tab_bar::{Catalog, Label, Status, Style, StyleFn, TabBar};
+
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+
+/// A container that displays the content of its active tab below (or above)
+/// a [`TabBar`].
+///
+/// The state of the content of every tab is kept by its id, even while the
+/// tab is not active.
+///
+/// ```no_run
+/// # type Tabs<'a, TabId, Message> = iced_widget::tabs::Tabs<'a, TabId, Message>;
+/// # use iced_widget::text;
+/// #
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Tab {
+///     Login,
+///     Board,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TabSelected(Tab),
+/// }
+///
+/// let tabs: Tabs<'_, Tab, Message> = Tabs::new(Message::TabSelected)
+///     .push(Tab::Login, "Login", text("Login content"))
+///     .push(Tab::Board, "Board", text("Board content"))
+///     .active(Tab::Login);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Tabs<
+    'a,
+    TabId,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    tab_bar: TabBar<'a, TabId, Message, Theme, Renderer>,
+    contents: Vec<(TabId, Element<'a, Message, Theme, Renderer>)>,
+    active: Option<TabId>,
+    position: Position,
+    width: Length,
+    height: Length,
+}
+
+impl<'a, TabId, Message, Theme, Renderer>
+    Tabs<'a, TabId, Message, Theme, Renderer>
+where
+    TabId: Clone + PartialEq,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Tabs`] with the message to produce when a tab is
+    /// selected.
+    pub fn new(on_select: impl Fn(TabId) -> Message + 'a) -> Self {
+        Self {
+            tab_bar: TabBar::new(on_select),
+            contents: Vec::new(),
+            active: None,
+            position: Position::default(),
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    /// Adds a tab with the given id, [`Label`], and content to the [`Tabs`].
+    pub fn push(
+        mut self,
+        id: TabId,
+        label: impl Into<Label>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.tab_bar = self.tab_bar.push(id.clone(), label);
+        self.contents.push((id, content.into()));
+        self
+    }
+
+    /// Sets the active tab of the [`Tabs`].
+    pub fn active(mut self, id: TabId) -> Self {
+        self.tab_bar = self.tab_bar.active(id.clone());
+        self.active = Some(id);
+        self
+    }
+
+    /// Sets the message that will be produced when the close button of a
+    /// tab is pressed.
+    ///
+    /// Unless `on_close` is called, the tabs will not be closeable.
+    pub fn on_close(
+        mut self,
+        on_close: impl Fn(TabId) -> Message + 'a,
+    ) -> Self {
+        self.tab_bar = self.tab_bar.on_close(on_close);
+        self
+    }
+
+    /// Sets the [`Position`] of the [`TabBar`] of the [`Tabs`].
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the width of the [`Tabs`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Tabs`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of each tab of the [`Tabs`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.tab_bar = self.tab_bar.padding(padding);
+        self
+    }
+
+    /// Sets the spacing between the tabs of the [`Tabs`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.tab_bar = self.tab_bar.spacing(spacing);
+        self
+    }
+
+    /// Sets the text size of the tabs of the [`Tabs`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.tab_bar = self.tab_bar.text_size(text_size);
+        self
+    }
+
+    /// Sets the font of the labels of the tabs of the [`Tabs`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.tab_bar = self.tab_bar.font(font);
+        self
+    }
+
+    /// Sets the font of the icons of the tabs of the [`Tabs`].
+    pub fn icon_font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.tab_bar = self.tab_bar.icon_font(font);
+        self
+    }
+
+    /// Sets the style of the [`TabBar`] of the [`Tabs`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.tab_bar = self.tab_bar.style(style);
+        self
+    }
+
+    /// Sets the style class of the [`TabBar`] of the [`Tabs`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.tab_bar = self.tab_bar.class(class);
+        self
+    }
+}
+
+/// The position of the [`TabBar`] of some [`Tabs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Position {
+    /// The [`TabBar`] is placed above the content.
+    #[default]
+    Top,
+    /// The [`TabBar`] is placed below the content.
+    Bottom,
+}
+
+struct State<TabId> {
+    ids: Vec<TabId>,
+}
+
+impl<'a, TabId, Message, Theme, Renderer>
+    Tabs<'a, TabId, Message, Theme, Renderer>
+where
+    TabId: Clone + PartialEq,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn active_index(&self) -> Option<usize> {
+        let active = self.active.as_ref()?;
+
+        self.contents.iter().position(|(id, _)| id == active)
+    }
+}
+
+impl<'a, TabId, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tabs<'a, TabId, Message, Theme, Renderer>
+where
+    TabId: Clone + PartialEq + 'static,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<TabId>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            ids: self
+                .contents
+                .iter()
+                .map(|(id, _)| id.clone())
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(Tree::new(
+            &self.tab_bar as &dyn Widget<Message, Theme, Renderer>,
+        ))
+        .chain(
+            self.contents
+                .iter()
+                .map(|(_, content)| Tree::new(content.as_widget())),
+        )
+        .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State<TabId>>();
+
+        children[0]
+            .diff(&self.tab_bar as &dyn Widget<Message, Theme, Renderer>);
+
+        let mut previous = state
+            .ids
+            .drain(..)
+            .zip(children.drain(1..))
+            .map(Some)
+            .collect::<Vec<_>>();
+
+        children.extend(self.contents.iter().map(|(id, content)| {
+            previous
+                .iter_mut()
+                .find(|entry| {
+                    entry.as_ref().is_some_and(|(previous, _)| previous == id)
+                })
+                .and_then(Option::take)
+                .map(|(_, mut tree)| {
+                    tree.diff(content.as_widget());
+                    tree
+                })
+                .unwrap_or_else(|| Tree::new(content.as_widget()))
+        }));
+
+        state
+            .ids
+            .extend(self.contents.iter().map(|(id, _)| id.clone()));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let tab_bar =
+            self.tab_bar
+                .layout(&mut tree.children[0], renderer, &limits);
+
+        let tab_bar_height = tab_bar.size().height;
+
+        let content = self.active_index().map(|index| {
+            self.contents[index].1.as_widget().layout(
+                &mut tree.children[index + 1],
+                renderer,
+                &limits.shrink(Size::new(0.0, tab_bar_height)),
+            )
+        });
+
+        let content_size = content
+            .as_ref()
+            .map(layout::Node::size)
+            .unwrap_or(Size::ZERO);
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(
+                tab_bar.size().width.max(content_size.width),
+                tab_bar_height + content_size.height,
+            ),
+        );
+
+        let (tab_bar, content) = match self.position {
+            Position::Top => (
+                tab_bar,
+                content.map(|content| {
+                    content.move_to(Point::new(0.0, tab_bar_height))
+                }),
+            ),
+            Position::Bottom => (
+                tab_bar.move_to(Point::new(0.0, size.height - tab_bar_height)),
+                content,
+            ),
+        };
+
+        layout::Node::with_children(
+            size,
+            std::iter::once(tab_bar).chain(content).collect(),
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            let mut children = layout.children();
+
+            self.tab_bar.operate(
+                &mut tree.children[0],
+                children.next().unwrap(),
+                renderer,
+                operation,
+            );
+
+            if let (Some(index), Some(layout)) =
+                (self.active_index(), children.next())
+            {
+                self.contents[index].1.as_widget().operate(
+                    &mut tree.children[index + 1],
+                    layout,
+                    renderer,
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let active = self.active_index();
+
+        let tab_bar_status = self.tab_bar.on_event(
+            &mut tree.children[0],
+            event.clone(),
+            children.next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let content_status = match (active, children.next()) {
+            (Some(index), Some(layout)) => {
+                self.contents[index].1.as_widget_mut().on_event(
+                    &mut tree.children[index + 1],
+                    event,
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            }
+            _ => event::Status::Ignored,
+        };
+
+        tab_bar_status.merge(content_status)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+
+        let tab_bar = self.tab_bar.mouse_interaction(
+            &tree.children[0],
+            children.next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        let content = match (self.active_index(), children.next()) {
+            (Some(index), Some(layout)) => {
+                self.contents[index].1.as_widget().mouse_interaction(
+                    &tree.children[index + 1],
+                    layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            }
+            _ => mouse::Interaction::default(),
+        };
+
+        tab_bar.max(content)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+
+        self.tab_bar.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            children.next().unwrap(),
+            cursor,
+            viewport,
+        );
+
+        if let (Some(index), Some(layout)) =
+            (self.active_index(), children.next())
+        {
+            self.contents[index].1.as_widget().draw(
+                &tree.children[index + 1],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let index = self.active_index()?;
+        let layout = layout.children().nth(1)?;
+
+        self.contents[index].1.as_widget_mut().overlay(
+            &mut tree.children[index + 1],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, TabId, Message, Theme, Renderer>
+    From<Tabs<'a, TabId, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    TabId: Clone + PartialEq + 'static,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tabs: Tabs<'a, TabId, Message, Theme, Renderer>) -> Self {
+        Self::new(tabs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::keyboard::key;
+    use crate::core::Theme;
+    use crate::runtime::user_interface;
+    use crate::text_input::{self, TextInput};
+
+    use iced_test::{selector, Simulator};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Tab {
+        A,
+        B,
+        C,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Select(Tab),
+        Close(Tab),
+        Input(Tab, String),
+    }
+
+    fn input(tab: Tab) -> TextInput<'static, Message, Theme, ()> {
+        TextInput::new("", "")
+            .id(text_input::Id::new(format!("{tab:?}")))
+            .on_input(move |value| Message::Input(tab, value))
+    }
+
+    fn simulator(
+        tabs: &[Tab],
+        active: Tab,
+        cache: user_interface::Cache,
+    ) -> Simulator<'static, Message, Theme, ()> {
+        let tabs = tabs
+            .iter()
+            .fold(Tabs::new(Message::Select), |tabs, &tab| {
+                tabs.push(tab, format!("{tab:?}"), input(tab))
+            })
+            .active(active)
+            .on_close(Message::Close);
+
+        Simulator::with_cache(iced_test::DEFAULT_SIZE, cache, tabs)
+    }
+
+    fn click_at(
+        ui: &mut Simulator<'static, Message, Theme, ()>,
+        position: Point,
+    ) {
+        ui.point_at(position);
+
+        let _ = ui.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+    }
+
+    // With the `()` renderer, labels have no width and every tab is as
+    // wide as its padding plus its spaced out close button.
+    const TAB_WIDTH: f32 = 32.0 + 8.0 + 20.8;
+
+    #[test]
+    fn arrow_keys_select_adjacent_tabs() {
+        let mut ui = simulator(
+            &[Tab::A, Tab::B, Tab::C],
+            Tab::B,
+            user_interface::Cache::default(),
+        );
+
+        click_at(&mut ui, Point::new(TAB_WIDTH + 8.0, 10.0));
+
+        let _ = ui.tap_key(key::Named::ArrowRight);
+        let _ = ui.tap_key(key::Named::ArrowLeft);
+        let _ = ui.tap_key(key::Named::End);
+        let _ = ui.tap_key(key::Named::Home);
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            [
+                Message::Select(Tab::B),
+                Message::Select(Tab::C),
+                Message::Select(Tab::A),
+                Message::Select(Tab::C),
+                Message::Select(Tab::A),
+            ]
+        );
+    }
+
+    #[test]
+    fn switching_tabs_keeps_content_state_by_id() {
+        let mut ui = simulator(
+            &[Tab::A, Tab::B],
+            Tab::A,
+            user_interface::Cache::default(),
+        );
+
+        assert!(ui.click(selector::id(text_input::Id::new("A"))).is_ok());
+
+        let mut ui = simulator(&[Tab::A, Tab::B], Tab::B, ui.into_cache());
+        let _ = ui.typewrite("b");
+
+        assert!(ui.messages().is_empty());
+
+        let mut ui = simulator(&[Tab::A, Tab::B], Tab::A, ui.into_cache());
+        let _ = ui.typewrite("a");
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            [Message::Input(Tab::A, String::from("a"))]
+        );
+    }
+
+    #[test]
+    fn closing_a_tab_drops_its_content_state() {
+        let mut ui = simulator(
+            &[Tab::A, Tab::B, Tab::C],
+            Tab::B,
+            user_interface::Cache::default(),
+        );
+
+        assert!(ui.click(selector::id(text_input::Id::new("B"))).is_ok());
+
+        click_at(&mut ui, Point::new(TAB_WIDTH * 2.0 - 16.0 - 10.0, 18.0));
+
+        assert_eq!(ui.messages(), [Message::Close(Tab::B)]);
+
+        let mut ui = simulator(&[Tab::A, Tab::C], Tab::C, ui.into_cache());
+        let _ = ui.typewrite("c");
+
+        assert!(ui.messages().is_empty());
+    }
+}
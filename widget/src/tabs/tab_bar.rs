@@ -0,0 +1,940 @@
+//! Display a row of tabs that can be selected and closed.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::widget::operation::{self, accessibility, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Layout, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+
+/// A horizontal bar of selectable tabs.
+///
+/// If the tabs do not fit in the available space, the [`TabBar`] can be
+/// scrolled horizontally with the mouse wheel. When focused, the arrow keys,
+/// `Home`, and `End` select a different tab.
+///
+/// ```no_run
+/// # type TabBar<'a, TabId, Message> = iced_widget::tabs::TabBar<'a, TabId, Message>;
+/// #
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Tab {
+///     General,
+///     Advanced,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TabSelected(Tab),
+///     TabClosed(Tab),
+/// }
+///
+/// let tab_bar: TabBar<'_, Tab, Message> = TabBar::new(Message::TabSelected)
+///     .push(Tab::General, "General")
+///     .push(Tab::Advanced, "Advanced")
+///     .active(Tab::General)
+///     .on_close(Message::TabClosed);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TabBar<
+    'a,
+    TabId,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    tabs: Vec<(TabId, Label)>,
+    active: Option<TabId>,
+    on_select: Box<dyn Fn(TabId) -> Message + 'a>,
+    on_close: Option<Box<dyn Fn(TabId) -> Message + 'a>>,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    text_shaping: text::Shaping,
+    font: Option<Renderer::Font>,
+    icon_font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, TabId, Message, Theme, Renderer>
+    TabBar<'a, TabId, Message, Theme, Renderer>
+where
+    TabId: Clone + PartialEq,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of a tab in a [`TabBar`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 8.0,
+        bottom: 8.0,
+        right: 16.0,
+        left: 16.0,
+    };
+
+    /// Creates a new [`TabBar`] with the message to produce when a tab is
+    /// selected.
+    pub fn new(on_select: impl Fn(TabId) -> Message + 'a) -> Self {
+        Self {
+            tabs: Vec::new(),
+            active: None,
+            on_select: Box::new(on_select),
+            on_close: None,
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 0.0,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            text_shaping: text::Shaping::Basic,
+            font: None,
+            icon_font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Adds a tab with the given id and [`Label`] to the [`TabBar`].
+    pub fn push(mut self, id: TabId, label: impl Into<Label>) -> Self {
+        self.tabs.push((id, label.into()));
+        self
+    }
+
+    /// Sets the active tab of the [`TabBar`].
+    pub fn active(mut self, id: TabId) -> Self {
+        self.active = Some(id);
+        self
+    }
+
+    /// Sets the message that will be produced when the close button of a
+    /// tab is pressed.
+    ///
+    /// Unless `on_close` is called, the tabs of the [`TabBar`] will not be
+    /// closeable.
+    pub fn on_close(
+        mut self,
+        on_close: impl Fn(TabId) -> Message + 'a,
+    ) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Sets the width of the [`TabBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of each tab of the [`TabBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the tabs of the [`TabBar`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the [`TabBar`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the [`TabBar`].
+    pub fn text_line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`text::Shaping`] strategy of the [`TabBar`].
+    pub fn text_shaping(mut self, shaping: text::Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the font of the labels of the [`TabBar`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the font of the icons of the [`TabBar`].
+    ///
+    /// By default, the font of the labels is used.
+    pub fn icon_font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.icon_font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`TabBar`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`TabBar`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn active_index(&self) -> Option<usize> {
+        let active = self.active.as_ref()?;
+
+        self.tabs.iter().position(|(id, _)| id == active)
+    }
+
+    fn line_height(&self, renderer: &Renderer) -> f32 {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        self.text_line_height.to_absolute(text_size).into()
+    }
+
+    fn close_bounds(&self, tab: Rectangle, line_height: f32) -> Rectangle {
+        Rectangle {
+            x: tab.x + tab.width - self.padding.right - line_height,
+            y: tab.y + self.padding.top,
+            width: line_height,
+            height: line_height,
+        }
+    }
+
+    fn select(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some((id, _)) = self.tabs.get(index) {
+            shell.publish((self.on_select)(id.clone()));
+        }
+    }
+}
+
+/// The label of a tab in a [`TabBar`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Label {
+    /// A text label.
+    Text(String),
+    /// An icon label.
+    Icon(char),
+    /// An icon followed by a text label.
+    IconText(char, String),
+}
+
+impl Label {
+    fn icon(&self) -> Option<char> {
+        match self {
+            Label::Text(_) => None,
+            Label::Icon(icon) | Label::IconText(icon, _) => Some(*icon),
+        }
+    }
+
+    fn text(&self) -> Option<&str> {
+        match self {
+            Label::Icon(_) => None,
+            Label::Text(text) | Label::IconText(_, text) => Some(text),
+        }
+    }
+}
+
+impl From<String> for Label {
+    fn from(text: String) -> Self {
+        Label::Text(text)
+    }
+}
+
+impl From<&str> for Label {
+    fn from(text: &str) -> Self {
+        Label::Text(text.to_owned())
+    }
+}
+
+impl From<char> for Label {
+    fn from(icon: char) -> Self {
+        Label::Icon(icon)
+    }
+}
+
+/// The spacing between the icon, the text, and the close button of a tab.
+const LABEL_SPACING: f32 = 8.0;
+
+/// The amount of pixels scrolled per line of a mouse wheel.
+const SCROLL_LINE: f32 = 60.0;
+
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    icons: Vec<P>,
+    labels: Vec<P>,
+    offset: f32,
+    active: Option<usize>,
+    is_focused: bool,
+    requests: Vec<accessibility::Requests>,
+}
+
+impl<P: text::Paragraph> State<P> {
+    fn new() -> Self {
+        Self {
+            icons: Vec::new(),
+            labels: Vec::new(),
+            offset: 0.0,
+            active: None,
+            is_focused: false,
+            requests: Vec::new(),
+        }
+    }
+}
+
+impl<P: text::Paragraph> Default for State<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// Returns the maximum scroll offset of a [`TabBar`] with the given
+/// [`Layout`].
+fn max_offset(layout: Layout<'_>) -> f32 {
+    let bounds = layout.bounds();
+
+    layout
+        .children()
+        .last()
+        .map(|tab| {
+            let tab = tab.bounds();
+
+            (tab.x + tab.width - bounds.x - bounds.width).max(0.0)
+        })
+        .unwrap_or(0.0)
+}
+
+impl<'a, TabId, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TabBar<'a, TabId, Message, Theme, Renderer>
+where
+    TabId: Clone + PartialEq,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::new())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let icon_font = self.icon_font.unwrap_or(font);
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let line_height = self.line_height(renderer);
+
+        state.icons.resize_with(self.tabs.len(), Default::default);
+        state.labels.resize_with(self.tabs.len(), Default::default);
+
+        let label_text = Text {
+            content: "",
+            bounds: Size::new(f32::INFINITY, line_height),
+            size: text_size,
+            line_height: self.text_line_height,
+            font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: self.text_shaping,
+        };
+
+        let tab_height = line_height + self.padding.vertical();
+        let mut x = 0.0;
+
+        let tabs = self
+            .tabs
+            .iter()
+            .zip(state.icons.iter_mut().zip(state.labels.iter_mut()))
+            .map(|((_, label), (icon_paragraph, label_paragraph))| {
+                let icon = label.icon().map(String::from).unwrap_or_default();
+
+                icon_paragraph.update(Text {
+                    content: &icon,
+                    font: icon_font,
+                    ..label_text
+                });
+
+                label_paragraph.update(Text {
+                    content: label.text().unwrap_or_default(),
+                    ..label_text
+                });
+
+                let parts = [
+                    label.icon().map(|_| icon_paragraph.min_width()),
+                    label.text().map(|_| label_paragraph.min_width()),
+                    self.on_close.as_ref().map(|_| line_height),
+                ];
+
+                let content_width = parts.iter().flatten().sum::<f32>()
+                    + LABEL_SPACING
+                        * (parts.iter().flatten().count().max(1) - 1) as f32;
+
+                let width = content_width + self.padding.horizontal();
+
+                let node = layout::Node::new(Size::new(width, tab_height))
+                    .move_to(Point::new(x, 0.0));
+
+                x += width + self.spacing;
+
+                node
+            })
+            .collect::<Vec<_>>();
+
+        let content_width = (x - self.spacing).max(0.0);
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(content_width, tab_height),
+        );
+
+        let max_offset = (content_width - size.width).max(0.0);
+        let active = self.active_index();
+
+        if active != state.active {
+            state.active = active;
+
+            if let Some(tab) = active.and_then(|index| tabs.get(index)) {
+                let tab = tab.bounds();
+
+                if tab.x < state.offset {
+                    state.offset = tab.x;
+                } else if tab.x + tab.width > state.offset + size.width {
+                    state.offset = tab.x + tab.width - size.width;
+                }
+            }
+        }
+
+        state.offset = state.offset.clamp(0.0, max_offset);
+
+        layout::Node::with_children(size, tabs)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        operation.focusable(state, None);
+        operation.accessibility(
+            None,
+            bounds,
+            &accessibility::Node::new(accessibility::Role::TabList)
+                .focused(state.is_focused),
+            None,
+        );
+
+        state
+            .requests
+            .resize_with(self.tabs.len(), accessibility::Requests::default);
+
+        operation.container(None, bounds, &mut |operation| {
+            let tabs = self.tabs.iter().zip(layout.children());
+
+            for (((id, label), tab_layout), requests) in
+                tabs.zip(&mut state.requests)
+            {
+                let tab_bounds =
+                    tab_layout.bounds() - Vector::new(state.offset, 0.0);
+
+                let Some(tab_bounds) = tab_bounds.intersection(&bounds) else {
+                    continue;
+                };
+
+                let mut node =
+                    accessibility::Node::new(accessibility::Role::Tab)
+                        .toggled(self.active.as_ref() == Some(id))
+                        .action(accessibility::Action::Press);
+
+                if let Some(text) = label.text() {
+                    node = node.label(text);
+                }
+
+                operation.accessibility(
+                    None,
+                    tab_bounds,
+                    &node,
+                    Some(requests),
+                );
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        for (index, requests) in state.requests.iter_mut().enumerate() {
+            for request in requests.take() {
+                if let (accessibility::Request::Press, Some((id, _))) =
+                    (request, self.tabs.get(index))
+                {
+                    shell.publish((self.on_select)(id.clone()));
+                }
+            }
+        }
+
+        let hovered_tab = cursor.position_over(bounds).and_then(|position| {
+            let position = position + Vector::new(state.offset, 0.0);
+
+            layout
+                .children()
+                .position(|tab| tab.bounds().contains(position))
+                .map(|index| {
+                    let tab = layout.children().nth(index).unwrap().bounds();
+
+                    let close_bounds =
+                        self.close_bounds(tab, self.line_height(renderer));
+
+                    (index, close_bounds.contains(position))
+                })
+        });
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !cursor.is_over(bounds) {
+                    state.is_focused = false;
+
+                    return event::Status::Ignored;
+                }
+
+                state.is_focused = true;
+
+                if let Some((index, is_over_close)) = hovered_tab {
+                    let id = self.tabs[index].0.clone();
+
+                    match &self.on_close {
+                        Some(on_close) if is_over_close => {
+                            shell.publish(on_close(id));
+                        }
+                        _ => {
+                            shell.publish((self.on_select)(id));
+                        }
+                    }
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Middle,
+            )) => {
+                if let (Some(on_close), Some((index, _))) =
+                    (&self.on_close, hovered_tab)
+                {
+                    shell.publish(on_close(self.tabs[index].0.clone()));
+
+                    return event::Status::Captured;
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let max_offset = max_offset(layout);
+
+                if max_offset <= 0.0 || !cursor.is_over(bounds) {
+                    return event::Status::Ignored;
+                }
+
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        (x * SCROLL_LINE, y * SCROLL_LINE)
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => (x, y),
+                };
+
+                let delta = if x.abs() > y.abs() { x } else { y };
+
+                state.offset = (state.offset - delta).clamp(0.0, max_offset);
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. })
+                if state.is_focused =>
+            {
+                let last = self.tabs.len().saturating_sub(1);
+                let active = self.active_index();
+
+                let next = match key.as_ref() {
+                    keyboard::Key::Named(key::Named::ArrowLeft) => Some(
+                        active.map_or(last, |index| index.saturating_sub(1)),
+                    ),
+                    keyboard::Key::Named(key::Named::ArrowRight) => {
+                        Some(active.map_or(0, |index| (index + 1).min(last)))
+                    }
+                    keyboard::Key::Named(key::Named::Home) => Some(0),
+                    keyboard::Key::Named(key::Named::End) => Some(last),
+                    _ => None,
+                };
+
+                match next {
+                    Some(next) => {
+                        if Some(next) != active {
+                            self.select(next, shell);
+                        }
+
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        if cursor.is_over(bounds) && !self.tabs.is_empty() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        let Some(clip_bounds) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let bar_style = theme.style(&self.class, Status::Active);
+
+        if let Some(background) = bar_style.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        let line_height = self.line_height(renderer);
+        let active = self.active_index();
+        let is_mouse_over_bar = cursor.is_over(bounds);
+
+        renderer.with_layer(clip_bounds, |renderer| {
+            for (index, ((_, label), tab)) in
+                self.tabs.iter().zip(layout.children()).enumerate()
+            {
+                let tab_bounds = tab.bounds() - Vector::new(state.offset, 0.0);
+
+                if !tab_bounds.intersects(&clip_bounds) {
+                    continue;
+                }
+
+                let is_mouse_over =
+                    is_mouse_over_bar && cursor.is_over(tab_bounds);
+
+                let status = if Some(index) == active {
+                    if state.is_focused {
+                        Status::Focused
+                    } else {
+                        Status::Selected
+                    }
+                } else if is_mouse_over {
+                    Status::Hovered
+                } else {
+                    Status::Active
+                };
+
+                let style = theme.style(&self.class, status);
+
+                if style.tab_background.is_some() || style.border.width > 0.0 {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: tab_bounds,
+                            border: style.border,
+                            ..renderer::Quad::default()
+                        },
+                        style
+                            .tab_background
+                            .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                    );
+                }
+
+                if let Some(indicator) = style.indicator {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                y: tab_bounds.y + tab_bounds.height
+                                    - INDICATOR_HEIGHT,
+                                height: INDICATOR_HEIGHT,
+                                ..tab_bounds
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        indicator,
+                    );
+                }
+
+                let mut position = Point::new(
+                    tab_bounds.x + self.padding.left,
+                    tab_bounds.y + self.padding.top,
+                );
+
+                if label.icon().is_some() {
+                    let icon = &state.icons[index];
+
+                    renderer.fill_paragraph(
+                        icon,
+                        position,
+                        style.text_color,
+                        clip_bounds,
+                    );
+
+                    position.x += icon.min_width() + LABEL_SPACING;
+                }
+
+                if label.text().is_some() {
+                    renderer.fill_paragraph(
+                        &state.labels[index],
+                        position,
+                        style.text_color,
+                        clip_bounds,
+                    );
+                }
+
+                if self.on_close.is_some() {
+                    let close_bounds =
+                        self.close_bounds(tab_bounds, line_height);
+
+                    if is_mouse_over && cursor.is_over(close_bounds) {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: close_bounds,
+                                border: Border::rounded(line_height / 2.0),
+                                ..renderer::Quad::default()
+                            },
+                            style.close_color.scale_alpha(0.2),
+                        );
+                    }
+
+                    renderer.fill_text(
+                        Text {
+                            content: String::from(CLOSE_ICON),
+                            bounds: close_bounds.size(),
+                            size: self
+                                .text_size
+                                .unwrap_or_else(|| renderer.default_size()),
+                            line_height: self.text_line_height,
+                            font: self
+                                .font
+                                .unwrap_or_else(|| renderer.default_font()),
+                            horizontal_alignment: alignment::Horizontal::Center,
+                            vertical_alignment: alignment::Vertical::Center,
+                            shaping: text::Shaping::Advanced,
+                        },
+                        close_bounds.center(),
+                        style.close_color,
+                        clip_bounds,
+                    );
+                }
+            }
+        });
+    }
+}
+
+impl<'a, TabId, Message, Theme, Renderer>
+    From<TabBar<'a, TabId, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    TabId: Clone + PartialEq + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tab_bar: TabBar<'a, TabId, Message, Theme, Renderer>) -> Self {
+        Self::new(tab_bar)
+    }
+}
+
+/// The icon used for the close button of a tab.
+const CLOSE_ICON: char = '×';
+
+/// The height of the indicator drawn under a tab.
+const INDICATOR_HEIGHT: f32 = 2.0;
+
+/// The possible status of a tab in a [`TabBar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The tab can be selected.
+    Active,
+    /// The tab can be selected and it is being hovered.
+    Hovered,
+    /// The tab is selected.
+    Selected,
+    /// The tab is selected and the [`TabBar`] has keyboard focus.
+    Focused,
+}
+
+/// The appearance of a tab bar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the tab bar.
+    pub background: Option<Background>,
+    /// The [`Background`] of a tab.
+    pub tab_background: Option<Background>,
+    /// The text [`Color`] of a tab.
+    pub text_color: Color,
+    /// The [`Border`] of a tab.
+    pub border: Border,
+    /// The [`Color`] of the close button of a tab.
+    pub close_color: Color,
+    /// The [`Color`] of the indicator drawn under a tab, if any.
+    pub indicator: Option<Color>,
+}
+
+/// The theme catalog of a [`TabBar`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`TabBar`].
+///
+/// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`TabBar`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let active = Style {
+        background: Some(palette.background.weak.color.into()),
+        tab_background: None,
+        text_color: palette.background.weak.text,
+        border: Border::default(),
+        close_color: palette.background.weak.text,
+        indicator: None,
+    };
+
+    let selected = Style {
+        tab_background: Some(palette.background.base.color.into()),
+        text_color: palette.background.base.text,
+        close_color: palette.background.base.text,
+        indicator: Some(palette.primary.strong.color),
+        ..active
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered => Style {
+            tab_background: Some(palette.background.strong.color.into()),
+            text_color: palette.background.strong.text,
+            close_color: palette.background.strong.text,
+            ..active
+        },
+        Status::Selected => selected,
+        Status::Focused => Style {
+            border: Border {
+                color: palette.primary.strong.color,
+                width: 1.0,
+                radius: 0.0.into(),
+            },
+            ..selected
+        },
+    }
+}
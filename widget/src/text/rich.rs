@@ -0,0 +1,609 @@
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{Paragraph, Span};
+use crate::core::touch;
+use crate::core::widget::operation::accessibility;
+use crate::core::widget::text::{Catalog, LineHeight, Shaping, Style, StyleFn};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    self, Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+
+/// A bunch of [`Span`]s of text with their own styles, laid out as a single
+/// paragraph.
+///
+/// Spans with a link can be clicked to produce it as a message.
+#[allow(missing_debug_implementations)]
+pub struct Rich<'a, Link, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Link: Clone + 'static,
+    Theme: Catalog,
+    Renderer: core::text::Renderer,
+{
+    spans: Vec<Span<'a, Link, Renderer::Font>>,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    width: Length,
+    height: Length,
+    font: Option<Renderer::Font>,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Link, Theme, Renderer> Rich<'a, Link, Theme, Renderer>
+where
+    Link: Clone + 'static,
+    Theme: Catalog,
+    Renderer: core::text::Renderer,
+{
+    /// Creates a new empty [`Rich`] text.
+    pub fn new() -> Self {
+        Self {
+            spans: Vec::new(),
+            size: None,
+            line_height: LineHeight::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            font: None,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            class: Theme::default(),
+        }
+    }
+
+    /// Creates a new [`Rich`] text with the given [`Span`]s.
+    pub fn with_spans(
+        spans: impl IntoIterator<Item = Span<'a, Link, Renderer::Font>>,
+    ) -> Self {
+        Self {
+            spans: spans.into_iter().collect(),
+            ..Self::new()
+        }
+    }
+
+    /// Adds a [`Span`] to the [`Rich`] text.
+    pub fn push(
+        mut self,
+        span: impl Into<Span<'a, Link, Renderer::Font>>,
+    ) -> Self {
+        self.spans.push(span.into());
+        self
+    }
+
+    /// Sets the size of the [`Rich`] text.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`Rich`] text.
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the default font of the [`Rich`] text.
+    ///
+    /// It is used for the [`Span`]s without a font.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the width of the [`Rich`] text boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Rich`] text boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`alignment::Horizontal`] of the [`Rich`] text.
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: alignment::Horizontal,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`alignment::Vertical`] of the [`Rich`] text.
+    pub fn vertical_alignment(
+        mut self,
+        alignment: alignment::Vertical,
+    ) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the style of the [`Rich`] text.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the default [`Color`] of the [`Rich`] text.
+    ///
+    /// It is used for the [`Span`]s without a color.
+    pub fn color(self, color: impl Into<Color>) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        let color = color.into();
+
+        self.style(move |_theme| Style { color: Some(color) })
+    }
+
+    /// Sets the style class of the [`Rich`] text.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Returns the index of the [`Span`] with a link under the cursor, if any.
+    fn hovered_link(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        let bounds = layout.bounds();
+        let position = cursor.position_over(bounds)?;
+        let origin = origin(&state.paragraph, bounds);
+
+        let index = state.paragraph.hit_span(Point::new(
+            position.x - origin.x,
+            position.y - origin.y,
+        ))?;
+
+        self.spans
+            .get(index)
+            .filter(|span| span.link.is_some())
+            .map(|_| index)
+    }
+}
+
+impl<'a, Link, Theme, Renderer> Default for Rich<'a, Link, Theme, Renderer>
+where
+    Link: Clone + 'static,
+    Theme: Catalog,
+    Renderer: core::text::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct State<P: Paragraph> {
+    spans: Vec<Span<'static, (), P::Font>>,
+    text: Option<core::Text<(), P::Font>>,
+    paragraph: P,
+    pressed_link: Option<usize>,
+}
+
+impl<'a, Link, Theme, Renderer> Widget<Link, Theme, Renderer>
+    for Rich<'a, Link, Theme, Renderer>
+where
+    Link: Clone + 'static,
+    Theme: Catalog,
+    Renderer: core::text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            spans: Vec::new(),
+            text: None,
+            paragraph: Renderer::Paragraph::default(),
+            pressed_link: None,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        layout::sized(limits, self.width, self.height, |limits| {
+            let bounds = limits.max();
+
+            let text = core::Text {
+                content: (),
+                bounds,
+                size: self.size.unwrap_or_else(|| renderer.default_size()),
+                line_height: self.line_height,
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                horizontal_alignment: self.horizontal_alignment,
+                vertical_alignment: self.vertical_alignment,
+                shaping: Shaping::Advanced,
+            };
+
+            let is_reshaped =
+                state.text.map(|old| core::Text { bounds, ..old })
+                    != Some(text)
+                    || state.spans.len() != self.spans.len()
+                    || state.spans.iter().zip(&self.spans).any(|(old, new)| {
+                        old.text != new.text
+                            || old.font != new.font
+                            || old.color != new.color
+                    });
+
+            if is_reshaped {
+                state.paragraph = Renderer::Paragraph::with_spans(core::Text {
+                    content: self.spans.as_slice(),
+                    bounds,
+                    size: text.size,
+                    line_height: text.line_height,
+                    font: text.font,
+                    horizontal_alignment: text.horizontal_alignment,
+                    vertical_alignment: text.vertical_alignment,
+                    shaping: text.shaping,
+                });
+
+                state.spans = self.spans.iter().map(Span::to_static).collect();
+            } else if state.text.is_some_and(|old| old.bounds != bounds) {
+                state.paragraph.resize(bounds);
+            }
+
+            state.text = Some(text);
+
+            state.paragraph.min_bounds()
+        })
+    }
+
+    fn operate(
+        &self,
+        _state: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Link>,
+    ) {
+        let content: String =
+            self.spans.iter().map(|span| span.text.as_ref()).collect();
+
+        operation.text(None, layout.bounds(), &content);
+        operation.accessibility(
+            None,
+            layout.bounds(),
+            &accessibility::Node::new(accessibility::Role::Label)
+                .label(content),
+            None,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Link>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(index) = self.hovered_link(state, layout, cursor) {
+                    state.pressed_link = Some(index);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(index) = state.pressed_link.take() {
+                    if self.hovered_link(state, layout, cursor) == Some(index) {
+                        if let Some(link) = self.spans[index].link.clone() {
+                            shell.publish(link);
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        if self.hovered_link(state, layout, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = theme.style(&self.class);
+
+        let bounds = layout.bounds();
+        let origin = origin(&state.paragraph, bounds);
+        let translation = Vector::new(origin.x, origin.y);
+        let color = style.color.unwrap_or(defaults.text_color);
+
+        for (index, span) in self.spans.iter().enumerate() {
+            if let Some(highlight) = span.highlight {
+                for region in state.paragraph.span_bounds(index) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: region + translation,
+                            border: highlight.border,
+                            ..renderer::Quad::default()
+                        },
+                        highlight.background,
+                    );
+                }
+            }
+        }
+
+        let x = match state.paragraph.horizontal_alignment() {
+            alignment::Horizontal::Left => bounds.x,
+            alignment::Horizontal::Center => bounds.center_x(),
+            alignment::Horizontal::Right => bounds.x + bounds.width,
+        };
+
+        let y = match state.paragraph.vertical_alignment() {
+            alignment::Vertical::Top => bounds.y,
+            alignment::Vertical::Center => bounds.center_y(),
+            alignment::Vertical::Bottom => bounds.y + bounds.height,
+        };
+
+        renderer.fill_paragraph(
+            &state.paragraph,
+            Point::new(x, y),
+            color,
+            *viewport,
+        );
+
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let thickness = (size.0 / 14.0).round().max(1.0);
+
+        for (index, span) in self.spans.iter().enumerate() {
+            if !span.underline && !span.strikethrough {
+                continue;
+            }
+
+            let color = span.color.unwrap_or(color);
+
+            for region in state.paragraph.span_bounds(index) {
+                let region = region + translation;
+
+                let mut line = |y: f32| {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: region.x,
+                                y: y.round(),
+                                width: region.width,
+                                height: thickness,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        color,
+                    );
+                };
+
+                if span.underline {
+                    line(region.center_y() + size.0 * 0.4);
+                }
+
+                if span.strikethrough {
+                    line(region.center_y());
+                }
+            }
+        }
+    }
+}
+
+/// Returns the top-left corner of the given paragraph laid out in the
+/// given bounds.
+fn origin<P: Paragraph>(paragraph: &P, bounds: Rectangle) -> Point {
+    let min_bounds = paragraph.min_bounds();
+
+    let x = match paragraph.horizontal_alignment() {
+        alignment::Horizontal::Left => bounds.x,
+        alignment::Horizontal::Center => {
+            bounds.center_x() - min_bounds.width / 2.0
+        }
+        alignment::Horizontal::Right => {
+            bounds.x + bounds.width - min_bounds.width
+        }
+    };
+
+    let y = match paragraph.vertical_alignment() {
+        alignment::Vertical::Top => bounds.y,
+        alignment::Vertical::Center => {
+            bounds.center_y() - min_bounds.height / 2.0
+        }
+        alignment::Vertical::Bottom => {
+            bounds.y + bounds.height - min_bounds.height
+        }
+    };
+
+    Point::new(x, y)
+}
+
+impl<'a, Link, Theme, Renderer> From<Rich<'a, Link, Theme, Renderer>>
+    for Element<'a, Link, Theme, Renderer>
+where
+    Link: Clone + 'static,
+    Theme: Catalog + 'a,
+    Renderer: core::text::Renderer + 'a,
+{
+    fn from(
+        text: Rich<'a, Link, Theme, Renderer>,
+    ) -> Element<'a, Link, Theme, Renderer> {
+        Element::new(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::text::Renderer as _;
+    use crate::core::Theme;
+
+    use iced_test::Simulator;
+
+    type Renderer = iced_test::snapshot::Renderer;
+    type Ui = Simulator<'static, Link, Theme, Renderer>;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Link {
+        Docs,
+        Source,
+    }
+
+    fn spans() -> Vec<Span<'static, Link, core::Font>> {
+        vec![
+            Span::new("Read the "),
+            Span::new("docs").link(Link::Docs),
+            Span::new(" or the "),
+            Span::new("source").link(Link::Source),
+            Span::new("."),
+        ]
+    }
+
+    fn simulator() -> Ui {
+        Simulator::new(Rich::with_spans(spans()))
+    }
+
+    /// Returns the center of the span with the given index, as laid out at
+    /// the top-left corner of the [`Simulator`].
+    fn span_center(index: usize) -> Point {
+        let renderer = Renderer::default();
+
+        let paragraph =
+            <Renderer as core::text::Renderer>::Paragraph::with_spans(
+                core::Text {
+                    content: spans().as_slice(),
+                    bounds: iced_test::DEFAULT_SIZE,
+                    size: renderer.default_size(),
+                    line_height: LineHeight::default(),
+                    font: renderer.default_font(),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                    shaping: Shaping::Advanced,
+                },
+            );
+
+        paragraph
+            .span_bounds(index)
+            .first()
+            .expect("Span should be laid out")
+            .center()
+    }
+
+    fn press(ui: &mut Ui, position: Point) {
+        ui.point_at(position);
+
+        let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        ))]);
+    }
+
+    fn release(ui: &mut Ui, position: Point) {
+        ui.point_at(position);
+
+        let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        ))]);
+    }
+
+    fn click(ui: &mut Ui, position: Point) {
+        press(ui, position);
+        release(ui, position);
+    }
+
+    #[test]
+    fn clicking_a_link_publishes_it() {
+        let mut ui = simulator();
+
+        click(&mut ui, span_center(1));
+        click(&mut ui, span_center(3));
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            [Link::Docs, Link::Source]
+        );
+    }
+
+    #[test]
+    fn clicking_outside_of_a_link_does_nothing() {
+        let mut ui = simulator();
+
+        click(&mut ui, span_center(0));
+        click(&mut ui, span_center(2));
+        click(&mut ui, span_center(4));
+        click(&mut ui, Point::new(500.0, 500.0));
+
+        assert_eq!(ui.into_messages().count(), 0);
+    }
+
+    #[test]
+    fn releasing_outside_of_the_pressed_link_cancels_it() {
+        let mut ui = simulator();
+
+        press(&mut ui, span_center(1));
+        release(&mut ui, span_center(3));
+
+        press(&mut ui, span_center(0));
+        release(&mut ui, span_center(1));
+
+        assert_eq!(ui.into_messages().count(), 0);
+    }
+}
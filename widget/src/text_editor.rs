@@ -5,23 +5,39 @@ use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
+use crate::core::overlay;
 use crate::core::renderer;
+#[cfg(feature = "search")]
+use crate::core::text::editor::Search;
 use crate::core::text::editor::{Cursor, Editor as _};
-use crate::core::text::highlighter::{self, Highlighter};
+use crate::core::text::highlighter::{
+    self, Decoration, Highlighter, Underline,
+};
 use crate::core::text::{self, LineHeight};
+use crate::core::text::{Paragraph as _, Text};
+use crate::core::widget::operation::accessibility;
 use crate::core::widget::{self, Widget};
+use crate::core::window;
 use crate::core::{
-    Background, Border, Color, Element, Length, Padding, Pixels, Rectangle,
-    Shell, Size, Theme, Vector,
+    alignment, Background, Border, Color, Element, InputMethod, Length,
+    Padding, Pixels, Point, Rectangle, Shell, Size, Theme, Vector,
 };
+use crate::history::{self, History};
+use crate::preedit::Preedit;
 
 use std::cell::RefCell;
 use std::fmt;
-use std::ops::DerefMut;
+use std::ops::{DerefMut, Range};
 use std::sync::Arc;
 
 pub use text::editor::{Action, Edit, Motion};
 
+#[cfg(feature = "search")]
+mod search;
+
+#[cfg(feature = "search")]
+use search::Matcher;
+
 /// A multi-line text input.
 #[allow(missing_debug_implementations)]
 pub struct TextEditor<
@@ -44,6 +60,10 @@ pub struct TextEditor<
     padding: Padding,
     class: Theme::Class<'a>,
     on_edit: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    line_numbers: bool,
+    folding: bool,
+    markers: Option<Box<dyn Fn(usize) -> Option<Marker<Renderer::Font>> + 'a>>,
+    on_gutter_click: Option<Box<dyn Fn(usize) -> Message + 'a>>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format: fn(
         &Highlighter::Highlight,
@@ -69,6 +89,10 @@ where
             padding: Padding::new(5.0),
             class: Theme::default(),
             on_edit: None,
+            line_numbers: false,
+            folding: false,
+            markers: None,
+            on_gutter_click: None,
             highlighter_settings: (),
             highlighter_format: |_highlight, _theme| {
                 highlighter::Format::default()
@@ -131,6 +155,42 @@ where
         self
     }
 
+    /// Sets whether the [`TextEditor`] displays line numbers in its gutter.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Sets whether the lines of the [`TextEditor`] can be folded from its
+    /// gutter.
+    ///
+    /// Foldable regions are provided by the [`Highlighter`], if it knows
+    /// them; or inferred from indentation, otherwise.
+    pub fn folding(mut self, folding: bool) -> Self {
+        self.folding = folding;
+        self
+    }
+
+    /// Sets the function that produces the [`Marker`] displayed in the
+    /// gutter next to each line of the [`TextEditor`], if any.
+    pub fn markers(
+        mut self,
+        markers: impl Fn(usize) -> Option<Marker<Renderer::Font>> + 'a,
+    ) -> Self {
+        self.markers = Some(Box::new(markers));
+        self
+    }
+
+    /// Sets the message that should be produced when the gutter of a line
+    /// of the [`TextEditor`] is clicked.
+    pub fn on_gutter_click(
+        mut self,
+        on_click: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_gutter_click = Some(Box::new(on_click));
+        self
+    }
+
     /// Highlights the [`TextEditor`] with the given [`Highlighter`] and
     /// a strategy to turn its highlights into some text format.
     pub fn highlight<H: text::Highlighter>(
@@ -151,6 +211,10 @@ where
             padding: self.padding,
             class: self.class,
             on_edit: self.on_edit,
+            line_numbers: self.line_numbers,
+            folding: self.folding,
+            markers: self.markers,
+            on_gutter_click: self.on_gutter_click,
             highlighter_settings: settings,
             highlighter_format: to_format,
         }
@@ -173,9 +237,99 @@ where
         self.class = class.into();
         self
     }
+
+    fn gutter(&self, renderer: &Renderer, line_count: usize) -> Gutter {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let line_height = self.line_height.to_absolute(size).0;
+
+        let numbers = if self.line_numbers {
+            let digits = line_count.max(10).ilog10() as usize + 1;
+
+            let paragraph = Renderer::Paragraph::with_text(text::Text {
+                content: &"0".repeat(digits),
+                bounds: Size::INFINITY,
+                size,
+                line_height: self.line_height,
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: text::Shaping::Basic,
+            });
+
+            paragraph.min_bounds().width
+        } else {
+            0.0
+        };
+
+        let markers = if self.markers.is_some() {
+            line_height
+        } else {
+            0.0
+        };
+
+        let folds = if self.folding { line_height } else { 0.0 };
+
+        let spacing = if self.line_numbers || markers > 0.0 || folds > 0.0 {
+            size.0 / 2.0
+        } else {
+            0.0
+        };
+
+        Gutter {
+            markers,
+            numbers,
+            folds,
+            spacing,
+        }
+    }
+
+    fn hovered_decoration(
+        &self,
+        gutter: Gutter,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<(usize, Point)> {
+        let position = cursor.position_over(bounds)?;
+        let padding = self.text_padding(gutter);
+
+        let target = position
+            - Vector::new(bounds.x + padding.left, bounds.y + padding.top);
+
+        let internal = self.content.0.borrow();
+        let visible_lines = internal.editor.visible_lines();
+
+        internal
+            .decorations
+            .iter()
+            .position(|decoration| {
+                decoration.tooltip.is_some()
+                    && regions(&internal.editor, decoration, &visible_lines)
+                        .any(|region| region.contains(target))
+            })
+            .map(|index| (index, position))
+    }
+
+    fn text_padding(&self, gutter: Gutter) -> Padding {
+        Padding {
+            left: self.padding.left + gutter.width(),
+            ..self.padding
+        }
+    }
 }
 
 /// The content of a [`TextEditor`].
+///
+/// A [`Content`] keeps a history of the edits performed on it, which can be
+/// undone and redone with [`Action::Undo`] and [`Action::Redo`]. Consecutive
+/// typing and deleting are undone together.
+///
+/// It can be edited with multiple cursors at once and, with the `search`
+/// feature, it can also highlight the matches of a [`Search`].
+///
+/// A [`Content`] can also hold some [`Decoration`]s, which move along with
+/// the text they decorate as it is edited.
+///
+/// [`Search`]: crate::core::text::editor::Search
 pub struct Content<R = crate::Renderer>(RefCell<Internal<R>>)
 where
     R: text::Renderer;
@@ -185,6 +339,14 @@ where
     R: text::Renderer,
 {
     editor: R::Editor,
+    history: History<Change>,
+    #[cfg(feature = "search")]
+    search: Option<Search>,
+    #[cfg(feature = "search")]
+    matcher: Option<Matcher>,
+    matches: Vec<Match>,
+    cursors: Vec<Selection>,
+    decorations: Vec<Decoration>,
     is_dirty: bool,
 }
 
@@ -201,6 +363,14 @@ where
     pub fn with_text(text: &str) -> Self {
         Self(RefCell::new(Internal {
             editor: R::Editor::with_text(text),
+            history: History::new(),
+            #[cfg(feature = "search")]
+            search: None,
+            #[cfg(feature = "search")]
+            matcher: None,
+            matches: Vec::new(),
+            cursors: Vec::new(),
+            decorations: Vec::new(),
             is_dirty: true,
         }))
     }
@@ -209,10 +379,133 @@ where
     pub fn perform(&mut self, action: Action) {
         let internal = self.0.get_mut();
 
-        internal.editor.perform(action);
+        match action {
+            Action::Edit(edit) => internal.edit(edit),
+            Action::Undo => internal.undo(),
+            Action::Redo => internal.redo(),
+            #[cfg(feature = "search")]
+            Action::Find(search) => internal.find(search),
+            #[cfg(feature = "search")]
+            Action::FindNext => internal.find_next(),
+            #[cfg(feature = "search")]
+            Action::FindPrevious => internal.find_previous(),
+            #[cfg(feature = "search")]
+            Action::Replace(replacement) => {
+                internal.replace_match(&replacement);
+            }
+            #[cfg(feature = "search")]
+            Action::ReplaceAll(replacement) => {
+                internal.replace_all(&replacement);
+            }
+            Action::AddCursor(position) => internal.add_cursor(position),
+            Action::SelectNextOccurrence => internal.select_next_occurrence(),
+            Action::ClearCursors => internal.cursors.clear(),
+            Action::Fold(lines) => {
+                internal.cursors.clear();
+                internal.editor.perform(Action::Fold(lines));
+            }
+            Action::Click(position) => {
+                internal.cursors.clear();
+                internal.editor.perform(Action::Click(position));
+            }
+            action @ (Action::Move(_)
+            | Action::Select(_)
+            | Action::SelectWord
+            | Action::SelectLine) => {
+                internal
+                    .at_each_cursor(|editor| editor.perform(action.clone()));
+            }
+            action => internal.editor.perform(action),
+        }
+
         internal.is_dirty = true;
     }
 
+    /// Undoes the last group of edits of the [`Content`], if any.
+    pub fn undo(&mut self) {
+        self.perform(Action::Undo);
+    }
+
+    /// Redoes the last group of undone edits of the [`Content`], if any.
+    pub fn redo(&mut self) {
+        self.perform(Action::Redo);
+    }
+
+    /// Returns whether the [`Content`] has any edits to undo.
+    pub fn can_undo(&self) -> bool {
+        self.0.borrow().history.can_undo()
+    }
+
+    /// Returns whether the [`Content`] has any undone edits to redo.
+    pub fn can_redo(&self) -> bool {
+        self.0.borrow().history.can_redo()
+    }
+
+    /// Discards the undo and redo history of the [`Content`].
+    pub fn clear_history(&mut self) {
+        self.0.get_mut().history.clear();
+    }
+
+    /// Returns the current [`Search`] of the [`Content`], if any.
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    pub fn search(&self) -> Option<Search> {
+        self.0.borrow().search.clone()
+    }
+
+    /// Returns the amount of matches of the current [`Search`].
+    ///
+    /// It is zero if the [`Search`] is not a valid regular expression.
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    pub fn match_count(&self) -> usize {
+        self.0.borrow().matches.len()
+    }
+
+    /// Returns the index of the match of the current [`Search`] that is
+    /// selected, if any.
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
+    pub fn current_match(&self) -> Option<usize> {
+        let internal = self.0.borrow();
+        let selection = internal.selection();
+
+        internal
+            .matches
+            .iter()
+            .position(|found| selection == found.selection())
+    }
+
+    /// Sets the [`Decoration`]s of the [`Content`].
+    ///
+    /// Decorations are drawn independently of the [`Highlighter`] and can be
+    /// used to display diagnostics, for instance. Edits move them along with
+    /// their text, and a decoration is dropped once all of its text is
+    /// removed.
+    pub fn set_decorations(
+        &mut self,
+        decorations: impl IntoIterator<Item = Decoration>,
+    ) {
+        self.0.get_mut().decorations = decorations.into_iter().collect();
+    }
+
+    /// Returns the [`Decoration`]s of the [`Content`].
+    pub fn decorations(&self) -> Vec<Decoration> {
+        self.0.borrow().decorations.clone()
+    }
+
+    /// Returns the amount of cursors of the [`Content`].
+    ///
+    /// It is always at least one.
+    pub fn cursor_count(&self) -> usize {
+        self.0.borrow().cursors.len() + 1
+    }
+
+    /// Returns the ranges of lines that are currently folded.
+    pub fn folds(&self) -> Vec<Range<usize>> {
+        self.0.borrow().editor.folds()
+    }
+
     /// Returns the amount of lines of the [`Content`].
     pub fn line_count(&self) -> usize {
         self.0.borrow().editor.line_count()
@@ -295,6 +588,708 @@ where
     }
 }
 
+impl<R> Internal<R>
+where
+    R: text::Renderer,
+{
+    fn edit(&mut self, edit: Edit) {
+        let selection_before = self.selection();
+
+        let is_selecting = self
+            .cursors
+            .iter()
+            .chain([&selection_before])
+            .any(|(_, anchor)| anchor.is_some());
+
+        let group = match edit {
+            _ if is_selecting => history::Group::Standalone,
+            Edit::Insert(c) => history::Group::Typing {
+                is_whitespace: c.is_whitespace(),
+            },
+            Edit::Backspace | Edit::Delete => history::Group::Deleting,
+            Edit::Enter | Edit::Paste(_) => history::Group::Standalone,
+        };
+
+        // An edit can only change the lines it touches and join them with
+        // their neighbors
+        let (top, bottom) = self
+            .cursors
+            .iter()
+            .chain([&selection_before])
+            .fold((usize::MAX, 0), |(top, bottom), selection| {
+                (top.min(start(selection).0), bottom.max(end(selection).0))
+            });
+
+        let line_count = self.editor.line_count();
+        let start = top.saturating_sub(1);
+        let end = (bottom + 1).min(line_count.saturating_sub(1));
+
+        let mut before = self.lines(start, end);
+
+        let lines = (!self.decorations.is_empty())
+            .then(|| LineStarts::new(&self.editor));
+        let mut shifts = Vec::new();
+
+        self.at_each_cursor(|editor| {
+            if lines.is_some() {
+                shifts.push(Shift::of(editor, |editor| {
+                    editor.perform(Action::Edit(edit.clone()));
+                }));
+            } else {
+                editor.perform(Action::Edit(edit.clone()));
+            }
+        });
+
+        if let Some(lines) = lines {
+            self.move_decorations(&lines, &shifts);
+        }
+
+        let end = (end + self.editor.line_count())
+            .saturating_sub(line_count)
+            .max(start);
+
+        let mut after = self.lines(start, end);
+
+        if before == after {
+            return;
+        }
+
+        let mut line = start;
+
+        while before.len() > 1 && after.len() > 1 && before[0] == after[0] {
+            let _ = before.remove(0);
+            let _ = after.remove(0);
+
+            line += 1;
+        }
+
+        while before.len() > 1
+            && after.len() > 1
+            && before.last() == after.last()
+        {
+            let _ = before.pop();
+            let _ = after.pop();
+        }
+
+        self.history.record(
+            Change {
+                line,
+                before,
+                after,
+                selection_before,
+                selection_after: self.selection(),
+            },
+            group,
+        );
+
+        self.update_matches();
+    }
+
+    fn undo(&mut self) {
+        let Some(change) = self.history.undo().cloned() else {
+            return;
+        };
+
+        self.replace(
+            change.line,
+            &change.after,
+            &change.before,
+            change.selection_before,
+        );
+    }
+
+    fn redo(&mut self) {
+        let Some(change) = self.history.redo().cloned() else {
+            return;
+        };
+
+        self.replace(
+            change.line,
+            &change.before,
+            &change.after,
+            change.selection_after,
+        );
+    }
+
+    fn replace(
+        &mut self,
+        line: usize,
+        old: &[String],
+        new: &[String],
+        (cursor, anchor): Selection,
+    ) {
+        let last_line = line + old.len().saturating_sub(1);
+        let last_column = old.last().map_or(0, String::len);
+
+        let (old, new) = (old.join("\n"), new.join("\n"));
+        let lines = (!self.decorations.is_empty())
+            .then(|| LineStarts::new(&self.editor));
+
+        self.editor
+            .set_cursor((last_line, last_column), Some((line, 0)));
+        self.editor
+            .perform(Action::Edit(Edit::Paste(Arc::new(new.clone()))));
+        self.editor.set_cursor(cursor, anchor);
+
+        if let Some(lines) = lines {
+            let shift = Shift::between(lines.offset((line, 0)), &old, &new);
+
+            self.move_decorations(&lines, &[shift]);
+        }
+
+        self.cursors.clear();
+        self.update_matches();
+    }
+
+    /// Moves the [`Decoration`]s along with the given [`Shift`]s of the
+    /// text, which had the given [`LineStarts`] before them.
+    ///
+    /// Decorations whose text is removed entirely are dropped.
+    fn move_decorations(&mut self, lines: &LineStarts, shifts: &[Shift]) {
+        let moved = LineStarts::new(&self.editor);
+
+        self.decorations.retain_mut(|decoration| {
+            let (start, end) = if decoration.start <= decoration.end {
+                (decoration.start, decoration.end)
+            } else {
+                (decoration.end, decoration.start)
+            };
+
+            let (mut start, mut end) = (lines.offset(start), lines.offset(end));
+            let is_empty = start == end;
+
+            for shift in shifts {
+                start = shift.start(start);
+                end = shift.end(end).max(start);
+            }
+
+            decoration.start = moved.position(start);
+            decoration.end = moved.position(end);
+
+            is_empty || start < end
+        });
+    }
+
+    #[cfg(feature = "search")]
+    fn find(&mut self, search: Option<Search>) {
+        self.matcher = search.as_ref().and_then(Matcher::new);
+        self.search = search;
+
+        self.update_matches();
+    }
+
+    #[cfg(not(feature = "search"))]
+    fn update_matches(&mut self) {}
+
+    #[cfg(feature = "search")]
+    fn update_matches(&mut self) {
+        let Some(matcher) = &self.matcher else {
+            self.matches.clear();
+            return;
+        };
+
+        self.matches = (0..self.editor.line_count())
+            .flat_map(|line| {
+                self.editor.line(line).into_iter().flat_map(move |text| {
+                    matcher.find(text).map(move |range| Match { line, range })
+                })
+            })
+            .collect();
+    }
+
+    #[cfg(feature = "search")]
+    fn find_next(&mut self) {
+        let selection = self.selection();
+        let end = end(&selection);
+
+        let next = self
+            .matches
+            .iter()
+            .find(|found| (found.line, found.range.start) >= end)
+            .or_else(|| self.matches.first());
+
+        if let Some((cursor, anchor)) = next.map(Match::selection) {
+            self.cursors.clear();
+            self.editor.set_cursor(cursor, anchor);
+        }
+    }
+
+    #[cfg(feature = "search")]
+    fn find_previous(&mut self) {
+        let selection = self.selection();
+        let start = start(&selection);
+
+        let previous = self
+            .matches
+            .iter()
+            .rev()
+            .find(|found| (found.line, found.range.start) < start)
+            .or_else(|| self.matches.last());
+
+        if let Some((cursor, anchor)) = previous.map(Match::selection) {
+            self.cursors.clear();
+            self.editor.set_cursor(cursor, anchor);
+        }
+    }
+
+    #[cfg(feature = "search")]
+    fn replace_match(&mut self, replacement: &str) {
+        let selection = self.selection();
+
+        let Some(found) = self
+            .matches
+            .iter()
+            .find(|found| found.selection() == selection)
+        else {
+            self.find_next();
+            return;
+        };
+
+        let (Some(matcher), Some(line)) =
+            (&self.matcher, self.editor.line(found.line))
+        else {
+            return;
+        };
+
+        let replacement =
+            matcher.replacement(line, found.range.clone(), replacement);
+
+        self.cursors.clear();
+        self.edit(Edit::Paste(Arc::new(replacement)));
+        self.find_next();
+    }
+
+    #[cfg(feature = "search")]
+    fn replace_all(&mut self, replacement: &str) {
+        let (Some(matcher), Some(first), Some(last)) =
+            (&self.matcher, self.matches.first(), self.matches.last())
+        else {
+            return;
+        };
+
+        let line = first.line;
+        let before = self.lines(line, last.line);
+
+        let after = before
+            .iter()
+            .map(|text| {
+                let mut replaced = String::new();
+                let mut last_end = 0;
+
+                for range in matcher.find(text) {
+                    replaced.push_str(&text[last_end..range.start]);
+                    replaced.push_str(&matcher.replacement(
+                        text,
+                        range.clone(),
+                        replacement,
+                    ));
+
+                    last_end = range.end;
+                }
+
+                replaced.push_str(&text[last_end..]);
+                replaced
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+            .split('\n')
+            .map(str::to_owned)
+            .collect::<Vec<_>>();
+
+        if before == after {
+            return;
+        }
+
+        let selection_before = self.selection();
+
+        self.replace(line, &before, &after, (selection_before.0, None));
+
+        self.history.record(
+            Change {
+                line,
+                before,
+                after,
+                selection_before,
+                selection_after: self.selection(),
+            },
+            history::Group::Standalone,
+        );
+    }
+
+    fn add_cursor(&mut self, position: Point) {
+        self.cursors.push(self.selection());
+        self.editor.perform(Action::Click(position));
+
+        let selection = self.selection();
+
+        self.cursors.retain(|cursor| !overlaps(cursor, &selection));
+    }
+
+    fn select_next_occurrence(&mut self) {
+        let selection = self.selection();
+
+        if selection.1.is_none() {
+            self.editor.perform(Action::SelectWord);
+            return;
+        }
+
+        let Some(occurrence) = self
+            .editor
+            .selection()
+            .filter(|text| !text.is_empty() && !text.contains('\n'))
+        else {
+            return;
+        };
+
+        let occurrence = occurrence.as_str();
+        let from = end(&selection);
+        let line_count = self.editor.line_count();
+
+        // Search until the end of the text, and then wrap around
+        let next = (0..=line_count)
+            .flat_map(|i| {
+                let line = (from.0 + i) % line_count;
+                let column = if i == 0 { from.1 } else { 0 };
+
+                self.editor.line(line).into_iter().flat_map(move |text| {
+                    text.match_indices(occurrence)
+                        .map(|(start, found)| start..start + found.len())
+                        .filter(move |range| range.start >= column)
+                        .map(move |range| Match { line, range })
+                })
+            })
+            .map(|found| found.selection())
+            .find(|next| {
+                self.cursors
+                    .iter()
+                    .chain([&selection])
+                    .all(|selection| !overlaps(selection, next))
+            });
+
+        if let Some((cursor, anchor)) = next {
+            self.cursors.push(selection);
+            self.editor.set_cursor(cursor, anchor);
+        }
+    }
+
+    /// Runs the given function on the editor once for each cursor, from
+    /// the last to the first one in the text.
+    ///
+    /// Cursors that end up overlapping are merged together.
+    fn at_each_cursor(&mut self, mut f: impl FnMut(&mut R::Editor)) {
+        if self.cursors.is_empty() {
+            f(&mut self.editor);
+            return;
+        }
+
+        let main = self.selection();
+
+        let mut selections: Vec<_> =
+            self.cursors.drain(..).chain([main]).enumerate().collect();
+
+        selections
+            .sort_by_key(|(_, selection)| std::cmp::Reverse(start(selection)));
+
+        // An edit only shifts the text after it, so the distance to the end
+        // of the text of the cursors already visited does not change
+        let mut distances = vec![(0, None); selections.len()];
+
+        for (index, (cursor, anchor)) in selections {
+            self.editor.set_cursor(cursor, anchor);
+
+            f(&mut self.editor);
+
+            let (cursor, anchor) = self.selection();
+            let lines = LineStarts::new(&self.editor);
+            let length = lines.length();
+
+            distances[index] = (
+                length - lines.offset(cursor),
+                anchor.map(|anchor| length - lines.offset(anchor)),
+            );
+        }
+
+        let lines = LineStarts::new(&self.editor);
+        let length = lines.length();
+
+        let mut selections: Vec<Selection> = distances
+            .into_iter()
+            .map(|(cursor, anchor)| {
+                (
+                    lines.position(length.saturating_sub(cursor)),
+                    anchor.map(|anchor| {
+                        lines.position(length.saturating_sub(anchor))
+                    }),
+                )
+            })
+            .collect();
+
+        // The main cursor was the last one
+        let Some(main) = selections.pop() else {
+            return;
+        };
+
+        self.editor.set_cursor(main.0, main.1);
+
+        for selection in selections {
+            if self
+                .cursors
+                .iter()
+                .chain([&main])
+                .all(|cursor| !overlaps(cursor, &selection))
+            {
+                self.cursors.push(selection);
+            }
+        }
+    }
+
+    fn selection(&self) -> Selection {
+        let cursor = self.editor.cursor_position();
+        let anchor = self
+            .editor
+            .selection_anchor()
+            .filter(|anchor| *anchor != cursor);
+
+        (cursor, anchor)
+    }
+
+    fn lines(&self, start: usize, end: usize) -> Vec<String> {
+        (start..=end)
+            .map_while(|index| self.editor.line(index).map(str::to_owned))
+            .collect()
+    }
+}
+
+/// The cursor position and selection anchor of a [`Content`].
+type Selection = ((usize, usize), Option<(usize, usize)>);
+
+/// Returns the position where the given [`Selection`] starts.
+fn start((cursor, anchor): &Selection) -> (usize, usize) {
+    anchor.map_or(*cursor, |anchor| anchor.min(*cursor))
+}
+
+/// Returns the position where the given [`Selection`] ends.
+fn end((cursor, anchor): &Selection) -> (usize, usize) {
+    anchor.map_or(*cursor, |anchor| anchor.max(*cursor))
+}
+
+/// The offsets where the lines of the text of an editor start.
+///
+/// An offset counts the bytes of the text before it, with lines joined by
+/// `'\n'`.
+struct LineStarts(Vec<usize>);
+
+impl LineStarts {
+    fn new(editor: &impl text::Editor) -> Self {
+        let mut starts = vec![0];
+
+        for index in 0..editor.line_count() {
+            let length = editor.line(index).map_or(0, str::len);
+
+            starts.push(starts[index] + length + 1);
+        }
+
+        Self(starts)
+    }
+
+    /// Returns the length of the whole text.
+    fn length(&self) -> usize {
+        self.0.last().map_or(0, |end| end.saturating_sub(1))
+    }
+
+    /// Returns the offset of the given position, clamped to its line.
+    fn offset(&self, (line, column): (usize, usize)) -> usize {
+        match (self.0.get(line), self.0.get(line + 1)) {
+            (Some(start), Some(next)) => start + column.min(next - start - 1),
+            _ => self.length(),
+        }
+    }
+
+    /// Returns the position of the given offset, clamped to the text.
+    fn position(&self, offset: usize) -> (usize, usize) {
+        let offset = offset.min(self.length());
+
+        let line = self
+            .0
+            .partition_point(|start| *start <= offset)
+            .saturating_sub(1)
+            .min(self.0.len().saturating_sub(2));
+
+        (line, offset - self.0[line])
+    }
+}
+
+/// The replacement of the text between two offsets by some other text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Shift {
+    from: usize,
+    old_to: usize,
+    new_to: usize,
+}
+
+impl Shift {
+    /// Returns the [`Shift`] of the text caused by performing an edit at
+    /// the cursor of the given editor.
+    fn of<E: text::Editor>(editor: &mut E, edit: impl FnOnce(&mut E)) -> Self {
+        let selection = (editor.cursor_position(), editor.selection_anchor());
+
+        let lines = LineStarts::new(editor);
+        let (from, to) = (
+            lines.offset(start(&selection)),
+            lines.offset(end(&selection)),
+        );
+        let old_length = lines.length();
+
+        edit(editor);
+
+        let lines = LineStarts::new(editor);
+        let cursor = lines.offset(editor.cursor_position());
+        let new_length = lines.length();
+
+        // The edit replaces the selection, leaving the cursor at the end of
+        // the new text
+        let prefix = from.min(cursor);
+        let suffix = (old_length - to).min(new_length - cursor);
+
+        Self {
+            from: prefix,
+            old_to: old_length - suffix,
+            new_to: new_length - suffix,
+        }
+    }
+
+    /// Returns the [`Shift`] of replacing the old text at the given offset
+    /// with the new one.
+    fn between(offset: usize, old: &str, new: &str) -> Self {
+        let prefix: usize = old
+            .chars()
+            .zip(new.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum();
+
+        let suffix: usize = old[prefix..]
+            .chars()
+            .rev()
+            .zip(new[prefix..].chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum();
+
+        Self {
+            from: offset + prefix,
+            old_to: offset + old.len() - suffix,
+            new_to: offset + new.len() - suffix,
+        }
+    }
+
+    /// Returns where the text starting at the given offset starts after the
+    /// [`Shift`].
+    ///
+    /// Text inserted at the offset ends up before it.
+    fn start(&self, offset: usize) -> usize {
+        if offset < self.from {
+            offset
+        } else if offset >= self.old_to {
+            offset - self.old_to + self.new_to
+        } else {
+            self.new_to
+        }
+    }
+
+    /// Returns where the text ending at the given offset ends after the
+    /// [`Shift`].
+    ///
+    /// Text inserted at the offset ends up after it.
+    fn end(&self, offset: usize) -> usize {
+        if offset <= self.from {
+            offset
+        } else if offset >= self.old_to {
+            offset - self.old_to + self.new_to
+        } else {
+            self.from
+        }
+    }
+}
+
+fn overlaps(a: &Selection, b: &Selection) -> bool {
+    start(a) == start(b) || (start(a) < end(b) && start(b) < end(a))
+}
+
+/// A match of a [`Search`] in a [`Content`].
+///
+/// [`Search`]: crate::core::text::editor::Search
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Match {
+    line: usize,
+    range: Range<usize>,
+}
+
+impl Match {
+    /// Returns the [`Selection`] that selects the [`Match`].
+    fn selection(&self) -> Selection {
+        (
+            (self.line, self.range.end),
+            Some((self.line, self.range.start)),
+        )
+    }
+}
+
+/// An undoable edit of a [`Content`].
+///
+/// It replaces the `before` lines starting at `line` with the `after` ones.
+#[derive(Debug, Clone)]
+struct Change {
+    line: usize,
+    before: Vec<String>,
+    after: Vec<String>,
+    selection_before: Selection,
+    selection_after: Selection,
+}
+
+impl history::Step for Change {
+    fn merge(&mut self, next: Self) -> Result<(), Self> {
+        let end = self.line + self.after.len();
+        let next_end = next.line + next.before.len();
+
+        let is_contiguous = next.selection_before == self.selection_after
+            && next.line <= end
+            && next_end >= self.line;
+
+        if !is_contiguous {
+            return Err(next);
+        }
+
+        // Lines outside of the current change are untouched by it, so they
+        // can be added to both of its sides
+        if next.line < self.line {
+            let lines = &next.before[..self.line - next.line];
+
+            let _ = self.before.splice(0..0, lines.iter().cloned());
+            let _ = self.after.splice(0..0, lines.iter().cloned());
+
+            self.line = next.line;
+        }
+
+        if next_end > end {
+            let lines = &next.before[next.before.len() - (next_end - end)..];
+
+            self.before.extend_from_slice(lines);
+            self.after.extend_from_slice(lines);
+        }
+
+        let start = next.line - self.line;
+
+        let _ = self
+            .after
+            .splice(start..start + next.before.len(), next.after);
+
+        self.selection_after = next.selection_after;
+
+        Ok(())
+    }
+}
+
 impl<Renderer> Default for Content<Renderer>
 where
     Renderer: text::Renderer,
@@ -326,9 +1321,14 @@ pub struct State<Highlighter: text::Highlighter> {
     last_click: Option<mouse::Click>,
     drag_click: Option<mouse::click::Kind>,
     partial_scroll: f32,
+    preedit: Option<Preedit>,
+    keyboard_modifiers: keyboard::Modifiers,
+    gutter: Gutter,
+    hovered_decoration: Option<(usize, Point)>,
     highlighter: RefCell<Highlighter>,
     highlighter_settings: Highlighter::Settings,
     highlighter_format_address: usize,
+    requests: accessibility::Requests,
 }
 
 impl<Highlighter: text::Highlighter> State<Highlighter> {
@@ -355,11 +1355,16 @@ where
             last_click: None,
             drag_click: None,
             partial_scroll: 0.0,
+            preedit: None,
+            keyboard_modifiers: keyboard::Modifiers::default(),
+            gutter: Gutter::default(),
+            hovered_decoration: None,
             highlighter: RefCell::new(Highlighter::new(
                 &self.highlighter_settings,
             )),
             highlighter_settings: self.highlighter_settings.clone(),
             highlighter_format_address: self.highlighter_format as usize,
+            requests: accessibility::Requests::default(),
         })
     }
 
@@ -397,8 +1402,10 @@ where
 
         let limits = limits.height(self.height);
 
+        state.gutter = self.gutter(renderer, internal.editor.line_count());
+
         internal.editor.update(
-            limits.shrink(self.padding).max(),
+            limits.shrink(self.text_padding(state.gutter)).max(),
             self.font.unwrap_or_else(|| renderer.default_font()),
             self.text_size.unwrap_or_else(|| renderer.default_size()),
             self.line_height,
@@ -422,36 +1429,115 @@ where
         }
     }
 
+    fn operate(
+        &self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Highlighter>>();
+
+        operation.accessibility(
+            None,
+            layout.bounds(),
+            &accessibility::Node::new(accessibility::Role::MultilineTextInput)
+                .value(self.content.text())
+                .focused(state.is_focused)
+                .disabled(self.on_edit.is_none())
+                .action(accessibility::Action::Focus),
+            Some(&mut state.requests),
+        );
+    }
+
     fn on_event(
         &mut self,
         tree: &mut widget::Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Highlighter>>();
+
+        for request in state.requests.take() {
+            if let accessibility::Request::Focus = request {
+                state.is_focused = true;
+            }
+        }
+
+        if let Event::Mouse(_) = event {
+            let hovered =
+                self.hovered_decoration(state.gutter, layout.bounds(), cursor);
+
+            if hovered.map(|(index, _)| index)
+                != state.hovered_decoration.map(|(index, _)| index)
+            {
+                state.hovered_decoration = hovered;
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
         let Some(on_edit) = self.on_edit.as_ref() else {
             return event::Status::Ignored;
         };
 
-        let state = tree.state.downcast_mut::<State<Highlighter>>();
+        let padding = self.text_padding(state.gutter);
+
+        if let Event::Window(_, window::Event::RedrawRequested(_)) = event {
+            if state.is_focused {
+                let bounds = layout.bounds();
+
+                let position = match self.content.0.borrow().editor.cursor() {
+                    Cursor::Caret(position) => position,
+                    Cursor::Selection(ranges) => ranges
+                        .first()
+                        .map(Rectangle::position)
+                        .unwrap_or(Point::ORIGIN),
+                };
+
+                let line_height = self.line_height.to_absolute(
+                    self.text_size.unwrap_or_else(|| renderer.default_size()),
+                );
+
+                shell.request_input_method(InputMethod::Enabled {
+                    cursor: Rectangle::new(
+                        position
+                            + Vector::new(
+                                bounds.x + padding.left,
+                                bounds.y + padding.top,
+                            ),
+                        Size::new(1.0, line_height.into()),
+                    ),
+                });
+            }
+
+            return event::Status::Ignored;
+        }
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
+            event
+        {
+            state.keyboard_modifiers = modifiers;
+        }
 
-        let Some(update) = Update::from_event(
-            event,
-            state,
-            layout.bounds(),
-            self.padding,
-            cursor,
-        ) else {
+        let Some(update) =
+            Update::from_event(event, state, layout.bounds(), padding, cursor)
+        else {
             return event::Status::Ignored;
         };
 
         match update {
             Update::Click(click) => {
                 let action = match click.kind() {
+                    mouse::click::Kind::Single
+                        if state.keyboard_modifiers.alt() =>
+                    {
+                        Action::AddCursor(click.position())
+                    }
                     mouse::click::Kind::Single => {
                         Action::Click(click.position())
                     }
@@ -473,13 +1559,58 @@ where
                     lines: lines as i32,
                 }));
             }
-            Update::Unfocus => {
+            Update::Escape if self.content.cursor_count() > 1 => {
+                shell.publish(on_edit(Action::ClearCursors));
+            }
+            Update::Unfocus | Update::Escape => {
                 state.is_focused = false;
                 state.drag_click = None;
+                state.preedit = None;
             }
             Update::Release => {
                 state.drag_click = None;
             }
+            Update::Gutter(position) => {
+                let internal = self.content.0.borrow();
+
+                let Some(line) = internal
+                    .editor
+                    .visible_lines()
+                    .into_iter()
+                    .rev()
+                    .find(|(_, y)| *y <= position.y)
+                    .map(|(line, _)| line)
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let gutter = state.gutter;
+                let x = position.x + gutter.width();
+
+                if self.folding && x >= gutter.markers + gutter.numbers {
+                    let is_folded = internal
+                        .editor
+                        .folds()
+                        .iter()
+                        .any(|fold| fold.start == line + 1);
+
+                    if is_folded {
+                        shell.publish(on_edit(Action::Unfold(line)));
+                    } else {
+                        let lines = folding_range(
+                            &internal.editor,
+                            &*state.highlighter.borrow(),
+                            line,
+                        );
+
+                        if !lines.is_empty() {
+                            shell.publish(on_edit(Action::Fold(lines)));
+                        }
+                    }
+                } else if let Some(on_gutter_click) = &self.on_gutter_click {
+                    shell.publish(on_gutter_click(line));
+                }
+            }
             Update::Action(action) => {
                 shell.publish(on_edit(action));
             }
@@ -503,6 +1634,13 @@ where
                     ))));
                 }
             }
+            Update::InputMethod(ime) => {
+                if let Some(text) = Preedit::update(&mut state.preedit, ime) {
+                    shell.publish(on_edit(Action::Edit(Edit::Paste(
+                        Arc::new(text),
+                    ))));
+                }
+            }
         }
 
         event::Status::Captured
@@ -553,24 +1691,210 @@ where
             style.background,
         );
 
+        let padding = self.text_padding(state.gutter);
+        let translation =
+            Vector::new(bounds.x + padding.left, bounds.y + padding.top);
+
+        let visible_lines = internal.editor.visible_lines();
+
+        // The line before the first visible one may be wrapped into view
+        let first_line = visible_lines
+            .first()
+            .map_or(0, |(line, _)| line.saturating_sub(1));
+        let last_line = visible_lines.last().map_or(0, |(line, _)| *line);
+
+        let matches = internal
+            .matches
+            .iter()
+            .skip_while(|found| found.line < first_line)
+            .take_while(|found| found.line <= last_line)
+            .filter_map(|found| {
+                let (cursor, anchor) = found.selection();
+
+                internal.editor.cursor_at(cursor, anchor)
+            });
+
+        for range in matches
+            .filter_map(|cursor| match cursor {
+                Cursor::Selection(ranges) => Some(ranges),
+                Cursor::Caret(_) => None,
+            })
+            .flatten()
+            .filter_map(|range| bounds.intersection(&(range + translation)))
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: range,
+                    ..renderer::Quad::default()
+                },
+                style.highlight,
+            );
+        }
+
+        for decoration in &internal.decorations {
+            let Some(background) = decoration.background else {
+                continue;
+            };
+
+            for region in regions(&internal.editor, decoration, &visible_lines)
+                .filter_map(|region| {
+                    bounds.intersection(&(region + translation))
+                })
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: region,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+        }
+
         renderer.fill_editor(
             &internal.editor,
-            bounds.position()
-                + Vector::new(self.padding.left, self.padding.top),
+            bounds.position() + Vector::new(padding.left, padding.top),
             defaults.text_color,
             *viewport,
         );
 
-        let translation = Vector::new(
-            bounds.x + self.padding.left,
-            bounds.y + self.padding.top,
-        );
+        for decoration in &internal.decorations {
+            let Some(underline) = decoration.underline else {
+                continue;
+            };
+
+            for region in regions(&internal.editor, decoration, &visible_lines)
+            {
+                draw_underline(
+                    renderer,
+                    region + translation,
+                    underline,
+                    bounds,
+                );
+            }
+        }
+
+        if state.gutter.width() > 0.0 {
+            let gutter = state.gutter;
+            let font = self.font.unwrap_or_else(|| renderer.default_font());
+            let size =
+                self.text_size.unwrap_or_else(|| renderer.default_size());
+
+            let text = |content: String, font, horizontal_alignment| Text {
+                content,
+                bounds: Size::new(
+                    f32::INFINITY,
+                    self.line_height.to_absolute(size).into(),
+                ),
+                size,
+                line_height: self.line_height,
+                font,
+                horizontal_alignment,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: text::Shaping::Advanced,
+            };
+
+            let clip_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + padding.top,
+                width: bounds.width,
+                height: bounds.height - padding.vertical(),
+            };
+
+            let (cursor_line, _) = internal.editor.cursor_position();
+            let folds = internal.editor.folds();
+            let highlighter = state.highlighter.borrow();
+
+            for (line, y) in visible_lines {
+                let position =
+                    Point::new(bounds.x + self.padding.left, translation.y + y);
+
+                if let Some(marker) =
+                    self.markers.as_ref().and_then(|markers| markers(line))
+                {
+                    renderer.fill_text(
+                        text(
+                            marker.code_point.to_string(),
+                            marker.font,
+                            alignment::Horizontal::Center,
+                        ),
+                        position + Vector::new(gutter.markers / 2.0, 0.0),
+                        marker.color,
+                        clip_bounds,
+                    );
+                }
+
+                if self.line_numbers {
+                    renderer.fill_text(
+                        text(
+                            (line + 1).to_string(),
+                            font,
+                            alignment::Horizontal::Right,
+                        ),
+                        position
+                            + Vector::new(gutter.markers + gutter.numbers, 0.0),
+                        if line == cursor_line {
+                            style.value
+                        } else {
+                            style.line_number
+                        },
+                        clip_bounds,
+                    );
+                }
+
+                if !self.folding {
+                    continue;
+                }
+
+                let is_folded = folds.iter().any(|fold| fold.start == line + 1);
+
+                if is_folded || can_fold(&internal.editor, &*highlighter, line)
+                {
+                    renderer.fill_text(
+                        text(
+                            if is_folded { "▸" } else { "▾" }.to_owned(),
+                            font,
+                            alignment::Horizontal::Center,
+                        ),
+                        position
+                            + Vector::new(
+                                gutter.markers
+                                    + gutter.numbers
+                                    + gutter.folds / 2.0,
+                                0.0,
+                            ),
+                        style.line_number,
+                        clip_bounds,
+                    );
+                }
+
+                let end = internal.editor.line(line).map_or(0, str::len);
+
+                if let Some(Cursor::Caret(end)) = internal
+                    .editor
+                    .cursor_at((line, end), None)
+                    .filter(|_| is_folded)
+                {
+                    renderer.fill_text(
+                        text("…".to_owned(), font, alignment::Horizontal::Left),
+                        end + translation + Vector::new(size.0 / 2.0, 0.0),
+                        style.line_number,
+                        clip_bounds,
+                    );
+                }
+            }
+        }
 
         if state.is_focused {
-            match internal.editor.cursor() {
-                Cursor::Caret(position) => {
-                    let cursor =
-                        Rectangle::new(
+            let cursors =
+                internal.cursors.iter().filter_map(|(cursor, anchor)| {
+                    internal.editor.cursor_at(*cursor, *anchor)
+                });
+
+            for cursor in cursors.chain([internal.editor.cursor()]) {
+                match cursor {
+                    Cursor::Caret(position) => {
+                        let cursor = Rectangle::new(
                             position + translation,
                             Size::new(
                                 1.0,
@@ -582,36 +1906,100 @@ where
                             ),
                         );
 
-                    if let Some(clipped_cursor) = bounds.intersection(&cursor) {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: Rectangle {
-                                    x: clipped_cursor.x.floor(),
-                                    y: clipped_cursor.y,
-                                    width: clipped_cursor.width,
-                                    height: clipped_cursor.height,
+                        if let Some(clipped_cursor) =
+                            bounds.intersection(&cursor)
+                        {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: Rectangle {
+                                        x: clipped_cursor.x.floor(),
+                                        y: clipped_cursor.y,
+                                        width: clipped_cursor.width,
+                                        height: clipped_cursor.height,
+                                    },
+                                    ..renderer::Quad::default()
                                 },
-                                ..renderer::Quad::default()
-                            },
-                            style.value,
-                        );
+                                style.value,
+                            );
+                        }
                     }
-                }
-                Cursor::Selection(ranges) => {
-                    for range in ranges.into_iter().filter_map(|range| {
-                        bounds.intersection(&(range + translation))
-                    }) {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: range,
-                                ..renderer::Quad::default()
-                            },
-                            style.selection,
-                        );
+                    Cursor::Selection(ranges) => {
+                        for range in ranges.into_iter().filter_map(|range| {
+                            bounds.intersection(&(range + translation))
+                        }) {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: range,
+                                    ..renderer::Quad::default()
+                                },
+                                style.selection,
+                            );
+                        }
                     }
                 }
             }
         }
+
+        if let Some(preedit) =
+            state.preedit.as_ref().filter(|_| state.is_focused)
+        {
+            let position = match internal.editor.cursor() {
+                Cursor::Caret(position) => position,
+                Cursor::Selection(ranges) => ranges
+                    .first()
+                    .map(Rectangle::position)
+                    .unwrap_or(Point::ORIGIN),
+            };
+
+            let text = preedit.text(
+                self.font.unwrap_or_else(|| renderer.default_font()),
+                self.text_size.unwrap_or_else(|| renderer.default_size()),
+                self.line_height,
+            );
+
+            preedit.draw(
+                renderer,
+                text,
+                position + translation,
+                Rectangle {
+                    x: bounds.x + padding.left,
+                    y: bounds.y + padding.top,
+                    width: bounds.width - padding.horizontal(),
+                    height: bounds.height - padding.vertical(),
+                },
+                style.value,
+                style.background,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        _layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_ref::<State<Highlighter>>();
+
+        let (index, position) = state.hovered_decoration?;
+        let content = self
+            .content
+            .0
+            .borrow()
+            .decorations
+            .get(index)?
+            .tooltip
+            .clone()?;
+
+        Some(overlay::Element::new(Box::new(Tooltip {
+            position: position + translation,
+            content,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.line_height,
+            class: &self.class,
+        })))
     }
 
     fn mouse_interaction(
@@ -656,11 +2044,14 @@ enum Update {
     Click(mouse::Click),
     Scroll(f32),
     Unfocus,
+    Escape,
     Release,
+    Gutter(Point),
     Action(Action),
     Copy,
     Cut,
     Paste,
+    InputMethod(keyboard::Ime),
 }
 
 impl Update {
@@ -679,7 +2070,12 @@ impl Update {
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
                     if let Some(cursor_position) = cursor.position_in(bounds) {
                         let cursor_position = cursor_position
-                            - Vector::new(padding.top, padding.left);
+                            - Vector::new(padding.left, padding.top);
+
+                        if cursor_position.x < 0.0 && state.gutter.width() > 0.0
+                        {
+                            return Some(Update::Gutter(cursor_position));
+                        }
 
                         let click = mouse::Click::new(
                             cursor_position,
@@ -699,7 +2095,7 @@ impl Update {
                 mouse::Event::CursorMoved { .. } => match state.drag_click {
                     Some(mouse::click::Kind::Single) => {
                         let cursor_position = cursor.position_in(bounds)?
-                            - Vector::new(padding.top, padding.left);
+                            - Vector::new(padding.left, padding.top);
 
                         action(Action::Drag(cursor_position))
                     }
@@ -739,7 +2135,7 @@ impl Update {
                             return edit(Edit::Delete);
                         }
                         keyboard::Key::Named(key::Named::Escape) => {
-                            return Some(Self::Unfocus);
+                            return Some(Self::Escape);
                         }
                         keyboard::Key::Character("c")
                             if modifiers.command() =>
@@ -756,6 +2152,25 @@ impl Update {
                         {
                             return Some(Self::Paste);
                         }
+                        keyboard::Key::Character("z" | "Z")
+                            if modifiers.command() =>
+                        {
+                            return action(if modifiers.shift() {
+                                Action::Redo
+                            } else {
+                                Action::Undo
+                            });
+                        }
+                        keyboard::Key::Character("y")
+                            if modifiers.command() =>
+                        {
+                            return action(Action::Redo);
+                        }
+                        keyboard::Key::Character("d")
+                            if modifiers.command() =>
+                        {
+                            return action(Action::SelectNextOccurrence);
+                        }
                         _ => {}
                     }
 
@@ -794,6 +2209,9 @@ impl Update {
 
                     None
                 }
+                keyboard::Event::Ime(ime) if state.is_focused => {
+                    Some(Self::InputMethod(ime))
+                }
                 _ => None,
             },
             _ => None,
@@ -843,6 +2261,314 @@ pub struct Style {
     pub value: Color,
     /// The [`Color`] of the selection of the text input.
     pub selection: Color,
+    /// The [`Color`] of the search matches of the text input.
+    pub highlight: Color,
+    /// The [`Color`] of the line numbers of the text input.
+    pub line_number: Color,
+}
+
+/// A marker displayed in the gutter of a [`TextEditor`], next to a line.
+///
+/// It can be used to show breakpoints or diagnostics, for instance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Marker<Font> {
+    /// The font that will be used to display the [`Marker`].
+    pub font: Font,
+    /// The unicode code point of the [`Marker`].
+    pub code_point: char,
+    /// The [`Color`] of the [`Marker`].
+    pub color: Color,
+}
+
+/// Returns the regions of the visible lines covered by the given
+/// [`Decoration`], relative to the text of the editor.
+fn regions<'a>(
+    editor: &'a impl text::Editor,
+    decoration: &'a Decoration,
+    visible_lines: &'a [(usize, f32)],
+) -> impl Iterator<Item = Rectangle> + 'a {
+    let (start, end) = if decoration.start <= decoration.end {
+        (decoration.start, decoration.end)
+    } else {
+        (decoration.end, decoration.start)
+    };
+
+    visible_lines
+        .iter()
+        .map(|(line, _)| *line)
+        .filter(move |line| (start.0..=end.0).contains(line))
+        .filter_map(move |line| {
+            let from = if line == start.0 { start.1 } else { 0 };
+            let to = if line == end.0 {
+                end.1
+            } else {
+                editor.line(line)?.len()
+            };
+
+            match editor.cursor_at((line, to), Some((line, from)))? {
+                Cursor::Selection(regions) => Some(regions),
+                Cursor::Caret(_) => None,
+            }
+        })
+        .flatten()
+}
+
+fn draw_underline<Renderer>(
+    renderer: &mut Renderer,
+    region: Rectangle,
+    underline: Underline,
+    clip_bounds: Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    const THICKNESS: f32 = 1.0;
+    const WAVELENGTH: f32 = 4.0;
+
+    let bottom = region.y + region.height - THICKNESS;
+
+    let (color, segments) = match underline {
+        Underline::Straight(color) => (
+            color,
+            vec![Rectangle::new(
+                Point::new(region.x, bottom),
+                Size::new(region.width, THICKNESS),
+            )],
+        ),
+        Underline::Squiggly(color) => {
+            let half = WAVELENGTH / 2.0;
+
+            // Alternate between two heights to approximate a wave
+            let segments = (0..(region.width / half).ceil() as usize)
+                .map(|i| {
+                    let x = i as f32 * half;
+
+                    Rectangle::new(
+                        Point::new(
+                            region.x + x,
+                            bottom - if i % 2 == 0 { THICKNESS } else { 0.0 },
+                        ),
+                        Size::new(half.min(region.width - x), THICKNESS),
+                    )
+                })
+                .collect();
+
+            (color, segments)
+        }
+    };
+
+    for segment in segments
+        .into_iter()
+        .filter_map(|segment| clip_bounds.intersection(&segment))
+    {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: segment,
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+    }
+}
+
+/// The tooltip of a hovered [`Decoration`].
+struct Tooltip<'a, 'b, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    position: Point,
+    content: String,
+    font: Renderer::Font,
+    size: Pixels,
+    line_height: LineHeight,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<'a, 'b, Theme, Renderer> Tooltip<'a, 'b, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    const PADDING: f32 = 5.0;
+
+    fn text<T>(&self, content: T, bounds: Size) -> Text<T, Renderer::Font> {
+        Text {
+            content,
+            bounds,
+            size: self.size,
+            line_height: self.line_height,
+            font: self.font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+        }
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    overlay::Overlay<Message, Theme, Renderer>
+    for Tooltip<'a, 'b, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> layout::Node {
+        let paragraph = Renderer::Paragraph::with_text(self.text(
+            self.content.as_str(),
+            Size::new(bounds.width - Self::PADDING * 2.0, f32::INFINITY),
+        ));
+
+        let size = paragraph
+            .min_bounds()
+            .expand(Size::new(Self::PADDING * 2.0, Self::PADDING * 2.0));
+
+        // Show the tooltip above the cursor, unless there is no room
+        let y = if self.position.y >= size.height {
+            self.position.y - size.height
+        } else {
+            self.position.y + f32::from(self.line_height.to_absolute(self.size))
+        };
+
+        let x = self.position.x.min(bounds.width - size.width).max(0.0);
+
+        layout::Node::new(size).move_to(Point::new(x, y))
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        operation.text(None, layout.bounds(), &self.content);
+        operation.accessibility(
+            None,
+            layout.bounds(),
+            &accessibility::Node::new(accessibility::Role::Label)
+                .label(self.content.clone()),
+            None,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(self.class, Status::Active);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        renderer.fill_text(
+            self.text(
+                self.content.clone(),
+                Size::new(
+                    bounds.width - Self::PADDING * 2.0,
+                    bounds.height - Self::PADDING * 2.0,
+                ),
+            ),
+            bounds.position() + Vector::new(Self::PADDING, Self::PADDING),
+            style.value,
+            bounds,
+        );
+    }
+
+    fn is_over(
+        &self,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        _cursor_position: Point,
+    ) -> bool {
+        false
+    }
+}
+
+/// The widths of the columns of the gutter of a [`TextEditor`].
+#[derive(Debug, Clone, Copy, Default)]
+struct Gutter {
+    markers: f32,
+    numbers: f32,
+    folds: f32,
+    spacing: f32,
+}
+
+impl Gutter {
+    fn width(self) -> f32 {
+        self.markers + self.numbers + self.folds + self.spacing
+    }
+}
+
+/// Returns the range of lines that can be folded into the given line.
+fn folding_range(
+    editor: &impl text::Editor,
+    highlighter: &impl Highlighter,
+    line: usize,
+) -> Range<usize> {
+    if let Some(lines) = highlighter.folding_range(line) {
+        return lines;
+    }
+
+    let Some(level) = editor.line(line).and_then(indentation) else {
+        return line + 1..line + 1;
+    };
+
+    let mut end = line + 1;
+
+    // Blank lines are only folded if followed by a line of the region
+    for (i, text) in (line + 1..).map_while(|i| Some((i, editor.line(i)?))) {
+        match indentation(text) {
+            Some(indentation) if indentation <= level => break,
+            Some(_) => end = i + 1,
+            None => {}
+        }
+    }
+
+    line + 1..end
+}
+
+/// Returns whether the given line has any lines to fold into it.
+fn can_fold(
+    editor: &impl text::Editor,
+    highlighter: &impl Highlighter,
+    line: usize,
+) -> bool {
+    if let Some(lines) = highlighter.folding_range(line) {
+        return !lines.is_empty();
+    }
+
+    let Some(level) = editor.line(line).and_then(indentation) else {
+        return false;
+    };
+
+    (line + 1..)
+        .map_while(|i| editor.line(i))
+        .find_map(indentation)
+        .is_some_and(|indentation| indentation > level)
+}
+
+/// Returns the indentation width of the given line, unless it is blank.
+fn indentation(line: &str) -> Option<usize> {
+    if line.trim().is_empty() {
+        return None;
+    }
+
+    Some(
+        line.chars()
+            .take_while(|c| c.is_whitespace())
+            .map(|c| if c == '\t' { 4 } else { 1 })
+            .sum(),
+    )
 }
 
 /// The theme catalog of a [`TextEditor`].
@@ -887,6 +2613,8 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         placeholder: palette.background.strong.color,
         value: palette.background.base.text,
         selection: palette.primary.weak.color,
+        highlight: palette.secondary.weak.color,
+        line_number: palette.background.strong.color,
     };
 
     match status {
@@ -912,3 +2640,195 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::text::editor::Motion;
+    use crate::core::Font;
+
+    use iced_test::{selector, Simulator};
+
+    type Renderer = iced_test::snapshot::Renderer;
+
+    fn decorated(
+        text: &str,
+        decorations: impl IntoIterator<Item = Decoration>,
+    ) -> Content<Renderer> {
+        let mut content = Content::with_text(text);
+        content.set_decorations(decorations);
+        content
+    }
+
+    fn ranges(
+        content: &Content<Renderer>,
+    ) -> Vec<((usize, usize), (usize, usize))> {
+        content
+            .decorations()
+            .into_iter()
+            .map(|decoration| (decoration.start, decoration.end))
+            .collect()
+    }
+
+    /// Performs the [`Action`] on the [`Content`], laid out like a
+    /// [`TextEditor`] would do first.
+    fn perform(content: &mut Content<Renderer>, action: Action) {
+        content.0.get_mut().editor.update(
+            Size::new(1000.0, 1000.0),
+            Font::default(),
+            Pixels(16.0),
+            LineHeight::default(),
+            &mut highlighter::PlainText::new(&()),
+        );
+
+        content.perform(action);
+    }
+
+    fn move_to(content: &mut Content<Renderer>, column: usize) {
+        perform(content, Action::Move(Motion::Home));
+
+        for _ in 0..column {
+            perform(content, Action::Move(Motion::Right));
+        }
+    }
+
+    fn type_text(content: &mut Content<Renderer>, text: &str) {
+        for c in text.chars() {
+            perform(content, Action::Edit(Edit::Insert(c)));
+        }
+    }
+
+    #[test]
+    fn decorations_move_with_the_text_before_them() {
+        let mut content =
+            decorated("let x = y;", [Decoration::new((0, 8), (0, 9))]);
+
+        type_text(&mut content, "mut ");
+        assert_eq!(ranges(&content), [((0, 12), (0, 13))]);
+
+        perform(&mut content, Action::Edit(Edit::Enter));
+        assert_eq!(ranges(&content), [((1, 8), (1, 9))]);
+
+        perform(&mut content, Action::Edit(Edit::Backspace));
+        assert_eq!(ranges(&content), [((0, 12), (0, 13))]);
+    }
+
+    #[test]
+    fn decorations_grow_with_the_text_inside_them() {
+        let mut content =
+            decorated("let value = 1;", [Decoration::new((0, 4), (0, 9))]);
+
+        move_to(&mut content, 6);
+        type_text(&mut content, "ll");
+        assert_eq!(ranges(&content), [((0, 4), (0, 11))]);
+
+        // Text typed at the edges stays out of the decoration
+        move_to(&mut content, 4);
+        type_text(&mut content, "a");
+        assert_eq!(ranges(&content), [((0, 5), (0, 12))]);
+
+        move_to(&mut content, 12);
+        type_text(&mut content, "b");
+        assert_eq!(ranges(&content), [((0, 5), (0, 12))]);
+    }
+
+    #[test]
+    fn decorations_are_dropped_with_their_text() {
+        let mut content = decorated(
+            "let value = 1;",
+            [
+                Decoration::new((0, 4), (0, 9)),
+                Decoration::new((0, 12), (0, 13)),
+            ],
+        );
+
+        move_to(&mut content, 9);
+        perform(&mut content, Action::Edit(Edit::Backspace));
+        assert_eq!(ranges(&content), [((0, 4), (0, 8)), ((0, 11), (0, 12))]);
+
+        move_to(&mut content, 3);
+
+        for _ in 0..5 {
+            perform(&mut content, Action::Select(Motion::Right));
+        }
+
+        perform(&mut content, Action::Edit(Edit::Backspace));
+        assert_eq!(content.line(0).as_deref(), Some("let = 1;"));
+        assert_eq!(ranges(&content), [((0, 6), (0, 7))]);
+    }
+
+    #[test]
+    fn decorations_move_back_on_undo() {
+        let mut content =
+            decorated("fn main() {}", [Decoration::new((0, 3), (0, 7))]);
+
+        perform(
+            &mut content,
+            Action::Edit(Edit::Paste(Arc::new("use std::io;\n\n".to_owned()))),
+        );
+        assert_eq!(ranges(&content), [((2, 3), (2, 7))]);
+
+        perform(&mut content, Action::Undo);
+        assert_eq!(ranges(&content), [((0, 3), (0, 7))]);
+
+        perform(&mut content, Action::Redo);
+        assert_eq!(ranges(&content), [((2, 3), (2, 7))]);
+    }
+
+    #[test]
+    fn decorations_move_with_every_cursor() {
+        let mut content = decorated(
+            "foo bar foo baz",
+            [
+                Decoration::new((0, 4), (0, 7)),
+                Decoration::new((0, 12), (0, 15)),
+            ],
+        );
+
+        perform(&mut content, Action::SelectNextOccurrence);
+        perform(&mut content, Action::SelectNextOccurrence);
+        assert_eq!(content.cursor_count(), 2);
+
+        type_text(&mut content, "x");
+        assert_eq!(content.line(0).as_deref(), Some("x bar x baz"));
+        assert_eq!(ranges(&content), [((0, 2), (0, 5)), ((0, 8), (0, 11))]);
+    }
+
+    #[test]
+    fn hovering_a_decoration_shows_its_tooltip() {
+        let content = decorated(
+            "let x = y;",
+            [Decoration::new((0, 4), (0, 5)).tooltip("unused variable")],
+        );
+
+        let mut ui: Simulator<'_, (), Theme, Renderer> =
+            Simulator::new(TextEditor::new(&content));
+
+        let region = {
+            let internal = content.0.borrow();
+            let visible_lines = internal.editor.visible_lines();
+
+            let region = regions(
+                &internal.editor,
+                &internal.decorations[0],
+                &visible_lines,
+            )
+            .next();
+
+            region.expect("decoration must be visible")
+        };
+
+        let padding = Vector::new(5.0, 5.0);
+
+        assert!(ui.find(selector::text("unused variable")).is_err());
+
+        ui.point_at(region.center() + padding);
+        assert!(ui.find(selector::text("unused variable")).is_ok());
+
+        ui.point_at(
+            Point::new(region.x + region.width * 4.0, region.y) + padding,
+        );
+        assert!(ui.find(selector::text("unused variable")).is_err());
+    }
+}
@@ -0,0 +1,114 @@
+//! Find the matches of a [`Search`] in some text.
+use crate::core::text::editor::Search;
+
+use regex::{Regex, RegexBuilder};
+
+use std::ops::Range;
+
+/// A compiled [`Search`].
+#[derive(Debug, Clone)]
+pub(super) struct Matcher {
+    regex: Regex,
+    is_regex: bool,
+}
+
+impl Matcher {
+    /// Compiles the given [`Search`].
+    ///
+    /// Returns `None` if the [`Search`] is empty or its pattern is not a
+    /// valid regular expression.
+    pub fn new(search: &Search) -> Option<Self> {
+        if search.pattern.is_empty() {
+            return None;
+        }
+
+        let pattern = if search.is_regex {
+            search.pattern.clone()
+        } else {
+            regex::escape(&search.pattern)
+        };
+
+        let regex = RegexBuilder::new(&pattern)
+            .case_insensitive(!search.is_case_sensitive)
+            .build()
+            .ok()?;
+
+        Some(Self {
+            regex,
+            is_regex: search.is_regex,
+        })
+    }
+
+    /// Returns the byte ranges of the matches in the given line.
+    ///
+    /// Empty matches are ignored.
+    pub fn find<'a>(
+        &'a self,
+        line: &'a str,
+    ) -> impl Iterator<Item = Range<usize>> + 'a {
+        self.regex
+            .find_iter(line)
+            .map(|found| found.range())
+            .filter(|range| !range.is_empty())
+    }
+
+    /// Returns the text that replaces the match at the given range of the
+    /// given line.
+    ///
+    /// The capture groups of a regular expression are expanded in the
+    /// replacement.
+    pub fn replacement(
+        &self,
+        line: &str,
+        range: Range<usize>,
+        replacement: &str,
+    ) -> String {
+        if !self.is_regex {
+            return replacement.to_owned();
+        }
+
+        let mut expanded = String::new();
+
+        if let Some(captures) = self
+            .regex
+            .captures_at(line, range.start)
+            .filter(|captures| {
+                captures.get(0).map(|found| found.range()) == Some(range)
+            })
+        {
+            captures.expand(replacement, &mut expanded);
+        }
+
+        expanded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_search_is_escaped() {
+        let matcher = Matcher::new(&Search::new("a.b")).unwrap();
+
+        assert_eq!(
+            matcher.find("axb A.B a.b").collect::<Vec<_>>(),
+            [4..7, 8..11]
+        );
+        assert_eq!(matcher.replacement("a.b", 0..3, "$1"), "$1");
+    }
+
+    #[test]
+    fn regex_replacement_expands_captures() {
+        let search =
+            Search::new(r"(\w+)=(\w+)").regex(true).case_sensitive(true);
+
+        let matcher = Matcher::new(&search).unwrap();
+
+        assert_eq!(
+            matcher.replacement("a=b key=value", 4..13, "$2=$1"),
+            "value=key"
+        );
+        assert!(Matcher::new(&Search::new("(").regex(true)).is_none());
+    }
+}
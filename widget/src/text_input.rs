@@ -23,13 +23,15 @@ use crate::core::text::{self, Paragraph as _, Text};
 use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget;
-use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::operation::{self, accessibility, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
-    Background, Border, Color, Element, Layout, Length, Padding, Pixels, Point,
-    Rectangle, Shell, Size, Theme, Vector, Widget,
+    Background, Border, Color, Element, InputMethod, Layout, Length, Padding,
+    Pixels, Point, Rectangle, Shell, Size, Theme, Vector, Widget,
 };
+use crate::history::{self, History};
+use crate::preedit::Preedit;
 use crate::runtime::Command;
 
 /// A field that can be filled with text.
@@ -208,6 +210,14 @@ where
         self
     }
 
+    /// Replaces the [`Value`] of the [`TextInput`].
+    ///
+    /// This is useful for widgets that keep their own editing buffer and
+    /// need the [`TextInput`] to process events against it.
+    pub(crate) fn set_value(&mut self, value: Value) {
+        self.value = value;
+    }
+
     /// Lays out the [`TextInput`], overriding its [`Value`] if provided.
     ///
     /// [`Renderer`]: text::Renderer
@@ -489,6 +499,25 @@ where
         } else {
             draw(renderer, text_bounds);
         }
+
+        if let Some(preedit) =
+            state.preedit.as_ref().filter(|_| state.is_focused())
+        {
+            let text = preedit.text(
+                self.font.unwrap_or_else(|| renderer.default_font()),
+                self.size.unwrap_or_else(|| renderer.default_size()),
+                self.line_height,
+            );
+
+            preedit.draw(
+                renderer,
+                text,
+                caret_bounds(text_bounds, value, state).position(),
+                text_bounds,
+                style.value,
+                style.background,
+            );
+        }
     }
 }
 
@@ -517,6 +546,11 @@ where
             state.is_pasting = None;
             state.is_dragging = false;
         }
+
+        // Forget any values typed before the input became secure
+        if self.is_secure {
+            state.history.clear();
+        }
     }
 
     fn size(&self) -> Size<Length> {
@@ -538,7 +572,7 @@ where
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
@@ -546,6 +580,41 @@ where
 
         operation.focusable(state, self.id.as_ref().map(|id| &id.0));
         operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+
+        // Secure inputs only expose what is displayed on screen
+        let value = if self.is_secure {
+            self.value.secure()
+        } else {
+            self.value.clone()
+        };
+
+        operation.text(
+            self.id.as_ref().map(|id| &id.0),
+            layout.bounds(),
+            &value.to_string(),
+        );
+
+        let mut node = accessibility::Node::new(accessibility::Role::TextInput)
+            .focused(state.is_focused())
+            .disabled(self.on_input.is_none())
+            .action(accessibility::Action::Focus)
+            .action(accessibility::Action::SetValue);
+
+        if !self.placeholder.is_empty() {
+            node = node.label(self.placeholder.clone());
+        }
+
+        // Never expose the contents of a secure input
+        if !self.is_secure {
+            node = node.value(self.value.to_string());
+        }
+
+        operation.accessibility(
+            self.id.as_ref().map(|id| &id.0),
+            layout.bounds(),
+            &node,
+            Some(&mut state.requests),
+        );
     }
 
     fn on_event(
@@ -559,6 +628,46 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        for request in state::<Renderer>(tree).requests.take() {
+            let state = state::<Renderer>(tree);
+
+            match request {
+                accessibility::Request::Focus => {
+                    state.focus();
+                }
+                accessibility::Request::SetText(text) => {
+                    let Some(on_input) = &self.on_input else {
+                        continue;
+                    };
+
+                    let before = (self.value.to_string(), state.cursor);
+
+                    self.value = Value::new(&text);
+                    state.move_cursor_to_end();
+
+                    shell.publish((on_input)(text));
+
+                    state.record(
+                        before,
+                        &self.value,
+                        history::Group::Standalone,
+                        self.is_secure,
+                    );
+
+                    replace_paragraph(
+                        renderer,
+                        state,
+                        layout,
+                        &self.value,
+                        self.font,
+                        self.size,
+                        self.line_height,
+                    );
+                }
+                _ => {}
+            }
+        }
+
         let update_cache = |state, value| {
             replace_paragraph(
                 renderer,
@@ -740,6 +849,8 @@ where
                                 );
                             }
 
+                            let before = (self.value.to_string(), state.cursor);
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
                             editor.delete();
@@ -747,6 +858,13 @@ where
                             let message = (on_input)(editor.contents());
                             shell.publish(message);
 
+                            state.record(
+                                before,
+                                &self.value,
+                                history::Group::Standalone,
+                                self.is_secure,
+                            );
+
                             update_cache(state, &self.value);
 
                             return event::Status::Captured;
@@ -769,6 +887,8 @@ where
                                 }
                             };
 
+                            let before = (self.value.to_string(), state.cursor);
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
 
@@ -782,6 +902,12 @@ where
                             shell.publish(message);
 
                             state.is_pasting = Some(content);
+                            state.record(
+                                before,
+                                &self.value,
+                                history::Group::Standalone,
+                                self.is_secure,
+                            );
 
                             update_cache(state, &self.value);
 
@@ -794,6 +920,24 @@ where
 
                             return event::Status::Captured;
                         }
+                        keyboard::Key::Character(
+                            character @ ("z" | "Z" | "y"),
+                        ) if state.keyboard_modifiers.command() => {
+                            let is_redo = character == "y" || modifiers.shift();
+
+                            if let Some((value, cursor)) =
+                                state.travel(&self.value, is_redo)
+                            {
+                                self.value = Value::new(&value);
+                                state.cursor = cursor;
+
+                                shell.publish((on_input)(value));
+
+                                update_cache(state, &self.value);
+                            }
+
+                            return event::Status::Captured;
+                        }
                         _ => {}
                     }
 
@@ -803,6 +947,19 @@ where
                         if let Some(c) =
                             text.chars().next().filter(|c| !c.is_control())
                         {
+                            let before = (self.value.to_string(), state.cursor);
+                            let group = if state
+                                .cursor
+                                .selection(&self.value)
+                                .is_some()
+                            {
+                                history::Group::Standalone
+                            } else {
+                                history::Group::Typing {
+                                    is_whitespace: c.is_whitespace(),
+                                }
+                            };
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
 
@@ -813,6 +970,13 @@ where
 
                             focus.updated_at = Instant::now();
 
+                            state.record(
+                                before,
+                                &self.value,
+                                group,
+                                self.is_secure,
+                            );
+
                             update_cache(state, &self.value);
 
                             return event::Status::Captured;
@@ -840,6 +1004,17 @@ where
                                 }
                             }
 
+                            let before = (self.value.to_string(), state.cursor);
+                            let group = if state
+                                .cursor
+                                .selection(&self.value)
+                                .is_some()
+                            {
+                                history::Group::Standalone
+                            } else {
+                                history::Group::Deleting
+                            };
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
                             editor.backspace();
@@ -847,6 +1022,13 @@ where
                             let message = (on_input)(editor.contents());
                             shell.publish(message);
 
+                            state.record(
+                                before,
+                                &self.value,
+                                group,
+                                self.is_secure,
+                            );
+
                             update_cache(state, &self.value);
                         }
                         keyboard::Key::Named(key::Named::Delete) => {
@@ -867,6 +1049,17 @@ where
                                 }
                             }
 
+                            let before = (self.value.to_string(), state.cursor);
+                            let group = if state
+                                .cursor
+                                .selection(&self.value)
+                                .is_some()
+                            {
+                                history::Group::Standalone
+                            } else {
+                                history::Group::Deleting
+                            };
+
                             let mut editor =
                                 Editor::new(&mut self.value, &mut state.cursor);
                             editor.delete();
@@ -874,6 +1067,13 @@ where
                             let message = (on_input)(editor.contents());
                             shell.publish(message);
 
+                            state.record(
+                                before,
+                                &self.value,
+                                group,
+                                self.is_secure,
+                            );
+
                             update_cache(state, &self.value);
                         }
                         keyboard::Key::Named(key::Named::Home) => {
@@ -1003,6 +1203,41 @@ where
 
                 state.keyboard_modifiers = modifiers;
             }
+            Event::Keyboard(keyboard::Event::Ime(ime)) => {
+                let state = state::<Renderer>(tree);
+
+                if let Some(focus) = &mut state.is_focused {
+                    let Some(on_input) = &self.on_input else {
+                        return event::Status::Ignored;
+                    };
+
+                    focus.updated_at = Instant::now();
+
+                    if let Some(text) = Preedit::update(&mut state.preedit, ime)
+                    {
+                        let before = (self.value.to_string(), state.cursor);
+
+                        let mut editor =
+                            Editor::new(&mut self.value, &mut state.cursor);
+
+                        editor.paste(Value::new(&text));
+
+                        let message = (on_input)(editor.contents());
+                        shell.publish(message);
+
+                        state.record(
+                            before,
+                            &self.value,
+                            history::Group::Standalone,
+                            self.is_secure,
+                        );
+
+                        update_cache(state, &self.value);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
             Event::Window(_, window::Event::Unfocused) => {
                 let state = state::<Renderer>(tree);
 
@@ -1037,6 +1272,19 @@ where
                             ),
                         ));
                     }
+
+                    if self.on_input.is_some() {
+                        let text_bounds =
+                            layout.children().next().unwrap().bounds();
+
+                        shell.request_input_method(InputMethod::Enabled {
+                            cursor: caret_bounds(
+                                text_bounds,
+                                &self.value,
+                                state,
+                            ),
+                        });
+                    }
                 }
             }
             _ => {}
@@ -1183,6 +1431,9 @@ pub struct State<P: text::Paragraph> {
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    history: History<Change>,
+    preedit: Option<Preedit>,
+    requests: accessibility::Requests,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -1192,6 +1443,25 @@ fn state<Renderer: text::Renderer>(
     tree.state.downcast_mut::<State<Renderer::Paragraph>>()
 }
 
+/// An undoable edit of a [`TextInput`].
+#[derive(Debug, Clone)]
+struct Change {
+    before: (String, Cursor),
+    after: (String, Cursor),
+}
+
+impl history::Step for Change {
+    fn merge(&mut self, next: Self) -> Result<(), Self> {
+        if next.before.0 != self.after.0 {
+            return Err(next);
+        }
+
+        self.after = next.after;
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 struct Focus {
     updated_at: Instant,
@@ -1217,6 +1487,9 @@ impl<P: text::Paragraph> State<P> {
             last_click: None,
             cursor: Cursor::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            history: History::new(),
+            preedit: None,
+            requests: accessibility::Requests::default(),
         }
     }
 
@@ -1246,6 +1519,7 @@ impl<P: text::Paragraph> State<P> {
     /// Unfocuses the [`TextInput`].
     pub fn unfocus(&mut self) {
         self.is_focused = None;
+        self.preedit = None;
     }
 
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
@@ -1267,6 +1541,72 @@ impl<P: text::Paragraph> State<P> {
     pub fn select_all(&mut self) {
         self.cursor.select_range(0, usize::MAX);
     }
+
+    /// Returns the accessibility [`Request`]s the [`TextInput`] has yet to
+    /// perform, for widgets that describe it on its behalf.
+    ///
+    /// [`Request`]: accessibility::Request
+    pub(crate) fn requests(&mut self) -> &mut accessibility::Requests {
+        &mut self.requests
+    }
+
+    /// Records the change from `before` to the given value in the history
+    /// of the [`TextInput`].
+    ///
+    /// Secure inputs do not keep a history, so their values never outlive
+    /// what is displayed.
+    fn record(
+        &mut self,
+        before: (String, Cursor),
+        value: &Value,
+        group: history::Group,
+        is_secure: bool,
+    ) {
+        if is_secure {
+            return;
+        }
+
+        let after = value.to_string();
+
+        if after != before.0 {
+            self.history.record(
+                Change {
+                    before,
+                    after: (after, self.cursor),
+                },
+                group,
+            );
+        }
+    }
+
+    /// Undoes or redoes the last step of the history of the [`TextInput`],
+    /// returning the resulting value and [`Cursor`].
+    ///
+    /// The history is discarded if the current value has been changed
+    /// outside of the [`TextInput`].
+    fn travel(
+        &mut self,
+        value: &Value,
+        is_redo: bool,
+    ) -> Option<(String, Cursor)> {
+        let value = value.to_string();
+
+        let (expected, target) = if is_redo {
+            let change = self.history.redo()?;
+            (&change.before.0, &change.after)
+        } else {
+            let change = self.history.undo()?;
+            (&change.after.0, &change.before)
+        };
+
+        if *expected != value {
+            self.history.clear();
+
+            return None;
+        }
+
+        Some(target.clone())
+    }
 }
 
 impl<P: text::Paragraph> operation::Focusable for State<P> {
@@ -1326,6 +1666,27 @@ fn offset<P: text::Paragraph>(
     }
 }
 
+/// Computes the bounds of the text cursor of a [`TextInput`], where the text
+/// being composed with an input method is displayed.
+fn caret_bounds<P: text::Paragraph>(
+    text_bounds: Rectangle,
+    value: &Value,
+    state: &State<P>,
+) -> Rectangle {
+    let (x, _) = measure_cursor_and_scroll_offset(
+        &state.value,
+        text_bounds,
+        state.cursor.start(value),
+    );
+
+    Rectangle {
+        x: text_bounds.x + x - offset(text_bounds, value, state),
+        y: text_bounds.y,
+        width: 1.0,
+        height: text_bounds.height,
+    }
+}
+
 fn measure_cursor_and_scroll_offset(
     paragraph: &impl text::Paragraph,
     text_bounds: Rectangle,
@@ -1495,3 +1856,136 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::runtime::user_interface;
+
+    use iced_test::{selector, Simulator};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Input(String),
+    }
+
+    fn simulator(
+        input: TextInput<'static, Message, Theme, ()>,
+        cache: user_interface::Cache,
+    ) -> Simulator<'static, Message, Theme, ()> {
+        let mut ui = Simulator::with_cache(
+            iced_test::DEFAULT_SIZE,
+            cache,
+            input.id(Id::new("input")).on_input(Message::Input),
+        );
+
+        assert!(ui.click(selector::id(Id::new("input"))).is_ok());
+        let _ = ui.tap_key(key::Named::End);
+
+        ui
+    }
+
+    fn undo(ui: &mut Simulator<'static, Message, Theme, ()>) {
+        let _ = ui.tap_key_with(
+            keyboard::Key::Character("z".into()),
+            keyboard::Modifiers::COMMAND,
+        );
+    }
+
+    fn redo(ui: &mut Simulator<'static, Message, Theme, ()>) {
+        let _ = ui.tap_key_with(
+            keyboard::Key::Character("z".into()),
+            keyboard::Modifiers::COMMAND | keyboard::Modifiers::SHIFT,
+        );
+    }
+
+    fn last_input<'a>(
+        ui: &'a Simulator<'static, Message, Theme, ()>,
+    ) -> Option<&'a str> {
+        ui.messages()
+            .last()
+            .map(|Message::Input(value)| value.as_str())
+    }
+
+    #[test]
+    fn typing_is_undone_word_by_word() {
+        let mut ui =
+            simulator(TextInput::new("", ""), user_interface::Cache::default());
+
+        let _ = ui.typewrite("hello world");
+
+        undo(&mut ui);
+        assert_eq!(last_input(&ui), Some("hello "));
+
+        undo(&mut ui);
+        assert_eq!(last_input(&ui), Some(""));
+
+        redo(&mut ui);
+        assert_eq!(last_input(&ui), Some("hello "));
+    }
+
+    #[test]
+    fn deleting_is_undone_at_once() {
+        let mut ui = simulator(
+            TextInput::new("", "ab"),
+            user_interface::Cache::default(),
+        );
+
+        let _ = ui.typewrite("cd");
+        let _ = ui.tap_key(key::Named::Backspace);
+        let _ = ui.tap_key(key::Named::Backspace);
+        let _ = ui.tap_key(key::Named::Backspace);
+        assert_eq!(last_input(&ui), Some("a"));
+
+        undo(&mut ui);
+        assert_eq!(last_input(&ui), Some("abcd"));
+
+        undo(&mut ui);
+        assert_eq!(last_input(&ui), Some("ab"));
+    }
+
+    #[test]
+    fn history_is_discarded_if_value_changes_outside() {
+        let mut ui =
+            simulator(TextInput::new("", ""), user_interface::Cache::default());
+
+        let _ = ui.typewrite("hi");
+        let cache = ui.into_cache();
+
+        let mut ui = simulator(TextInput::new("", "bye"), cache);
+
+        undo(&mut ui);
+        redo(&mut ui);
+        assert_eq!(last_input(&ui), None);
+    }
+
+    #[test]
+    fn secure_history_is_not_recorded() {
+        let mut ui = simulator(
+            TextInput::new("", "").secure(true),
+            user_interface::Cache::default(),
+        );
+
+        let _ = ui.typewrite("secret");
+        let count = ui.messages().len();
+
+        undo(&mut ui);
+        assert_eq!(ui.messages().len(), count);
+    }
+
+    #[test]
+    fn history_is_forgotten_when_input_becomes_secure() {
+        let mut ui =
+            simulator(TextInput::new("", ""), user_interface::Cache::default());
+
+        let _ = ui.typewrite("secret");
+        let cache = ui.into_cache();
+
+        let mut ui =
+            simulator(TextInput::new("", "secret").secure(true), cache);
+
+        undo(&mut ui);
+        assert_eq!(last_input(&ui), None);
+    }
+}
@@ -0,0 +1,333 @@
+//! Expose a user interface to assistive technologies through [AccessKit].
+//!
+//! [AccessKit]: https://accesskit.dev
+mod adapter;
+
+use crate::core::widget::operation::accessibility::{
+    self, Collect, Entry, Request, Target,
+};
+use crate::runtime::user_interface::UserInterface;
+
+use adapter::Adapter;
+
+use accesskit::{
+    ActionData, ActionHandler, ActionRequest, ActivationHandler,
+    DeactivationHandler, NodeBuilder, NodeId, Role, Toggled, TreeUpdate,
+};
+use rustc_hash::{FxHashMap, FxHasher};
+
+use std::hash::{Hash, Hasher};
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// The [`NodeId`] of the window, which is the root of the accessibility tree.
+const ROOT: NodeId = NodeId(0);
+
+/// The accessibility bridge of a window.
+///
+/// It describes the user interface of the window to the assistive
+/// technologies of the platform, and performs their requests on the widgets
+/// of the user interface.
+#[allow(missing_debug_implementations)]
+pub struct Accessibility {
+    adapter: Adapter,
+    window: Arc<winit::window::Window>,
+    requests: mpsc::Receiver<ActionRequest>,
+    targets: FxHashMap<NodeId, Target>,
+}
+
+impl Accessibility {
+    /// Creates a new [`Accessibility`] bridge for the given window.
+    ///
+    /// It must be created before the window is shown for the first time.
+    pub fn new(window: &Arc<winit::window::Window>) -> Self {
+        let (sender, requests) = mpsc::channel();
+
+        let adapter = Adapter::new(
+            window,
+            Activation {
+                window: window.clone(),
+            },
+            Actions {
+                window: window.clone(),
+                sender,
+            },
+            Deactivation,
+        );
+
+        Self {
+            adapter,
+            window: window.clone(),
+            requests,
+            targets: FxHashMap::default(),
+        }
+    }
+
+    /// Lets the bridge react to the given window event.
+    ///
+    /// It must be called for every window event before it is processed.
+    pub fn process_event(
+        &mut self,
+        window: &winit::window::Window,
+        event: &winit::event::WindowEvent,
+    ) {
+        self.adapter.process_event(window, event);
+    }
+
+    /// Describes the current [`UserInterface`] to the assistive technologies,
+    /// if any is listening.
+    pub fn update<Message, Theme, Renderer>(
+        &mut self,
+        title: &str,
+        scale_factor: f64,
+        user_interface: &mut UserInterface<'_, Message, Theme, Renderer>,
+        renderer: &Renderer,
+    ) where
+        Renderer: crate::core::Renderer,
+    {
+        let targets = &mut self.targets;
+
+        self.adapter.update_if_active(|| {
+            let mut collect = Collect::new();
+            user_interface.operate(renderer, &mut collect);
+
+            let entries = collect.finish();
+
+            targets.clear();
+
+            let mut nodes = Vec::new();
+            let mut focus = ROOT;
+
+            let children = entries
+                .iter()
+                .map(|entry| build(entry, targets, &mut nodes, &mut focus))
+                .collect::<Vec<_>>();
+
+            let mut window = NodeBuilder::new(Role::Window);
+            window.set_name(title);
+            window.set_children(children);
+            window.set_transform(accesskit::Affine::scale(scale_factor));
+
+            nodes.push((ROOT, window.build()));
+
+            TreeUpdate {
+                nodes,
+                tree: Some(accesskit::Tree::new(ROOT)),
+                focus,
+            }
+        });
+    }
+
+    /// Processes the pending requests of the assistive technologies by
+    /// performing them on the widgets of the given [`UserInterface`].
+    ///
+    /// The widgets carry out the requests the next time they process an
+    /// event, so a redraw is requested whenever some request is performed.
+    pub fn process_requests<Message, Theme, Renderer>(
+        &mut self,
+        user_interface: &mut UserInterface<'_, Message, Theme, Renderer>,
+        renderer: &Renderer,
+    ) where
+        Renderer: crate::core::Renderer,
+    {
+        while let Ok(action) = self.requests.try_recv() {
+            let Some(target) = self.targets.get(&action.target) else {
+                continue;
+            };
+
+            let Some(request) = request(action) else {
+                continue;
+            };
+
+            user_interface.operate(
+                renderer,
+                &mut accessibility::perform(target.clone(), request),
+            );
+
+            self.window.request_redraw();
+        }
+    }
+}
+
+/// Turns the given [`ActionRequest`] into a [`Request`] for a widget, if
+/// supported.
+fn request(action: ActionRequest) -> Option<Request> {
+    Some(match (action.action, action.data) {
+        (accesskit::Action::Default, _) => Request::Press,
+        (accesskit::Action::Focus, _) => Request::Focus,
+        (
+            accesskit::Action::SetValue
+            | accesskit::Action::ReplaceSelectedText,
+            Some(ActionData::Value(text)),
+        ) => Request::SetText(text.into()),
+        (
+            accesskit::Action::SetValue,
+            Some(ActionData::NumericValue(number)),
+        ) => Request::SetNumber(number),
+        (accesskit::Action::Increment, _) => Request::Increment,
+        (accesskit::Action::Decrement, _) => Request::Decrement,
+        _ => return None,
+    })
+}
+
+fn build(
+    entry: &Entry,
+    targets: &mut FxHashMap<NodeId, Target>,
+    nodes: &mut Vec<(NodeId, accesskit::Node)>,
+    focus: &mut NodeId,
+) -> NodeId {
+    let id = node_id(entry);
+    let node = &entry.node;
+
+    let _ = targets.insert(
+        id,
+        match &entry.id {
+            Some(id) => Target::Id(id.clone()),
+            None => Target::Path(entry.path.clone()),
+        },
+    );
+
+    if node.is_focused && *focus == ROOT {
+        *focus = id;
+    }
+
+    let mut builder = NodeBuilder::new(role(node.role));
+
+    builder.set_bounds(accesskit::Rect {
+        x0: f64::from(entry.bounds.x),
+        y0: f64::from(entry.bounds.y),
+        x1: f64::from(entry.bounds.x + entry.bounds.width),
+        y1: f64::from(entry.bounds.y + entry.bounds.height),
+    });
+
+    if let Some(label) = entry.label() {
+        builder.set_name(label);
+    }
+
+    match &node.value {
+        Some(accessibility::Value::Text(text)) => {
+            builder.set_value(text.as_str());
+        }
+        Some(accessibility::Value::Number {
+            value,
+            min,
+            max,
+            step,
+        }) => {
+            builder.set_numeric_value(*value);
+            builder.set_min_numeric_value(*min);
+            builder.set_max_numeric_value(*max);
+
+            if let Some(step) = step {
+                builder.set_numeric_value_step(*step);
+            }
+        }
+        None => {}
+    }
+
+    if let Some(is_toggled) = node.is_toggled {
+        builder.set_toggled(if is_toggled {
+            Toggled::True
+        } else {
+            Toggled::False
+        });
+    }
+
+    if node.is_disabled {
+        builder.set_disabled();
+    } else {
+        for action in &node.actions {
+            builder.add_action(match action {
+                accessibility::Action::Focus => accesskit::Action::Focus,
+                accessibility::Action::Press => accesskit::Action::Default,
+                accessibility::Action::SetValue => accesskit::Action::SetValue,
+                accessibility::Action::Increment => {
+                    accesskit::Action::Increment
+                }
+                accessibility::Action::Decrement => {
+                    accesskit::Action::Decrement
+                }
+            });
+        }
+    }
+
+    let children = entry
+        .children
+        .iter()
+        .map(|child| build(child, targets, nodes, focus))
+        .collect::<Vec<_>>();
+
+    builder.set_children(children);
+
+    nodes.push((id, builder.build()));
+
+    id
+}
+
+/// Returns a [`NodeId`] for the given [`Entry`] that is stable across
+/// updates; derived from its widget [`Id`], if any, or its path and role.
+///
+/// [`Id`]: crate::core::widget::Id
+fn node_id(entry: &Entry) -> NodeId {
+    let mut hasher = FxHasher::default();
+
+    match &entry.id {
+        Some(id) => id.hash(&mut hasher),
+        None => (&entry.path, entry.node.role).hash(&mut hasher),
+    }
+
+    // The root of the tree is reserved for the window
+    NodeId(hasher.finish().max(1))
+}
+
+fn role(role: accessibility::Role) -> Role {
+    match role {
+        accessibility::Role::Button => Role::Button,
+        accessibility::Role::CheckBox => Role::CheckBox,
+        accessibility::Role::ComboBox => Role::ComboBox,
+        accessibility::Role::Image => Role::Image,
+        accessibility::Role::Label => Role::StaticText,
+        accessibility::Role::MultilineTextInput => Role::MultilineTextInput,
+        accessibility::Role::ProgressIndicator => Role::ProgressIndicator,
+        accessibility::Role::RadioButton => Role::RadioButton,
+        accessibility::Role::ScrollView => Role::ScrollView,
+        accessibility::Role::Slider => Role::Slider,
+        accessibility::Role::SpinButton => Role::SpinButton,
+        accessibility::Role::Switch => Role::Switch,
+        accessibility::Role::Tab => Role::Tab,
+        accessibility::Role::TabList => Role::TabList,
+        accessibility::Role::TextInput => Role::TextInput,
+    }
+}
+
+struct Activation {
+    window: Arc<winit::window::Window>,
+}
+
+impl ActivationHandler for Activation {
+    fn request_initial_tree(&mut self) -> Option<TreeUpdate> {
+        // The tree will be sent during the next redraw
+        self.window.request_redraw();
+
+        None
+    }
+}
+
+struct Actions {
+    window: Arc<winit::window::Window>,
+    sender: mpsc::Sender<ActionRequest>,
+}
+
+impl ActionHandler for Actions {
+    fn do_action(&mut self, request: ActionRequest) {
+        if self.sender.send(request).is_ok() {
+            self.window.request_redraw();
+        }
+    }
+}
+
+struct Deactivation;
+
+impl DeactivationHandler for Deactivation {
+    fn deactivate_accessibility(&mut self) {}
+}
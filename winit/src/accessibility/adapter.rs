@@ -0,0 +1,227 @@
+//! Connect the accessibility tree of a window to the platform.
+pub use platform::Adapter;
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+mod platform {
+    use accesskit::{
+        ActionHandler, ActivationHandler, DeactivationHandler, Rect, TreeUpdate,
+    };
+    use winit::dpi::{PhysicalPosition, PhysicalSize};
+    use winit::event::WindowEvent;
+    use winit::window::Window;
+
+    /// An adapter for AT-SPI.
+    pub struct Adapter(accesskit_unix::Adapter);
+
+    impl Adapter {
+        pub fn new(
+            _window: &Window,
+            activation_handler: impl ActivationHandler + Send + 'static,
+            action_handler: impl ActionHandler + Send + 'static,
+            deactivation_handler: impl DeactivationHandler + Send + 'static,
+        ) -> Self {
+            Self(accesskit_unix::Adapter::new(
+                activation_handler,
+                action_handler,
+                deactivation_handler,
+            ))
+        }
+
+        pub fn update_if_active(
+            &mut self,
+            update: impl FnOnce() -> TreeUpdate,
+        ) {
+            self.0.update_if_active(update);
+        }
+
+        pub fn process_event(&mut self, window: &Window, event: &WindowEvent) {
+            match event {
+                WindowEvent::Moved(_) | WindowEvent::Resized(_) => {
+                    let outer = rect(
+                        window.outer_position().unwrap_or_default(),
+                        window.outer_size(),
+                    );
+
+                    let inner = rect(
+                        window.inner_position().unwrap_or_default(),
+                        window.inner_size(),
+                    );
+
+                    self.0.set_root_window_bounds(outer, inner);
+                }
+                WindowEvent::Focused(is_focused) => {
+                    self.0.update_window_focus_state(*is_focused);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn rect(position: PhysicalPosition<i32>, size: PhysicalSize<u32>) -> Rect {
+        let position: (f64, f64) = position.cast::<f64>().into();
+        let size: (f64, f64) = size.cast::<f64>().into();
+
+        Rect::from_origin_size(position, size)
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use accesskit::{
+        ActionHandler, ActivationHandler, DeactivationHandler, TreeUpdate,
+    };
+    use winit::event::WindowEvent;
+    use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+    use winit::window::Window;
+
+    /// An adapter for UI Automation.
+    pub struct Adapter(accesskit_windows::SubclassingAdapter);
+
+    impl Adapter {
+        pub fn new(
+            window: &Window,
+            activation_handler: impl ActivationHandler + Send + 'static,
+            action_handler: impl ActionHandler + Send + 'static,
+            _deactivation_handler: impl DeactivationHandler + Send + 'static,
+        ) -> Self {
+            let hwnd =
+                match window.window_handle().map(|handle| handle.as_raw()) {
+                    Ok(RawWindowHandle::Win32(handle)) => handle.hwnd.get(),
+                    _ => unreachable!("windows are always Win32 windows"),
+                };
+
+            Self(accesskit_windows::SubclassingAdapter::new(
+                accesskit_windows::HWND(hwnd),
+                activation_handler,
+                action_handler,
+            ))
+        }
+
+        pub fn update_if_active(
+            &mut self,
+            update: impl FnOnce() -> TreeUpdate,
+        ) {
+            if let Some(events) = self.0.update_if_active(update) {
+                events.raise();
+            }
+        }
+
+        pub fn process_event(
+            &mut self,
+            _window: &Window,
+            _event: &WindowEvent,
+        ) {
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use accesskit::{
+        ActionHandler, ActivationHandler, DeactivationHandler, TreeUpdate,
+    };
+    use winit::event::WindowEvent;
+    use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+    use winit::window::Window;
+
+    /// An adapter for the `NSAccessibility` protocol.
+    pub struct Adapter(accesskit_macos::SubclassingAdapter);
+
+    impl Adapter {
+        pub fn new(
+            window: &Window,
+            activation_handler: impl ActivationHandler + Send + 'static,
+            action_handler: impl ActionHandler + Send + 'static,
+            _deactivation_handler: impl DeactivationHandler + Send + 'static,
+        ) -> Self {
+            let view = match window
+                .window_handle()
+                .map(|handle| handle.as_raw())
+            {
+                Ok(RawWindowHandle::AppKit(handle)) => handle.ns_view.as_ptr(),
+                _ => unreachable!("windows are always AppKit windows"),
+            };
+
+            // SAFETY: The view is valid, since it belongs to the window
+            #[allow(unsafe_code)]
+            let adapter = unsafe {
+                accesskit_macos::SubclassingAdapter::new(
+                    view,
+                    activation_handler,
+                    action_handler,
+                )
+            };
+
+            Self(adapter)
+        }
+
+        pub fn update_if_active(
+            &mut self,
+            update: impl FnOnce() -> TreeUpdate,
+        ) {
+            if let Some(events) = self.0.update_if_active(update) {
+                events.raise();
+            }
+        }
+
+        pub fn process_event(&mut self, _window: &Window, event: &WindowEvent) {
+            if let WindowEvent::Focused(is_focused) = event {
+                if let Some(events) =
+                    self.0.update_view_focus_state(*is_focused)
+                {
+                    events.raise();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "windows",
+    target_os = "macos"
+)))]
+mod platform {
+    use accesskit::{
+        ActionHandler, ActivationHandler, DeactivationHandler, TreeUpdate,
+    };
+    use winit::event::WindowEvent;
+    use winit::window::Window;
+
+    /// An adapter for platforms without assistive technologies.
+    pub struct Adapter;
+
+    impl Adapter {
+        pub fn new(
+            _window: &Window,
+            _activation_handler: impl ActivationHandler + Send + 'static,
+            _action_handler: impl ActionHandler + Send + 'static,
+            _deactivation_handler: impl DeactivationHandler + Send + 'static,
+        ) -> Self {
+            Self
+        }
+
+        pub fn update_if_active(
+            &mut self,
+            _update: impl FnOnce() -> TreeUpdate,
+        ) {
+        }
+
+        pub fn process_event(
+            &mut self,
+            _window: &Window,
+            _event: &WindowEvent,
+        ) {
+        }
+    }
+}
@@ -10,7 +10,7 @@ use crate::core::renderer;
 use crate::core::time::Instant;
 use crate::core::widget::operation;
 use crate::core::window;
-use crate::core::{Color, Event, Point, Size, Theme};
+use crate::core::{Color, Event, InputMethod, Point, Size, Theme};
 use crate::futures::futures;
 use crate::futures::{Executor, Runtime, Subscription};
 use crate::graphics;
@@ -19,7 +19,7 @@ use crate::runtime::clipboard;
 use crate::runtime::program::Program;
 use crate::runtime::user_interface::{self, UserInterface};
 use crate::runtime::{Command, Debug};
-use crate::{Clipboard, Error, Proxy, Settings};
+use crate::{Clipboard, Error, Proxy, Settings, UserEvent};
 
 use futures::channel::mpsc;
 use futures::channel::oneshot;
@@ -479,12 +479,16 @@ struct Boot<C> {
 
 async fn run_instance<A, E, C>(
     mut application: A,
-    mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
-    mut proxy: Proxy<A::Message>,
+    mut runtime: Runtime<
+        E,
+        Proxy<UserEvent<A::Message>>,
+        UserEvent<A::Message>,
+    >,
+    mut proxy: Proxy<UserEvent<A::Message>>,
     mut debug: Debug,
     mut boot: oneshot::Receiver<Boot<C>>,
     mut event_receiver: mpsc::UnboundedReceiver<
-        winit::event::Event<A::Message>,
+        winit::event::Event<UserEvent<A::Message>>,
     >,
     mut control_sender: mpsc::UnboundedSender<winit::event_loop::ControlFlow>,
     init_command: Command<A::Message>,
@@ -525,6 +529,9 @@ async fn run_instance<A, E, C>(
     );
     let mut should_exit = false;
 
+    #[cfg(feature = "accessibility")]
+    let mut accessibility = crate::accessibility::Accessibility::new(&window);
+
     if should_be_visible {
         window.set_visible(true);
     }
@@ -536,7 +543,7 @@ async fn run_instance<A, E, C>(
         &mut cache,
         &state,
         &mut renderer,
-        init_command,
+        init_command.map(UserEvent::Message),
         &mut runtime,
         &mut clipboard,
         &mut should_exit,
@@ -544,7 +551,12 @@ async fn run_instance<A, E, C>(
         &mut debug,
         &window,
     );
-    runtime.track(application.subscription().into_recipes());
+    runtime.track(
+        application
+            .subscription()
+            .map(UserEvent::Message)
+            .into_recipes(),
+    );
 
     let mut user_interface = ManuallyDrop::new(build_user_interface(
         &application,
@@ -555,8 +567,10 @@ async fn run_instance<A, E, C>(
     ));
 
     let mut mouse_interaction = mouse::Interaction::default();
+    let mut input_method = InputMethod::Disabled;
     let mut events = Vec::new();
     let mut messages = Vec::new();
+    let mut continuations = Vec::new();
     let mut user_events = 0;
     let mut redraw_pending = false;
 
@@ -582,8 +596,14 @@ async fn run_instance<A, E, C>(
                     )),
                 ));
             }
-            event::Event::UserEvent(message) => {
-                messages.push(message);
+            event::Event::UserEvent(event) => {
+                match event {
+                    UserEvent::Message(message) => messages.push(message),
+                    UserEvent::Chain(continuation) => {
+                        continuations.push(continuation);
+                    }
+                }
+
                 user_events += 1;
             }
             event::Event::WindowEvent {
@@ -635,9 +655,27 @@ async fn run_instance<A, E, C>(
                     &mut messages,
                 );
 
+                if let user_interface::State::Updated {
+                    input_method: new_input_method,
+                    ..
+                } = interface_state
+                {
+                    if new_input_method != input_method {
+                        update_input_method(
+                            &window,
+                            input_method,
+                            new_input_method,
+                            state.scale_factor(),
+                        );
+
+                        input_method = new_input_method;
+                    }
+                }
+
                 let _ = control_sender.start_send(match interface_state {
                     user_interface::State::Updated {
                         redraw_request: Some(redraw_request),
+                        ..
                     } => match redraw_request {
                         window::RedrawRequest::NextFrame => {
                             window.request_redraw();
@@ -665,6 +703,14 @@ async fn run_instance<A, E, C>(
                 redraw_pending = false;
                 debug.draw_finished();
 
+                #[cfg(feature = "accessibility")]
+                accessibility.update(
+                    &application.title(),
+                    state.scale_factor(),
+                    &mut user_interface,
+                    &renderer,
+                );
+
                 if new_mouse_interaction != mouse_interaction {
                     window.set_cursor(conversion::mouse_interaction(
                         new_mouse_interaction,
@@ -705,6 +751,9 @@ async fn run_instance<A, E, C>(
                 event: window_event,
                 ..
             } => {
+                #[cfg(feature = "accessibility")]
+                accessibility.process_event(&window, &window_event);
+
                 if requests_exit(&window_event, state.modifiers())
                     && exit_on_close_request
                 {
@@ -723,7 +772,13 @@ async fn run_instance<A, E, C>(
                 }
             }
             event::Event::AboutToWait => {
-                if events.is_empty() && messages.is_empty() {
+                #[cfg(feature = "accessibility")]
+                accessibility.process_requests(&mut user_interface, &renderer);
+
+                if events.is_empty()
+                    && messages.is_empty()
+                    && continuations.is_empty()
+                {
                     continue;
                 }
 
@@ -746,6 +801,7 @@ async fn run_instance<A, E, C>(
                 }
 
                 if !messages.is_empty()
+                    || !continuations.is_empty()
                     || matches!(
                         interface_state,
                         user_interface::State::Outdated
@@ -754,6 +810,25 @@ async fn run_instance<A, E, C>(
                     let mut cache =
                         ManuallyDrop::into_inner(user_interface).into_cache();
 
+                    // Run chained commands
+                    for continuation in continuations.drain(..) {
+                        run_command(
+                            &application,
+                            &mut compositor,
+                            &mut surface,
+                            &mut cache,
+                            &state,
+                            &mut renderer,
+                            continuation(),
+                            &mut runtime,
+                            &mut clipboard,
+                            &mut should_exit,
+                            &mut proxy,
+                            &mut debug,
+                            &window,
+                        );
+                    }
+
                     // Update application
                     update(
                         &mut application,
@@ -826,6 +901,31 @@ pub fn requests_exit(
     }
 }
 
+/// Enables or disables the input method of the given window according to
+/// the [`InputMethod`] requested by its widgets.
+pub fn update_input_method(
+    window: &winit::window::Window,
+    current: InputMethod,
+    new: InputMethod,
+    scale_factor: f64,
+) {
+    match new {
+        InputMethod::Disabled => {
+            window.set_ime_allowed(false);
+        }
+        InputMethod::Enabled { cursor } => {
+            if !current.is_enabled() {
+                window.set_ime_allowed(true);
+            }
+
+            let (position, size) =
+                conversion::ime_cursor_area(cursor, scale_factor);
+
+            window.set_ime_cursor_area(position, size);
+        }
+    }
+}
+
 /// Builds a [`UserInterface`] for the provided [`Application`], logging
 /// [`struct@Debug`] information accordingly.
 pub fn build_user_interface<'a, A: Application>(
@@ -858,10 +958,14 @@ pub fn update<A: Application, C, E: Executor>(
     cache: &mut user_interface::Cache,
     state: &mut State<A>,
     renderer: &mut A::Renderer,
-    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    runtime: &mut Runtime<
+        E,
+        Proxy<UserEvent<A::Message>>,
+        UserEvent<A::Message>,
+    >,
     clipboard: &mut Clipboard,
     should_exit: &mut bool,
-    proxy: &mut Proxy<A::Message>,
+    proxy: &mut Proxy<UserEvent<A::Message>>,
     debug: &mut Debug,
     messages: &mut Vec<A::Message>,
     window: &winit::window::Window,
@@ -873,7 +977,9 @@ pub fn update<A: Application, C, E: Executor>(
         debug.log_message(&message);
 
         debug.update_started();
-        let command = runtime.enter(|| application.update(message));
+        let command = runtime
+            .enter(|| application.update(message))
+            .map(UserEvent::Message);
         debug.update_finished();
 
         run_command(
@@ -896,7 +1002,7 @@ pub fn update<A: Application, C, E: Executor>(
     state.synchronize(application, window);
 
     let subscription = application.subscription();
-    runtime.track(subscription.into_recipes());
+    runtime.track(subscription.map(UserEvent::Message).into_recipes());
 }
 
 /// Runs the actions of a [`Command`].
@@ -907,11 +1013,15 @@ pub fn run_command<A, C, E>(
     cache: &mut user_interface::Cache,
     state: &State<A>,
     renderer: &mut A::Renderer,
-    command: Command<A::Message>,
-    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    command: Command<UserEvent<A::Message>>,
+    runtime: &mut Runtime<
+        E,
+        Proxy<UserEvent<A::Message>>,
+        UserEvent<A::Message>,
+    >,
     clipboard: &mut Clipboard,
     should_exit: &mut bool,
-    proxy: &mut Proxy<A::Message>,
+    proxy: &mut Proxy<UserEvent<A::Message>>,
     debug: &mut Debug,
     window: &winit::window::Window,
 ) where
@@ -941,6 +1051,24 @@ pub fn run_command<A, C, E>(
                 clipboard::Action::Write(contents, kind) => {
                     clipboard.write(kind, contents);
                 }
+                clipboard::Action::ReadData(tag, kind, mime) => {
+                    let message = tag(core::Clipboard::read_data(
+                        &*clipboard,
+                        kind,
+                        &mime,
+                    ));
+
+                    proxy.send(message);
+                }
+                clipboard::Action::WriteData(contents, kind) => {
+                    core::Clipboard::write_data(clipboard, kind, contents);
+                }
+                clipboard::Action::ReadFormats(tag, kind) => {
+                    let message =
+                        tag(core::Clipboard::formats(&*clipboard, kind));
+
+                    proxy.send(message);
+                }
             },
             command::Action::Window(action) => match action {
                 window::Action::Close(_id) => {
@@ -1101,7 +1229,10 @@ pub fn run_command<A, C, E>(
                 );
 
                 while let Some(mut operation) = current_operation.take() {
-                    user_interface.operate(renderer, operation.as_mut());
+                    user_interface.operate(
+                        renderer,
+                        &mut operation::black_box(operation.as_mut()),
+                    );
 
                     match operation.finish() {
                         operation::Outcome::None => {}
@@ -1123,6 +1254,23 @@ pub fn run_command<A, C, E>(
 
                 proxy.send(tagger(Ok(())));
             }
+            command::Action::Chain(chain) => {
+                run_command(
+                    application,
+                    compositor,
+                    surface,
+                    cache,
+                    state,
+                    renderer,
+                    Command::single(chain.resolve(UserEvent::Chain)),
+                    runtime,
+                    clipboard,
+                    should_exit,
+                    proxy,
+                    debug,
+                    window,
+                );
+            }
             command::Action::Custom(_) => {
                 log::warn!("Unsupported custom action in `iced_winit` shell");
             }
@@ -1,16 +1,35 @@
 //! Access the clipboard.
 
-use crate::core::clipboard::Kind;
+#[cfg(not(any(
+    target_arch = "wasm32",
+    target_os = "android",
+    target_os = "ios"
+)))]
+mod rich;
+
+use crate::core::clipboard::{mime, Bytes, Data, Kind};
+
+use rich::Rich;
 
 /// A buffer for short-term storage and transfer within and between
 /// applications.
+///
+/// Besides plain text, the native clipboard supports HTML, URI lists of
+/// files and PNG images on desktop platforms. Any other MIME type is
+/// reported as unavailable and discarded when written.
+///
+/// Only one representation besides plain text can be written at once; the
+/// first supported one is chosen.
 #[allow(missing_debug_implementations)]
 pub struct Clipboard {
     state: State,
 }
 
 enum State {
-    Connected(window_clipboard::Clipboard),
+    Connected {
+        clipboard: window_clipboard::Clipboard,
+        rich: Option<Rich>,
+    },
     Unavailable,
 }
 
@@ -20,7 +39,10 @@ impl Clipboard {
         #[allow(unsafe_code)]
         let state = unsafe { window_clipboard::Clipboard::connect(window) }
             .ok()
-            .map(State::Connected)
+            .map(|clipboard| State::Connected {
+                clipboard,
+                rich: Rich::connect(),
+            })
             .unwrap_or(State::Unavailable);
 
         Clipboard { state }
@@ -37,7 +59,7 @@ impl Clipboard {
     /// Reads the current content of the [`Clipboard`] as text.
     pub fn read(&self, kind: Kind) -> Option<String> {
         match &self.state {
-            State::Connected(clipboard) => match kind {
+            State::Connected { clipboard, .. } => match kind {
                 Kind::Standard => clipboard.read().ok(),
                 Kind::Primary => clipboard.read_primary().and_then(Result::ok),
             },
@@ -48,7 +70,7 @@ impl Clipboard {
     /// Writes the given text contents to the [`Clipboard`].
     pub fn write(&mut self, kind: Kind, contents: String) {
         match &mut self.state {
-            State::Connected(clipboard) => {
+            State::Connected { clipboard, .. } => {
                 let result = match kind {
                     Kind::Standard => clipboard.write(contents),
                     Kind::Primary => {
@@ -66,6 +88,13 @@ impl Clipboard {
             State::Unavailable => {}
         }
     }
+
+    fn rich(&self) -> Option<&Rich> {
+        match &self.state {
+            State::Connected { rich, .. } => rich.as_ref(),
+            State::Unavailable => None,
+        }
+    }
 }
 
 impl crate::core::Clipboard for Clipboard {
@@ -76,4 +105,85 @@ impl crate::core::Clipboard for Clipboard {
     fn write(&mut self, kind: Kind, contents: String) {
         self.write(kind, contents);
     }
+
+    fn read_data(&self, kind: Kind, mime: &str) -> Option<Bytes> {
+        if mime::is_text(mime) {
+            self.read(kind).map(Bytes::from)
+        } else {
+            self.rich()?.read(kind, mime)
+        }
+    }
+
+    fn write_data(&mut self, kind: Kind, contents: Vec<Data>) {
+        let is_rich = self.rich().is_some();
+        let is_supported =
+            |mime: &str| mime::is_text(mime) || is_rich && Rich::supports(mime);
+
+        let unsupported: Vec<_> = contents
+            .iter()
+            .map(|data| data.mime.as_str())
+            .filter(|mime| !is_supported(mime))
+            .collect();
+
+        if !unsupported.is_empty() {
+            log::warn!(
+                "unsupported MIME types discarded from clipboard: \
+                {unsupported:?}"
+            );
+        }
+
+        if self.rich().is_some_and(|rich| rich.write(kind, &contents)) {
+            return;
+        }
+
+        if let Some(text) = contents
+            .iter()
+            .filter(|data| mime::is_text(&data.mime))
+            .find_map(Data::as_text)
+        {
+            self.write(kind, text.to_owned());
+        }
+    }
+
+    fn formats(&self, kind: Kind) -> Vec<String> {
+        let text = self.read(kind).map(|_| String::from(mime::TEXT));
+
+        text.into_iter()
+            .chain(
+                self.rich()
+                    .map(|rich| rich.formats(kind))
+                    .unwrap_or_default(),
+            )
+            .collect()
+    }
+}
+
+/// The native clipboard of this platform only supports plain text.
+#[cfg(any(target_arch = "wasm32", target_os = "android", target_os = "ios"))]
+mod rich {
+    use crate::core::clipboard::{Bytes, Data, Kind};
+
+    pub enum Rich {}
+
+    impl Rich {
+        pub fn connect() -> Option<Self> {
+            None
+        }
+
+        pub fn supports(_mime: &str) -> bool {
+            false
+        }
+
+        pub fn read(&self, _kind: Kind, _mime: &str) -> Option<Bytes> {
+            match *self {}
+        }
+
+        pub fn formats(&self, _kind: Kind) -> Vec<String> {
+            match *self {}
+        }
+
+        pub fn write(&self, _kind: Kind, _contents: &[Data]) -> bool {
+            match *self {}
+        }
+    }
 }
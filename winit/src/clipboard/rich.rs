@@ -0,0 +1,297 @@
+//! Access the formats of the native clipboard other than plain text.
+use crate::core::clipboard::{mime, Bytes, Data, Kind};
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::path::PathBuf;
+
+/// A native clipboard that supports HTML, URI lists of files and PNG
+/// images.
+pub struct Rich(RefCell<arboard::Clipboard>);
+
+/// Some contents of a [`Rich`] clipboard.
+enum Payload<'a> {
+    Html(&'a str),
+    Image(arboard::ImageData<'static>),
+    Files(Vec<PathBuf>),
+}
+
+impl Rich {
+    /// Connects to the native clipboard, if available.
+    pub fn connect() -> Option<Self> {
+        match arboard::Clipboard::new() {
+            Ok(clipboard) => Some(Self(RefCell::new(clipboard))),
+            Err(error) => {
+                log::warn!("rich clipboard is unavailable: {error}");
+                None
+            }
+        }
+    }
+
+    /// Returns true if the given MIME type is supported.
+    pub fn supports(mime: &str) -> bool {
+        [mime::HTML, mime::URI_LIST, mime::PNG].contains(&mime)
+    }
+
+    /// Reads the current content of the clipboard in the given MIME type.
+    pub fn read(&self, kind: Kind, mime: &str) -> Option<Bytes> {
+        let mut clipboard = self.0.borrow_mut();
+        let get = get(&mut clipboard, kind)?;
+
+        match mime {
+            mime::HTML => get.html().ok().map(Bytes::from),
+            mime::URI_LIST => {
+                let files = get.file_list().ok()?;
+
+                if files.is_empty() {
+                    return None;
+                }
+
+                Some(Bytes::from(uri_list(&files)))
+            }
+            mime::PNG => encode_png(&get.image().ok()?).map(Bytes::from),
+            _ => None,
+        }
+    }
+
+    /// Returns the supported MIME types the current content of the
+    /// clipboard is available in.
+    pub fn formats(&self, kind: Kind) -> Vec<String> {
+        let mut clipboard = self.0.borrow_mut();
+
+        let formats: [(&str, fn(arboard::Get<'_>) -> bool); 3] = [
+            (mime::HTML, |get| get.html().is_ok()),
+            (mime::URI_LIST, |get| {
+                get.file_list().is_ok_and(|files| !files.is_empty())
+            }),
+            (mime::PNG, |get| get.image().is_ok()),
+        ];
+
+        formats
+            .into_iter()
+            .filter(|(_, is_available)| {
+                get(&mut clipboard, kind).is_some_and(is_available)
+            })
+            .map(|(mime, _)| String::from(mime))
+            .collect()
+    }
+
+    /// Writes the first of the given representations of some contents in
+    /// a supported MIME type, returning whether it was written.
+    ///
+    /// HTML is written along with the plain text representation, if any.
+    pub fn write(&self, kind: Kind, contents: &[Data]) -> bool {
+        let payload =
+            contents.iter().find_map(|data| match data.mime.as_str() {
+                mime::HTML => data.as_text().map(Payload::Html),
+                mime::PNG => decode_png(&data.bytes).map(Payload::Image),
+                mime::URI_LIST => {
+                    let files = files(data.as_text()?);
+
+                    (!files.is_empty()).then_some(Payload::Files(files))
+                }
+                _ => None,
+            });
+
+        let Some(payload) = payload else {
+            return false;
+        };
+
+        let text = contents
+            .iter()
+            .filter(|data| mime::is_text(&data.mime))
+            .find_map(Data::as_text);
+
+        let mut clipboard = self.0.borrow_mut();
+
+        let Some(set) = set(&mut clipboard, kind) else {
+            return false;
+        };
+
+        let result = match payload {
+            Payload::Html(html) => set.html(html, text),
+            Payload::Image(image) => set.image(image),
+            Payload::Files(files) => set.file_list(&files),
+        };
+
+        match result {
+            Ok(()) => true,
+            Err(error) => {
+                log::warn!("error writing to clipboard: {error}");
+                false
+            }
+        }
+    }
+}
+
+fn get(
+    clipboard: &mut arboard::Clipboard,
+    kind: Kind,
+) -> Option<arboard::Get<'_>> {
+    match kind {
+        Kind::Standard => Some(clipboard.get()),
+        #[cfg(all(unix, not(target_os = "macos")))]
+        Kind::Primary => {
+            use arboard::GetExtLinux;
+
+            Some(
+                clipboard
+                    .get()
+                    .clipboard(arboard::LinuxClipboardKind::Primary),
+            )
+        }
+        #[cfg(not(all(unix, not(target_os = "macos"))))]
+        Kind::Primary => None,
+    }
+}
+
+fn set(
+    clipboard: &mut arboard::Clipboard,
+    kind: Kind,
+) -> Option<arboard::Set<'_>> {
+    match kind {
+        Kind::Standard => Some(clipboard.set()),
+        #[cfg(all(unix, not(target_os = "macos")))]
+        Kind::Primary => {
+            use arboard::SetExtLinux;
+
+            Some(
+                clipboard
+                    .set()
+                    .clipboard(arboard::LinuxClipboardKind::Primary),
+            )
+        }
+        #[cfg(not(all(unix, not(target_os = "macos"))))]
+        Kind::Primary => None,
+    }
+}
+
+/// Returns the `text/uri-list` of the given files.
+fn uri_list(files: &[PathBuf]) -> String {
+    files
+        .iter()
+        .filter_map(|path| url::Url::from_file_path(path).ok())
+        .map(String::from)
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+/// Returns the files in the given `text/uri-list`.
+fn files(uri_list: &str) -> Vec<PathBuf> {
+    uri_list
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| url::Url::parse(line).ok()?.to_file_path().ok())
+        .collect()
+}
+
+fn encode_png(image: &arboard::ImageData<'_>) -> Option<Vec<u8>> {
+    let mut png = Vec::new();
+
+    let mut encoder = png::Encoder::new(
+        &mut png,
+        u32::try_from(image.width).ok()?,
+        u32::try_from(image.height).ok()?,
+    );
+
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let result = encoder.write_header().and_then(|mut writer| {
+        writer.write_image_data(&image.bytes)?;
+        writer.finish()
+    });
+
+    match result {
+        Ok(()) => Some(png),
+        Err(error) => {
+            log::warn!("error encoding clipboard image: {error}");
+            None
+        }
+    }
+}
+
+fn decode_png(bytes: &[u8]) -> Option<arboard::ImageData<'static>> {
+    let mut decoder = png::Decoder::new(bytes);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+
+    let mut reader = decoder
+        .read_info()
+        .map_err(|error| log::warn!("error decoding clipboard image: {error}"))
+        .ok()?;
+
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer).ok()?;
+    buffer.truncate(info.buffer_size());
+
+    let rgba = match info.color_type {
+        png::ColorType::Rgba => buffer,
+        png::ColorType::Rgb => buffer
+            .chunks_exact(3)
+            .flat_map(|pixel| [pixel[0], pixel[1], pixel[2], u8::MAX])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => buffer
+            .chunks_exact(2)
+            .flat_map(|pixel| [pixel[0], pixel[0], pixel[0], pixel[1]])
+            .collect(),
+        png::ColorType::Grayscale => buffer
+            .iter()
+            .flat_map(|gray| [*gray, *gray, *gray, u8::MAX])
+            .collect(),
+        png::ColorType::Indexed => return None,
+    };
+
+    Some(arboard::ImageData {
+        width: info.width as usize,
+        height: info.height as usize,
+        bytes: Cow::Owned(rgba),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn png_round_trips() {
+        let image = arboard::ImageData {
+            width: 2,
+            height: 1,
+            bytes: Cow::Owned(vec![255, 0, 0, 255, 0, 0, 255, 128]),
+        };
+
+        let png = encode_png(&image).expect("encode image");
+        let decoded = decode_png(&png).expect("decode image");
+
+        assert_eq!((decoded.width, decoded.height), (2, 1));
+        assert_eq!(decoded.bytes, image.bytes);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn uri_lists_round_trip() {
+        let files = vec![
+            PathBuf::from("/tmp/some file.txt"),
+            PathBuf::from("/home/iced/ü.png"),
+        ];
+
+        let list = uri_list(&files);
+
+        assert_eq!(
+            list,
+            "file:///tmp/some%20file.txt\r\nfile:///home/iced/%C3%BC.png"
+        );
+        assert_eq!(super::files(&list), files);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn uri_lists_skip_comments_and_remote_uris() {
+        let list = "# copied from a browser\r\n\
+            https://iced.rs\r\n\
+            file:///tmp/a.txt\r\n";
+
+        assert_eq!(files(list), [PathBuf::from("/tmp/a.txt")]);
+    }
+}
@@ -6,7 +6,7 @@ use crate::core::keyboard;
 use crate::core::mouse;
 use crate::core::touch;
 use crate::core::window;
-use crate::core::{Event, Point, Size};
+use crate::core::{Event, Point, Rectangle, Size};
 
 /// Converts some [`window::Settings`] into some `WindowAttributes` from `winit`.
 pub fn window_attributes(
@@ -264,6 +264,9 @@ pub fn window_event(
                 self::modifiers(new_modifiers.state()),
             )))
         }
+        WindowEvent::Ime(ime) => {
+            Some(Event::Keyboard(keyboard::Event::Ime(self::ime(ime))))
+        }
         WindowEvent::Focused(focused) => Some(Event::Window(
             id,
             if focused {
@@ -472,6 +475,45 @@ pub fn modifiers(
     result
 }
 
+/// Converts an `Ime` event from [`winit`] to an [`iced`] input method event.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced`]: https://github.com/iced-rs/iced/tree/0.12
+pub fn ime(ime: winit::event::Ime) -> keyboard::Ime {
+    match ime {
+        winit::event::Ime::Enabled => keyboard::Ime::Enabled,
+        winit::event::Ime::Preedit(content, cursor) => {
+            keyboard::Ime::Preedit { content, cursor }
+        }
+        winit::event::Ime::Commit(text) => keyboard::Ime::Commit(text),
+        winit::event::Ime::Disabled => keyboard::Ime::Disabled,
+    }
+}
+
+/// Converts the logical cursor area of an [`InputMethod`] to the physical
+/// position and size expected by [`winit`].
+///
+/// [`InputMethod`]: crate::core::InputMethod
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn ime_cursor_area(
+    cursor: Rectangle,
+    scale_factor: f64,
+) -> (
+    winit::dpi::PhysicalPosition<f64>,
+    winit::dpi::PhysicalSize<f64>,
+) {
+    (
+        winit::dpi::PhysicalPosition::new(
+            f64::from(cursor.x) * scale_factor,
+            f64::from(cursor.y) * scale_factor,
+        ),
+        winit::dpi::PhysicalSize::new(
+            f64::from(cursor.width) * scale_factor,
+            f64::from(cursor.height) * scale_factor,
+        ),
+    )
+}
+
 /// Converts a physical cursor position to a logical `Point`.
 pub fn cursor_position(
     position: winit::dpi::PhysicalPosition<f64>,
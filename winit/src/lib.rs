@@ -27,6 +27,8 @@ pub use winit;
 #[cfg(feature = "multi-window")]
 pub mod multi_window;
 
+#[cfg(feature = "accessibility")]
+pub mod accessibility;
 #[cfg(feature = "application")]
 pub mod application;
 pub mod clipboard;
@@ -43,5 +45,5 @@ mod proxy;
 pub use application::Application;
 pub use clipboard::Clipboard;
 pub use error::Error;
-pub use proxy::Proxy;
+pub use proxy::{Proxy, UserEvent};
 pub use settings::Settings;
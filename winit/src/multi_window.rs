@@ -4,6 +4,7 @@ mod window_manager;
 
 pub use state::State;
 
+use crate::application;
 use crate::conversion;
 use crate::core;
 use crate::core::mouse;
@@ -24,7 +25,7 @@ use crate::runtime::command::{self, Command};
 use crate::runtime::multi_window::Program;
 use crate::runtime::user_interface::{self, UserInterface};
 use crate::runtime::Debug;
-use crate::{Clipboard, Error, Proxy, Settings};
+use crate::{Clipboard, Error, Proxy, Settings, UserEvent};
 
 pub use crate::application::{default, Appearance, DefaultStyle};
 
@@ -379,12 +380,20 @@ where
                                 let exit_on_close_request =
                                     settings.exit_on_close_request;
 
+                                #[cfg(feature = "accessibility")]
+                                let should_be_visible = settings.visible;
+
+                                let attributes = conversion::window_attributes(
+                                    settings, &title, monitor, None,
+                                );
+
+                                // The accessibility bridge of the window must
+                                // be created before it is shown
+                                #[cfg(feature = "accessibility")]
+                                let attributes = attributes.with_visible(false);
+
                                 let window = event_loop
-                                    .create_window(
-                                        conversion::window_attributes(
-                                            settings, &title, monitor, None,
-                                        ),
-                                    )
+                                    .create_window(attributes)
                                     .expect("Create window");
 
                                 self.process_event(
@@ -393,6 +402,8 @@ where
                                         id,
                                         window,
                                         exit_on_close_request,
+                                        #[cfg(feature = "accessibility")]
+                                        should_be_visible,
                                     },
                                 );
                             }
@@ -430,6 +441,8 @@ enum Event<Message: 'static> {
         id: window::Id,
         window: winit::window::Window,
         exit_on_close_request: bool,
+        #[cfg(feature = "accessibility")]
+        should_be_visible: bool,
     },
     EventLoopAwakened(winit::event::Event<Message>),
 }
@@ -447,11 +460,15 @@ enum Control {
 
 async fn run_instance<A, E, C>(
     mut application: A,
-    mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
-    mut proxy: Proxy<A::Message>,
+    mut runtime: Runtime<
+        E,
+        Proxy<UserEvent<A::Message>>,
+        UserEvent<A::Message>,
+    >,
+    mut proxy: Proxy<UserEvent<A::Message>>,
     mut debug: Debug,
     mut boot: oneshot::Receiver<Boot<C>>,
-    mut event_receiver: mpsc::UnboundedReceiver<Event<A::Message>>,
+    mut event_receiver: mpsc::UnboundedReceiver<Event<UserEvent<A::Message>>>,
     mut control_sender: mpsc::UnboundedSender<Control>,
     init_command: Command<A::Message>,
 ) where
@@ -516,7 +533,7 @@ async fn run_instance<A, E, C>(
     run_command(
         &application,
         &mut compositor,
-        init_command,
+        init_command.map(UserEvent::Message),
         &mut runtime,
         &mut clipboard,
         &mut control_sender,
@@ -526,9 +543,15 @@ async fn run_instance<A, E, C>(
         &mut ui_caches,
     );
 
-    runtime.track(application.subscription().into_recipes());
+    runtime.track(
+        application
+            .subscription()
+            .map(UserEvent::Message)
+            .into_recipes(),
+    );
 
     let mut messages = Vec::new();
+    let mut continuations = Vec::new();
     let mut user_events = 0;
 
     debug.startup_finished();
@@ -539,6 +562,8 @@ async fn run_instance<A, E, C>(
                 id,
                 window,
                 exit_on_close_request,
+                #[cfg(feature = "accessibility")]
+                should_be_visible,
             } => {
                 let window = window_manager.insert(
                     id,
@@ -548,6 +573,11 @@ async fn run_instance<A, E, C>(
                     exit_on_close_request,
                 );
 
+                #[cfg(feature = "accessibility")]
+                if should_be_visible {
+                    window.raw.set_visible(true);
+                }
+
                 let logical_size = window.state.logical_size();
 
                 let _ = user_interfaces.insert(
@@ -602,8 +632,16 @@ async fn run_instance<A, E, C>(
                             ),
                         ));
                     }
-                    event::Event::UserEvent(message) => {
-                        messages.push(message);
+                    event::Event::UserEvent(event) => {
+                        match event {
+                            UserEvent::Message(message) => {
+                                messages.push(message);
+                            }
+                            UserEvent::Chain(continuation) => {
+                                continuations.push(continuation);
+                            }
+                        }
+
                         user_events += 1;
                     }
                     event::Event::WindowEvent {
@@ -652,6 +690,14 @@ async fn run_instance<A, E, C>(
                         );
                         debug.draw_finished();
 
+                        #[cfg(feature = "accessibility")]
+                        window.accessibility.update(
+                            &application.title(id),
+                            window.state.scale_factor(),
+                            ui,
+                            &window.renderer,
+                        );
+
                         if new_mouse_interaction != window.mouse_interaction {
                             window.raw.set_cursor(
                                 conversion::mouse_interaction(
@@ -662,6 +708,23 @@ async fn run_instance<A, E, C>(
                             window.mouse_interaction = new_mouse_interaction;
                         }
 
+                        if let user_interface::State::Updated {
+                            input_method,
+                            ..
+                        } = ui_state
+                        {
+                            if input_method != window.input_method {
+                                application::update_input_method(
+                                    &window.raw,
+                                    window.input_method,
+                                    input_method,
+                                    window.state.scale_factor(),
+                                );
+
+                                window.input_method = input_method;
+                            }
+                        }
+
                         runtime.broadcast(
                             redraw_event.clone(),
                             core::event::Status::Ignored,
@@ -671,6 +734,7 @@ async fn run_instance<A, E, C>(
                             match ui_state {
                                 user_interface::State::Updated {
                                     redraw_request: Some(redraw_request),
+                                    ..
                                 } => match redraw_request {
                                     window::RedrawRequest::NextFrame => {
                                         window.raw.request_redraw();
@@ -791,6 +855,11 @@ async fn run_instance<A, E, C>(
                             continue;
                         };
 
+                        #[cfg(feature = "accessibility")]
+                        window
+                            .accessibility
+                            .process_event(&window.raw, &window_event);
+
                         if matches!(
                             window_event,
                             winit::event::WindowEvent::CloseRequested
@@ -826,7 +895,19 @@ async fn run_instance<A, E, C>(
                         }
                     }
                     event::Event::AboutToWait => {
-                        if events.is_empty() && messages.is_empty() {
+                        #[cfg(feature = "accessibility")]
+                        for (id, window) in window_manager.iter_mut() {
+                            if let Some(ui) = user_interfaces.get_mut(&id) {
+                                window
+                                    .accessibility
+                                    .process_requests(ui, &window.renderer);
+                            }
+                        }
+
+                        if events.is_empty()
+                            && messages.is_empty()
+                            && continuations.is_empty()
+                        {
                             continue;
                         }
 
@@ -881,7 +962,10 @@ async fn run_instance<A, E, C>(
                         debug.event_processing_finished();
 
                         // TODO mw application update returns which window IDs to update
-                        if !messages.is_empty() || uis_stale {
+                        if !messages.is_empty()
+                            || !continuations.is_empty()
+                            || uis_stale
+                        {
                             let mut cached_interfaces: FxHashMap<
                                 window::Id,
                                 user_interface::Cache,
@@ -890,6 +974,22 @@ async fn run_instance<A, E, C>(
                                 .map(|(id, ui)| (id, ui.into_cache()))
                                 .collect();
 
+                            // Run chained commands
+                            for continuation in continuations.drain(..) {
+                                run_command(
+                                    &application,
+                                    &mut compositor,
+                                    continuation(),
+                                    &mut runtime,
+                                    &mut clipboard,
+                                    &mut control_sender,
+                                    &mut proxy,
+                                    &mut debug,
+                                    &mut window_manager,
+                                    &mut cached_interfaces,
+                                );
+                            }
+
                             // Update application
                             update(
                                 &mut application,
@@ -970,10 +1070,14 @@ where
 fn update<A: Application, C, E: Executor>(
     application: &mut A,
     compositor: &mut C,
-    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    runtime: &mut Runtime<
+        E,
+        Proxy<UserEvent<A::Message>>,
+        UserEvent<A::Message>,
+    >,
     clipboard: &mut Clipboard,
     control_sender: &mut mpsc::UnboundedSender<Control>,
-    proxy: &mut Proxy<A::Message>,
+    proxy: &mut Proxy<UserEvent<A::Message>>,
     debug: &mut Debug,
     messages: &mut Vec<A::Message>,
     window_manager: &mut WindowManager<A, C>,
@@ -986,7 +1090,9 @@ fn update<A: Application, C, E: Executor>(
         debug.log_message(&message);
         debug.update_started();
 
-        let command = runtime.enter(|| application.update(message));
+        let command = runtime
+            .enter(|| application.update(message))
+            .map(UserEvent::Message);
         debug.update_finished();
 
         run_command(
@@ -1004,18 +1110,22 @@ fn update<A: Application, C, E: Executor>(
     }
 
     let subscription = application.subscription();
-    runtime.track(subscription.into_recipes());
+    runtime.track(subscription.map(UserEvent::Message).into_recipes());
 }
 
 /// Runs the actions of a [`Command`].
 fn run_command<A, C, E>(
     application: &A,
     compositor: &mut C,
-    command: Command<A::Message>,
-    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    command: Command<UserEvent<A::Message>>,
+    runtime: &mut Runtime<
+        E,
+        Proxy<UserEvent<A::Message>>,
+        UserEvent<A::Message>,
+    >,
     clipboard: &mut Clipboard,
     control_sender: &mut mpsc::UnboundedSender<Control>,
-    proxy: &mut Proxy<A::Message>,
+    proxy: &mut Proxy<UserEvent<A::Message>>,
     debug: &mut Debug,
     window_manager: &mut WindowManager<A, C>,
     ui_caches: &mut FxHashMap<window::Id, user_interface::Cache>,
@@ -1046,6 +1156,24 @@ fn run_command<A, C, E>(
                 clipboard::Action::Write(contents, kind) => {
                     clipboard.write(kind, contents);
                 }
+                clipboard::Action::ReadData(tag, kind, mime) => {
+                    let message = tag(core::Clipboard::read_data(
+                        &*clipboard,
+                        kind,
+                        &mime,
+                    ));
+
+                    proxy.send(message);
+                }
+                clipboard::Action::WriteData(contents, kind) => {
+                    core::Clipboard::write_data(clipboard, kind, contents);
+                }
+                clipboard::Action::ReadFormats(tag, kind) => {
+                    let message =
+                        tag(core::Clipboard::formats(&*clipboard, kind));
+
+                    proxy.send(message);
+                }
             },
             command::Action::Window(action) => match action {
                 window::Action::Spawn(id, settings) => {
@@ -1277,7 +1405,10 @@ fn run_command<A, C, E>(
                 {
                     for (id, ui) in uis.iter_mut() {
                         if let Some(window) = window_manager.get_mut(*id) {
-                            ui.operate(&window.renderer, operation.as_mut());
+                            ui.operate(
+                                &window.renderer,
+                                &mut operation::black_box(operation.as_mut()),
+                            );
 
                             match operation.finish() {
                                 operation::Outcome::None => {}
@@ -1304,6 +1435,20 @@ fn run_command<A, C, E>(
 
                 proxy.send(tagger(Ok(())));
             }
+            command::Action::Chain(chain) => {
+                run_command(
+                    application,
+                    compositor,
+                    Command::single(chain.resolve(UserEvent::Chain)),
+                    runtime,
+                    clipboard,
+                    control_sender,
+                    proxy,
+                    debug,
+                    window_manager,
+                    ui_caches,
+                );
+            }
             command::Action::Custom(_) => {
                 log::warn!("Unsupported custom action in `iced_winit` shell");
             }
@@ -1,6 +1,6 @@
 use crate::core::mouse;
 use crate::core::window::Id;
-use crate::core::{Point, Size};
+use crate::core::{InputMethod, Point, Size};
 use crate::graphics::Compositor;
 use crate::multi_window::{Application, DefaultStyle, State};
 
@@ -50,6 +50,9 @@ where
         );
         let renderer = compositor.create_renderer();
 
+        #[cfg(feature = "accessibility")]
+        let accessibility = crate::accessibility::Accessibility::new(&window);
+
         let _ = self.aliases.insert(window.id(), id);
 
         let _ = self.entries.insert(
@@ -62,6 +65,9 @@ where
                 surface,
                 renderer,
                 mouse_interaction: mouse::Interaction::None,
+                input_method: InputMethod::Disabled,
+                #[cfg(feature = "accessibility")]
+                accessibility,
             },
         );
 
@@ -128,8 +134,11 @@ where
     pub viewport_version: u64,
     pub exit_on_close_request: bool,
     pub mouse_interaction: mouse::Interaction,
+    pub input_method: InputMethod,
     pub surface: C::Surface,
     pub renderer: A::Renderer,
+    #[cfg(feature = "accessibility")]
+    pub accessibility: crate::accessibility::Accessibility,
 }
 
 impl<A, C> Window<A, C>
@@ -4,6 +4,9 @@ use crate::futures::futures::{
     task::{Context, Poll},
     Future, Sink, StreamExt,
 };
+use crate::runtime::command;
+
+use std::fmt;
 use std::pin::Pin;
 
 /// An event loop proxy with backpressure that implements `Sink`.
@@ -130,3 +133,30 @@ impl<Message: 'static> Sink<Message> for Proxy<Message> {
         Poll::Ready(Ok(()))
     }
 }
+
+/// An event sent to the event loop of an application through a [`Proxy`].
+pub enum UserEvent<Message> {
+    /// A message produced by the application.
+    Message(Message),
+
+    /// The [`Continuation`] of a chained [`Command`], which must be run
+    /// by the application.
+    ///
+    /// [`Continuation`]: command::Continuation
+    /// [`Command`]: crate::runtime::Command
+    Chain(command::Continuation<UserEvent<Message>>),
+}
+
+impl<Message> fmt::Debug for UserEvent<Message>
+where
+    Message: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Message(message) => {
+                write!(f, "UserEvent::Message({message:?})")
+            }
+            Self::Chain(_) => write!(f, "UserEvent::Chain"),
+        }
+    }
+}